@@ -1,10 +1,8 @@
 extern crate chrono;
 extern crate gribberish;
 
-use gribberish::message::MessageIterator;
+use gribberish::file::GribFile;
 use std::env;
-use std::fs::File;
-use std::io::Read;
 use std::process;
 use std::vec::Vec;
 
@@ -16,14 +14,10 @@ fn main() {
     }
 
     let grib_path = &args[1];
-    let mut grib_file = File::open(grib_path).expect("file not found");
+    let grib_file = GribFile::open(grib_path).expect("failed to open grib2 file");
 
-    let mut raw_grib_data = Vec::new();
-    grib_file
-        .read_to_end(&mut raw_grib_data)
-        .expect("failed to read raw grib2 data");
-
-    let message_iter = MessageIterator::from_data(raw_grib_data.as_slice(), 0);
+    // Only GRIB2 messages are dumped, GRIB1 messages are skipped
+    let message_iter = grib_file.messages();
 
     println!("GRIB2 file read: {}", grib_path);
     println!(
@@ -110,4 +104,9 @@ fn main() {
             },
         );
     });
+
+    let grib1_count = grib_file.grib1_messages().count();
+    if grib1_count > 0 {
+        println!("Skipped {grib1_count} GRIB1 messages");
+    }
 }
@@ -1,8 +1,8 @@
 use std::fs::File;
-use std::io::{Read, Write};
+use std::io::Write;
 use std::path::{PathBuf};
 use clap::Parser;
-use gribberish::message_metadata::scan_message_metadata;
+use gribberish::file::GribFile;
 
 /// Simple program to greet a person
 #[derive(Parser, Debug)]
@@ -33,17 +33,12 @@ pub fn main() {
     let args = Args::parse();
 
     let grib_path = PathBuf::from(&args.input_file);
-    let mut grib_file = File::open(&grib_path).expect("file not found");
+    let grib_file = GribFile::open(&grib_path).expect("failed to open grib2 file");
 
     let grib_name = grib_path.file_stem().expect("Failed to get grib filename");
     let output_folder = args.output_folder();
 
-    let mut raw_grib_data = Vec::new();
-    grib_file
-        .read_to_end(&mut raw_grib_data)
-        .expect("failed to read raw grib2 data");
-
-    let messages = scan_message_metadata(raw_grib_data.as_slice());
+    let messages = grib_file.scan_metadata();
     messages.iter().for_each(|(key, (idx, offset, metadata))| {
         let mut message_path = PathBuf::from(&output_folder);
         let root_name = grib_name.to_str().expect("Failed to get filename string");
@@ -51,7 +46,7 @@ pub fn main() {
         message_path.push(filename);
         let mut message_file = File::create(message_path).expect("Failed to create message file");
 
-        let data = &raw_grib_data[*offset..*offset+metadata.message_size];
+        let data = &grib_file.byte_data()[*offset..*offset+metadata.message_size];
 
         message_file.write_all(&data).expect("Failed to write message file");
     });
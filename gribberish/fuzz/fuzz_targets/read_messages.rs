@@ -0,0 +1,36 @@
+#![no_main]
+
+use gribberish::grib1::read_grib1_messages;
+use gribberish::message::{read_messages, read_messages_resilient};
+use gribberish::message_metadata::{scan_message_metadata, MessageMetadata};
+use libfuzzer_sys::fuzz_target;
+
+/// Skip unpacking grids declaring more points than this so inputs can not allocate without bound
+const MAX_GRID_POINTS: usize = 1 << 20;
+
+fn is_small_grid(dimensions: Option<(usize, usize)>) -> bool {
+    dimensions
+        .and_then(|(y, x)| y.checked_mul(x))
+        .is_some_and(|count| count <= MAX_GRID_POINTS)
+}
+
+fuzz_target!(|data: &[u8]| {
+    for message in read_messages(data) {
+        let _ = message.key();
+        let _ = MessageMetadata::try_from(&message);
+        if is_small_grid(message.grid_dimensions().ok()) {
+            let _ = message.data();
+            let _ = message.latlng_projector().map(|projector| projector.lat_lng());
+        }
+    }
+
+    for _ in read_messages_resilient(data) {}
+    let _ = scan_message_metadata(data);
+
+    for message in read_grib1_messages(data) {
+        let _ = MessageMetadata::try_from(&message);
+        if is_small_grid(message.grid_dimensions().ok()) {
+            let _ = message.data();
+        }
+    }
+});
@@ -0,0 +1,112 @@
+use futures::io::{AsyncRead, AsyncReadExt};
+use futures::stream::{self, Stream};
+
+use crate::error::GribberishError;
+use crate::reader::{
+    message_framing, stream_ended, MessageFraming, OwnedMessage, GRIB1_INDICATOR_LENGTH,
+};
+
+/// Reads GRIB1 and GRIB2 messages one at a time from an async stream, framing them by the total
+/// length in their indicator section like [`GribReader`](crate::reader::GribReader). Tokio
+/// readers can be adapted with `tokio_util::compat`.
+pub struct AsyncGribReader<R> {
+    reader: R,
+    offset: usize,
+    finished: bool,
+}
+
+impl<R: AsyncRead + Unpin> AsyncGribReader<R> {
+    pub fn new(reader: R) -> Self {
+        AsyncGribReader {
+            reader,
+            offset: 0,
+            finished: false,
+        }
+    }
+
+    /// Byte offset in the stream of the next message to be read
+    pub fn current_offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads the next message, or `None` once the stream ends between messages
+    pub async fn read_message(&mut self) -> Result<Option<OwnedMessage>, GribberishError> {
+        let mut data = vec![0; GRIB1_INDICATOR_LENGTH];
+        if !self.read_or_eof(&mut data).await? {
+            return Ok(None);
+        }
+
+        let message_size = loop {
+            match message_framing(&data, self.offset)? {
+                MessageFraming::Missing(missing) => self.read_into(&mut data, missing).await?,
+                MessageFraming::Size(message_size) => break message_size,
+            }
+        };
+
+        let remaining = message_size - data.len();
+        self.read_into(&mut data, remaining).await?;
+
+        let message = OwnedMessage::new(data, self.offset);
+        self.offset += message_size;
+        Ok(Some(message))
+    }
+
+    /// A stream of the remaining messages. Messages are only read from the underlying reader as
+    /// the stream is polled, and the stream ends after the first error.
+    pub fn into_stream(self) -> impl Stream<Item = Result<OwnedMessage, GribberishError>> {
+        stream::unfold(self, |mut reader| async move {
+            if reader.finished {
+                return None;
+            }
+
+            match reader.read_message().await {
+                Ok(Some(message)) => Some((Ok(message), reader)),
+                Ok(None) => None,
+                Err(e) => {
+                    reader.finished = true;
+                    Some((Err(e), reader))
+                }
+            }
+        })
+    }
+
+    /// Fills `data` from the stream, returning `false` when the stream has already ended
+    async fn read_or_eof(&mut self, data: &mut [u8]) -> Result<bool, GribberishError> {
+        let mut filled = 0;
+        while filled < data.len() {
+            match self.reader.read(&mut data[filled..]).await {
+                Ok(0) if filled == 0 => return Ok(false),
+                Ok(0) => return Err(stream_ended(self.offset)),
+                Ok(read) => filled += read,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(GribberishError::IoError(e.to_string())),
+            }
+        }
+        Ok(true)
+    }
+
+    /// Appends the next `length` bytes of the stream to `data`
+    async fn read_into(&mut self, data: &mut Vec<u8>, length: usize) -> Result<(), GribberishError> {
+        let read = (&mut self.reader)
+            .take(length as u64)
+            .read_to_end(data)
+            .await
+            .map_err(|e| GribberishError::IoError(e.to_string()))?;
+
+        if read < length {
+            return Err(stream_ended(self.offset));
+        }
+        Ok(())
+    }
+}
+
+/// A stream of the GRIB messages read from `reader`
+pub fn read_grib_async_stream<R: AsyncRead + Unpin>(
+    reader: R,
+) -> impl Stream<Item = Result<OwnedMessage, GribberishError>> {
+    AsyncGribReader::new(reader).into_stream()
+}
@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+
+use crate::templates::product::tables::{
+    FixedSurfaceType, StatisticalProcessing, TypeOfStatisticalProcessing,
+};
+
+/// A netcdf attribute value as expected by the CF conventions
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttributeValue {
+    Text(String),
+    Number(f64),
+    Numbers(Vec<f64>),
+}
+
+impl From<&str> for AttributeValue {
+    fn from(value: &str) -> Self {
+        AttributeValue::Text(value.to_string())
+    }
+}
+
+impl From<f64> for AttributeValue {
+    fn from(value: f64) -> Self {
+        AttributeValue::Number(value)
+    }
+}
+
+impl std::fmt::Display for AttributeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttributeValue::Text(text) => write!(f, "{text}"),
+            AttributeValue::Number(number) => write!(f, "{number}"),
+            AttributeValue::Numbers(numbers) => write!(
+                f,
+                "{}",
+                numbers
+                    .iter()
+                    .map(|n| n.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ),
+        }
+    }
+}
+
+pub type Attributes = HashMap<&'static str, AttributeValue>;
+
+fn cell_method(process: &TypeOfStatisticalProcessing) -> Option<&'static str> {
+    match process {
+        TypeOfStatisticalProcessing::Average => Some("mean"),
+        TypeOfStatisticalProcessing::Accumulation => Some("sum"),
+        TypeOfStatisticalProcessing::Maximum => Some("maximum"),
+        TypeOfStatisticalProcessing::Minimum => Some("minimum"),
+        TypeOfStatisticalProcessing::RootMeanSquare => Some("root_mean_square"),
+        TypeOfStatisticalProcessing::StandardDeviation => Some("standard_deviation"),
+        TypeOfStatisticalProcessing::Summation => Some("sum"),
+        _ => None,
+    }
+}
+
+/// CF `cell_methods` over time for a statistical process. GRIB lists nested processes from the
+/// outermost in, while CF lists them in the order they were applied. Returns `None` when any of
+/// the processes has no CF equivalent, such as differences or ratios.
+pub fn cell_methods(statistical_processing: &StatisticalProcessing) -> Option<String> {
+    statistical_processing
+        .processes
+        .iter()
+        .rev()
+        .map(|p| cell_method(p).map(|method| format!("time: {method}")))
+        .collect::<Option<Vec<_>>>()
+        .filter(|methods| !methods.is_empty())
+        .map(|methods| methods.join(" "))
+}
+
+/// CF attributes for a vertical coordinate variable built from a fixed surface type, or `None`
+/// for single level surfaces that do not form a vertical coordinate
+pub fn vertical_coordinate_attributes(surface: &FixedSurfaceType) -> Option<Attributes> {
+    let (standard_name, units, positive) = match surface {
+        FixedSurfaceType::IsobaricSurface => (Some("air_pressure"), "Pa", "down"),
+        FixedSurfaceType::SpecifiedHeightLevelAboveGround => (Some("height"), "m", "up"),
+        FixedSurfaceType::SpecificAltitudeAboveMeanSeaLevel => (Some("altitude"), "m", "up"),
+        FixedSurfaceType::DepthBelowLandSurface => (Some("depth"), "m", "down"),
+        FixedSurfaceType::DepthBelowSeaLevel => (Some("depth"), "m", "down"),
+        FixedSurfaceType::DepthBelowWaterSurface => (Some("depth"), "m", "down"),
+        FixedSurfaceType::SigmaLevel => (Some("atmosphere_sigma_coordinate"), "1", "down"),
+        FixedSurfaceType::HybridLevel => (
+            Some("atmosphere_hybrid_sigma_pressure_coordinate"),
+            "1",
+            "down",
+        ),
+        FixedSurfaceType::LevelAtSpecifiedPressureDifferenceFromGroundToLevel => {
+            (None, "Pa", "down")
+        }
+        FixedSurfaceType::GeneralizedVerticalHeightCoordinate => (None, "m", "up"),
+        FixedSurfaceType::SigmaHeightLevel | FixedSurfaceType::EtaLevel => (None, "1", "down"),
+        s if s.is_vertical_level() => (None, s.unit(), "up"),
+        _ => return None,
+    };
+
+    let mut attributes = Attributes::from([
+        ("long_name", surface.to_string().as_str().into()),
+        ("units", units.into()),
+        ("positive", positive.into()),
+        ("axis", "Z".into()),
+    ]);
+    if let Some(standard_name) = standard_name {
+        attributes.insert("standard_name", standard_name.into());
+    }
+
+    Some(attributes)
+}
+
+/// CF `grid_mapping` variable attributes from a projection name and its proj parameters
+pub fn grid_mapping_attributes(proj_name: &str, params: &HashMap<String, f64>) -> Option<Attributes> {
+    let mut attributes = match proj_name {
+        "latlon" => Attributes::from([("grid_mapping_name", "latitude_longitude".into())]),
+        "lcc" => {
+            let mut attributes =
+                Attributes::from([("grid_mapping_name", "lambert_conformal_conic".into())]);
+            let standard_parallels = ["lat_1", "lat_2"]
+                .iter()
+                .filter_map(|k| params.get(*k).copied())
+                .collect::<Vec<_>>();
+            attributes.insert("standard_parallel", AttributeValue::Numbers(standard_parallels));
+            if let Some(lon_0) = params.get("lon_0") {
+                attributes.insert("longitude_of_central_meridian", (*lon_0).into());
+            }
+            if let Some(lat_0) = params.get("lat_0") {
+                attributes.insert("latitude_of_projection_origin", (*lat_0).into());
+            }
+            attributes
+        }
+        _ => return None,
+    };
+
+    match (params.get("a"), params.get("b")) {
+        (Some(a), Some(b)) if a == b => {
+            attributes.insert("earth_radius", (*a).into());
+        }
+        (Some(a), Some(b)) => {
+            attributes.insert("semi_major_axis", (*a).into());
+            attributes.insert("semi_minor_axis", (*b).into());
+        }
+        _ => {}
+    }
+
+    Some(attributes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_cell_methods() {
+        let daily_max_of_means = StatisticalProcessing {
+            processes: vec![
+                TypeOfStatisticalProcessing::Maximum,
+                TypeOfStatisticalProcessing::Average,
+            ],
+        };
+        assert_eq!(
+            cell_methods(&daily_max_of_means).as_deref(),
+            Some("time: mean time: maximum")
+        );
+
+        let difference: StatisticalProcessing = TypeOfStatisticalProcessing::Difference.into();
+        assert!(cell_methods(&difference).is_none());
+
+        let covariance: StatisticalProcessing = TypeOfStatisticalProcessing::Covariance.into();
+        assert!(cell_methods(&covariance).is_none());
+    }
+
+    #[test]
+    fn lambert_grid_mapping() {
+        let params = HashMap::from([
+            ("lat_0".to_string(), 38.5),
+            ("lat_1".to_string(), 38.5),
+            ("lat_2".to_string(), 38.5),
+            ("lon_0".to_string(), 262.5),
+            ("a".to_string(), 6371229.0),
+            ("b".to_string(), 6371229.0),
+        ]);
+        let attributes = grid_mapping_attributes("lcc", &params).unwrap();
+        assert_eq!(
+            attributes["grid_mapping_name"],
+            AttributeValue::Text("lambert_conformal_conic".into())
+        );
+        assert_eq!(
+            attributes["standard_parallel"],
+            AttributeValue::Numbers(vec![38.5, 38.5])
+        );
+        assert_eq!(attributes["earth_radius"], AttributeValue::Number(6371229.0));
+    }
+}
@@ -1,11 +1,32 @@
 use std::convert::TryFrom;
-use crate::{error::GribberishError, message::Message, message_metadata::MessageMetadata};
+use crate::{error::GribberishError, grib1::Grib1Message, message::{read_message_data, Message}, message_metadata::{read_message_metadata, MessageMetadata}, units::convert_values};
 
 pub struct DataMessage {
     pub metadata: MessageMetadata,
     pub data: Vec<f64>
 }
 
+impl DataMessage {
+    /// Converts the data to the `target` unit, updating the units in the metadata. Fails when the
+    /// units cannot be parsed or have incompatible dimensions.
+    pub fn convert_units(&self, target: &str) -> Result<DataMessage, GribberishError> {
+        let data = convert_values(&self.data, &self.metadata.units, target)?;
+        let mut metadata = self.metadata.clone();
+        metadata.units = target.to_string();
+
+        Ok(DataMessage { metadata, data })
+    }
+}
+
+/// Reads a field of the message at `offset` along with its data, reading GRIB1 messages as a
+/// single field
+pub fn read_data_message(data: &[u8], offset: usize, field: usize) -> Result<DataMessage, GribberishError> {
+    Ok(DataMessage {
+        metadata: read_message_metadata(data, offset, field)?,
+        data: read_message_data(data, offset, field)?,
+    })
+}
+
 impl <'a> TryFrom<&Message<'a>> for DataMessage {
     type Error = GribberishError;
 
@@ -28,3 +49,15 @@ impl <'a> TryFrom<(&Message<'a>, &MessageMetadata)> for DataMessage {
         })
     }
 }
+
+impl <'a> TryFrom<&Grib1Message<'a>> for DataMessage {
+    type Error = GribberishError;
+
+    fn try_from(message: &Grib1Message) -> Result<Self, Self::Error> {
+        let metadata = MessageMetadata::try_from(message)?;
+        Ok(DataMessage {
+            metadata,
+            data: message.data()?,
+        })
+    }
+}
@@ -1,15 +1,155 @@
 use thiserror::Error;
 
+/// Broad classes of errors, telling valid data this crate can not read apart from broken data
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// Valid GRIB using a template, parameter or encoding that is not supported
+    Unsupported,
+    /// Truncated, inconsistent or otherwise invalid GRIB data
+    Corrupt,
+    /// Failure reading from the underlying stream
+    Io,
+    /// Invalid arguments, such as unknown units
+    InvalidInput,
+}
+
 #[derive(Clone, Debug, Error)]
 pub enum GribberishError {
     #[error("Error reading data representation template metadata: `{0}`")]
     DataRepresentationTemplateError(String),
     #[error("Error reading grid template metadata: `{0}`")]
     GridTemplateError(String),
-    #[error("Error decoding JPEG2000 data: `{0}`")]
-    JpegError(String),
+    #[error("Error reading from stream: `{0}`")]
+    IoError(String),
     #[error("Error reading message: `{0}`")]
     MessageError(String),
     #[error("Unknown time unit: `{0}`")]
     TimeUnitError(String),
+    #[error("Error converting units: `{0}`")]
+    UnitError(String),
+    #[error("Template {section}.{template} is not supported")]
+    UnsupportedTemplate { section: u8, template: u16 },
+    #[error("Parameter ({discipline}, {category}, {number}) is not supported")]
+    UnsupportedParameter { discipline: u8, category: u8, number: u8 },
+    #[error("{0} is not supported")]
+    Unsupported(String),
+    #[error("Section {section} not found")]
+    MissingSection { section: u8 },
+    /// A section too short for its contents, `offset` bytes into its message, or into the section
+    /// data when the section is read on its own
+    #[error("Section {section} at byte {offset} is truncated, holding {length} of the {expected} bytes expected")]
+    TruncatedSection { section: u8, offset: usize, length: usize, expected: usize },
+    #[error("Packed data is truncated, holding {available} of the {expected} bits expected")]
+    TruncatedData { available: usize, expected: usize },
+    #[error("Error decoding {codec} data: `{reason}`")]
+    DecodeError { codec: &'static str, reason: String },
+    #[error("Bitmap of {length} bits does not cover the {expected} grid points")]
+    InvalidBitmapLength { length: usize, expected: usize },
+    #[error("Error in message at byte offset {byte_offset}{}", .message_index.map(|i| format!(", index {i}")).unwrap_or_default())]
+    InMessage {
+        message_index: Option<usize>,
+        byte_offset: usize,
+        source: Box<GribberishError>,
+    },
+}
+
+impl GribberishError {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            GribberishError::UnsupportedTemplate { .. }
+            | GribberishError::UnsupportedParameter { .. }
+            | GribberishError::Unsupported(_) => ErrorKind::Unsupported,
+            GribberishError::IoError(_) => ErrorKind::Io,
+            GribberishError::TimeUnitError(_) | GribberishError::UnitError(_) => ErrorKind::InvalidInput,
+            GribberishError::InMessage { source, .. } => source.kind(),
+            _ => ErrorKind::Corrupt,
+        }
+    }
+
+    /// Attaches the position of the message the error occurred in. The outermost context wins, so
+    /// callers that know the position in the whole file override offsets into a message buffer.
+    pub fn in_message(self, message_index: Option<usize>, byte_offset: usize) -> Self {
+        match self {
+            GribberishError::InMessage {
+                message_index: inner_index,
+                source,
+                ..
+            } => GribberishError::InMessage {
+                message_index: message_index.or(inner_index),
+                byte_offset,
+                source,
+            },
+            source => GribberishError::InMessage {
+                message_index,
+                byte_offset,
+                source: Box::new(source),
+            },
+        }
+    }
+
+    /// Places a truncated section that was read on its own at `section_offset` bytes into its
+    /// message
+    pub(crate) fn at_section_offset(self, section_offset: usize) -> Self {
+        match self {
+            GribberishError::TruncatedSection { section, offset, length, expected } => {
+                GribberishError::TruncatedSection {
+                    section,
+                    offset: section_offset + offset,
+                    length,
+                    expected,
+                }
+            }
+            error => error,
+        }
+    }
+
+    /// Index of the message the error occurred in, when read from an iterator
+    pub fn message_index(&self) -> Option<usize> {
+        match self {
+            GribberishError::InMessage { message_index, .. } => *message_index,
+            _ => None,
+        }
+    }
+
+    /// Byte offset of the message the error occurred in
+    pub fn byte_offset(&self) -> Option<usize> {
+        match self {
+            GribberishError::InMessage { byte_offset, .. } => Some(*byte_offset),
+            _ => None,
+        }
+    }
+
+    /// The error without its message context
+    pub fn root(&self) -> &GribberishError {
+        match self {
+            GribberishError::InMessage { source, .. } => source.root(),
+            error => error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ErrorKind, GribberishError};
+
+    #[test]
+    fn error_context() {
+        let error = GribberishError::UnsupportedTemplate { section: 4, template: 5 }
+            .in_message(Some(3), 120)
+            .in_message(None, 4096);
+
+        assert_eq!(error.kind(), ErrorKind::Unsupported);
+        assert_eq!(error.message_index(), Some(3));
+        assert_eq!(error.byte_offset(), Some(4096));
+        assert!(matches!(
+            error.root(),
+            GribberishError::UnsupportedTemplate { section: 4, template: 5 }
+        ));
+        assert_eq!(error.to_string(), "Error in message at byte offset 4096, index 3");
+
+        // The underlying error is only given as the source, so printing the chain shows it once
+        let source = std::error::Error::source(&error).unwrap();
+        assert_eq!(source.to_string(), "Template 4.5 is not supported");
+        assert!(source.source().is_none());
+    }
 }
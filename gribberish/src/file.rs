@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+
+use memmap2::Mmap;
+
+use crate::data_message::{read_data_message, DataMessage};
+use crate::error::GribberishError;
+use crate::grib1::{read_grib1_messages, Grib1Message, Grib1MessageIterator};
+use crate::message::{read_message_data, read_messages, Message, MessageIterator};
+use crate::message_metadata::{
+    iter_message_metadata, read_message_metadata, scan_message_metadata, MessageMetadata,
+};
+
+/// A GRIB file mapped into memory, so messages are paged in from disk as they are read instead
+/// of loading the whole file up front. Messages borrow from the map and can be read at any byte
+/// offset, such as the offsets recorded in an index.
+pub struct GribFile {
+    mmap: Mmap,
+}
+
+impl GribFile {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, GribberishError> {
+        let file = File::open(path).map_err(|e| GribberishError::IoError(e.to_string()))?;
+
+        // The map is read only, but its contents change if the file is modified while it is mapped
+        let mmap = unsafe { Mmap::map(&file) }.map_err(|e| GribberishError::IoError(e.to_string()))?;
+
+        Ok(GribFile { mmap })
+    }
+
+    pub fn byte_data(&self) -> &[u8] {
+        &self.mmap
+    }
+
+    pub fn len(&self) -> usize {
+        self.mmap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mmap.is_empty()
+    }
+
+    /// Iterates the fields of every GRIB2 message in the file. GRIB1 messages are skipped, read
+    /// them with [`GribFile::grib1_messages`], or use [`GribFile::metadata`] for both editions.
+    pub fn messages(&self) -> MessageIterator<'_> {
+        read_messages(self.byte_data())
+    }
+
+    /// Iterates every GRIB1 message in the file, skipping GRIB2 messages
+    pub fn grib1_messages(&self) -> Grib1MessageIterator<'_> {
+        read_grib1_messages(self.byte_data())
+    }
+
+    /// Iterates the metadata of every field of every message in the file, GRIB1 and GRIB2 alike
+    pub fn metadata(&self) -> impl Iterator<Item = Result<MessageMetadata, GribberishError>> + '_ {
+        iter_message_metadata(self.byte_data())
+    }
+
+    /// The metadata of every field in the file keyed by message key, see
+    /// [`scan_message_metadata`]
+    pub fn scan_metadata(&self) -> HashMap<String, (usize, usize, MessageMetadata)> {
+        scan_message_metadata(self.byte_data())
+    }
+
+    /// The GRIB2 message starting at `offset`
+    pub fn message(&self, offset: usize) -> Option<Message<'_>> {
+        Message::from_data(self.byte_data(), offset)
+    }
+
+    /// The GRIB1 message starting at `offset`
+    pub fn grib1_message(&self, offset: usize) -> Option<Grib1Message<'_>> {
+        Grib1Message::from_data(self.byte_data(), offset)
+    }
+
+    /// Reads the metadata of a field of the message starting at `offset`
+    pub fn message_metadata(&self, offset: usize, field: usize) -> Result<MessageMetadata, GribberishError> {
+        read_message_metadata(self.byte_data(), offset, field)
+    }
+
+    /// Decodes the data of a field of the message starting at `offset`
+    pub fn message_data(&self, offset: usize, field: usize) -> Result<Vec<f64>, GribberishError> {
+        read_message_data(self.byte_data(), offset, field)
+    }
+
+    pub fn data_message(&self, offset: usize, field: usize) -> Result<DataMessage, GribberishError> {
+        read_data_message(self.byte_data(), offset, field)
+    }
+}
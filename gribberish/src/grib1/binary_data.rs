@@ -0,0 +1,143 @@
+use bitvec::prelude::*;
+
+use crate::error::GribberishError;
+use crate::sections::grib_section::{check_section_length, GribSection};
+use crate::utils::iter::ScaleGribValueIterator;
+use crate::utils::{read_u16_from_bytes, read_u24_from_bytes, read_u32_from_bytes};
+
+/// Length of the binary data section header preceding the packed values
+pub const BINARY_DATA_HEADER_LENGTH: usize = 11;
+
+/// GRIB1 section 4, the binary data section (BDS)
+pub struct BinaryDataSection<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> BinaryDataSection<'a> {
+    /// The section stored in `data`, failing when it is too short for the accessors
+    pub fn from_data(data: &'a [u8]) -> Result<Self, GribberishError> {
+        check_section_length(4, data, BINARY_DATA_HEADER_LENGTH)?;
+        Ok(BinaryDataSection { data })
+    }
+
+    /// Flags of code table 11 in the upper four bits of octet 4
+    pub fn flags(&self) -> u8 {
+        self.data[3] >> 4
+    }
+
+    pub fn is_spherical_harmonics(&self) -> bool {
+        self.flags() & 0b1000 > 0
+    }
+
+    pub fn is_complex_packing(&self) -> bool {
+        self.flags() & 0b0100 > 0
+    }
+
+    pub fn unused_bit_count(&self) -> u8 {
+        self.data[3] & 0x0f
+    }
+
+    pub fn binary_scale_factor(&self) -> i16 {
+        let raw = read_u16_from_bytes(self.data, 4).unwrap_or(0);
+        as_signed!(raw, 16, i16)
+    }
+
+    pub fn reference_value(&self) -> f32 {
+        ibm_to_f32(read_u32_from_bytes(self.data, 6).unwrap_or(0))
+    }
+
+    pub fn bits_per_value(&self) -> u8 {
+        self.data[10]
+    }
+
+    pub fn packed_data(&self) -> &'a [u8] {
+        &self.data[BINARY_DATA_HEADER_LENGTH..]
+    }
+
+    pub fn compression_type(&self) -> String {
+        match (self.is_spherical_harmonics(), self.is_complex_packing()) {
+            (false, false) => "Simple Packing".to_string(),
+            (false, true) => "Second Order Packing".to_string(),
+            (true, false) => "Spherical Harmonics Simple Packing".to_string(),
+            (true, true) => "Spherical Harmonics Complex Packing".to_string(),
+        }
+    }
+
+    /// Unpacks `count` grid point values packed with simple packing, applying the decimal scale
+    /// factor from the product definition section
+    pub fn unpack(&self, count: usize, decimal_scale_factor: i16) -> Result<Vec<f64>, GribberishError> {
+        if self.is_spherical_harmonics() || self.is_complex_packing() {
+            return Err(GribberishError::Unsupported(format!(
+                "GRIB1 {}",
+                self.compression_type().to_lowercase()
+            )));
+        }
+
+        let bits_per_value = self.bits_per_value() as usize;
+        let values = std::iter::repeat_n(0u32, count);
+        if bits_per_value == 0 {
+            return Ok(values
+                .scale_value_by(
+                    self.binary_scale_factor(),
+                    decimal_scale_factor,
+                    self.reference_value(),
+                )
+                .collect());
+        }
+
+        if bits_per_value > 32 {
+            return Err(GribberishError::Unsupported(format!(
+                "GRIB1 values packed with {bits_per_value} bits"
+            )));
+        }
+
+        let bits = self.packed_data().view_bits::<Msb0>();
+        if bits.len() < count * bits_per_value {
+            return Err(GribberishError::TruncatedData {
+                available: bits.len(),
+                expected: count * bits_per_value,
+            });
+        }
+
+        Ok(bits
+            .chunks_exact(bits_per_value)
+            .take(count)
+            .map(|chunk| chunk.load_be::<u32>())
+            .scale_value_by(
+                self.binary_scale_factor(),
+                decimal_scale_factor,
+                self.reference_value(),
+            )
+            .collect())
+    }
+}
+
+impl GribSection for BinaryDataSection<'_> {
+    fn len(&self) -> usize {
+        read_u24_from_bytes(self.data, 0).unwrap_or(0) as usize
+    }
+
+    fn number(&self) -> u8 {
+        4
+    }
+}
+
+/// Converts an IBM System/360 single precision float, as used for GRIB1 reference values
+pub fn ibm_to_f32(value: u32) -> f32 {
+    let sign = if value & 0x8000_0000 > 0 { -1.0 } else { 1.0 };
+    let exponent = ((value >> 24) & 0x7f) as i32 - 64;
+    let mantissa = (value & 0x00ff_ffff) as f64 / (1 << 24) as f64;
+    (sign * mantissa * 16f64.powi(exponent)) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ibm_to_f32;
+
+    #[test]
+    fn convert_ibm_floats() {
+        assert_eq!(ibm_to_f32(0x4110_0000), 1.0);
+        assert_eq!(ibm_to_f32(0xc276_a000), -118.625);
+        assert_eq!(ibm_to_f32(0), 0.0);
+    }
+}
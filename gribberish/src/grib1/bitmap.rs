@@ -0,0 +1,69 @@
+use bitvec::prelude::*;
+
+use crate::error::GribberishError;
+use crate::sections::grib_section::{check_section_length, GribSection};
+use crate::utils::{read_u16_from_bytes, read_u24_from_bytes};
+
+/// Length of the bit map section header preceding the bitmap
+pub const BITMAP_HEADER_LENGTH: usize = 6;
+
+/// GRIB1 section 3, the bit map section (BMS)
+pub struct BitmapSection<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> BitmapSection<'a> {
+    /// The section stored in `data`, failing when it is too short for the accessors
+    pub fn from_data(data: &'a [u8]) -> Result<Self, GribberishError> {
+        check_section_length(3, data, BITMAP_HEADER_LENGTH)?;
+        Ok(BitmapSection { data })
+    }
+
+    pub fn unused_bit_count(&self) -> u8 {
+        self.data[3]
+    }
+
+    /// Zero when the bitmap follows, otherwise the number of a bitmap predefined by the centre
+    pub fn predefined_bitmap(&self) -> u16 {
+        read_u16_from_bytes(self.data, 4).unwrap_or(0)
+    }
+
+    pub fn raw_bitmap_data(&self) -> &'a [u8] {
+        &self.data[6..]
+    }
+
+    /// Spreads the values of the points present in the bitmap over the full grid, filling the
+    /// missing points with NaN
+    pub fn map_data(&self, unmapped_data: Vec<f64>, point_count: usize) -> Vec<f64> {
+        let bitmask = self.raw_bitmap_data().view_bits::<Msb0>();
+        let mut values = unmapped_data.into_iter();
+
+        bitmask
+            .iter()
+            .take(point_count)
+            .map(|mask| match *mask {
+                true => values.next().unwrap_or(f64::NAN),
+                false => f64::NAN,
+            })
+            .collect()
+    }
+
+    pub fn present_count(&self, point_count: usize) -> usize {
+        self.raw_bitmap_data()
+            .view_bits::<Msb0>()
+            .iter()
+            .take(point_count)
+            .filter(|b| **b)
+            .count()
+    }
+}
+
+impl GribSection for BitmapSection<'_> {
+    fn len(&self) -> usize {
+        read_u24_from_bytes(self.data, 0).unwrap_or(0) as usize
+    }
+
+    fn number(&self) -> u8 {
+        3
+    }
+}
@@ -0,0 +1,482 @@
+use std::collections::HashMap;
+
+use mappers::{projections::LambertConformalConic, Ellipsoid, Projection};
+
+use crate::error::GribberishError;
+use crate::sections::grib_section::{check_section_length, GribSection};
+use crate::templates::grid_definition::tables::{ProjectionCenter, ScanningMode, ScanningModeFlags};
+use crate::utils::iter::projection::{
+    GaussianProjection, LambertConformalConicProjection, LatLngProjection, PlateCareeProjection,
+    PolarStereographic, PolarStereographicProjection, RegularCoordinateIterator,
+};
+use crate::utils::{read_u16_from_bytes, read_u24_from_bytes};
+
+/// Minimum length of the grid description section for the supported grids
+pub const GRID_DESCRIPTION_MIN_LENGTH: usize = 32;
+
+const SPHERICAL_EARTH_RADIUS: f64 = 6_367_470.0;
+const IAU_MAJOR_AXIS: f64 = 6_378_160.0;
+const IAU_MINOR_AXIS: f64 = 6_356_775.0;
+
+/// Latitude of true scale of GRIB1 polar stereographic grids
+const POLAR_STEREOGRAPHIC_TRUE_LATITUDE: f64 = 60.0;
+
+/// Data representation type, code table 6
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Grib1GridType {
+    LatLng,
+    LambertConformal,
+    Gaussian,
+    PolarStereographic,
+    Other(u8),
+}
+
+impl From<u8> for Grib1GridType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Grib1GridType::LatLng,
+            3 => Grib1GridType::LambertConformal,
+            4 => Grib1GridType::Gaussian,
+            5 => Grib1GridType::PolarStereographic,
+            other => Grib1GridType::Other(other),
+        }
+    }
+}
+
+/// GRIB1 section 2, the grid description section (GDS)
+pub struct GridDescriptionSection<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> GridDescriptionSection<'a> {
+    /// The section stored in `data`, failing when it is too short for the accessors
+    pub fn from_data(data: &'a [u8]) -> Result<Self, GribberishError> {
+        check_section_length(2, data, GRID_DESCRIPTION_MIN_LENGTH)?;
+        Ok(GridDescriptionSection { data })
+    }
+
+    pub fn grid_type(&self) -> Grib1GridType {
+        self.data[5].into()
+    }
+
+    fn u16_at(&self, offset: usize) -> u16 {
+        read_u16_from_bytes(self.data, offset).unwrap_or(0)
+    }
+
+    fn u24_at(&self, offset: usize) -> u32 {
+        read_u24_from_bytes(self.data, offset).unwrap_or(0)
+    }
+
+    /// Reads a sign and magnitude coordinate in millidegrees
+    fn degrees_at(&self, offset: usize) -> f64 {
+        let raw = self.u24_at(offset);
+        as_signed!(raw, 24, i32) as f64 / 1000.0
+    }
+
+    /// Number of points along a parallel, or along the x axis of projected grids
+    pub fn x_count(&self) -> usize {
+        self.u16_at(6) as usize
+    }
+
+    /// Number of points along a meridian, or along the y axis of projected grids
+    pub fn y_count(&self) -> usize {
+        self.u16_at(8) as usize
+    }
+
+    pub fn latitude_of_first_grid_point(&self) -> f64 {
+        self.degrees_at(10)
+    }
+
+    pub fn longitude_of_first_grid_point(&self) -> f64 {
+        self.degrees_at(13)
+    }
+
+    pub fn resolution_and_component_flags(&self) -> u8 {
+        self.data[16]
+    }
+
+    pub fn has_direction_increments(&self) -> bool {
+        self.resolution_and_component_flags() & 0x80 > 0
+    }
+
+    /// Whether the earth is the IAU 1965 oblate spheroid rather than a sphere of radius 6367.47 km
+    pub fn is_oblate_earth(&self) -> bool {
+        self.resolution_and_component_flags() & 0x40 > 0
+    }
+
+    pub fn scanning_mode_flags(&self) -> ScanningModeFlags {
+        ScanningMode::read_flags(self.data[27])
+    }
+
+    pub fn latitude_of_last_grid_point(&self) -> f64 {
+        self.degrees_at(17)
+    }
+
+    pub fn longitude_of_last_grid_point(&self) -> f64 {
+        self.degrees_at(20)
+    }
+
+    /// Longitude increment of regular and gaussian grids, derived from the first and last points
+    /// when it is not given
+    pub fn i_direction_increment(&self) -> f64 {
+        let increment = self.u16_at(23);
+        if self.has_direction_increments() && increment != u16::MAX {
+            return increment as f64 / 1000.0;
+        }
+
+        let mut span = self.longitude_of_last_grid_point() - self.longitude_of_first_grid_point();
+        if self.scanning_mode_flags()[0] == ScanningMode::MinusI {
+            span = -span;
+        }
+        if span < 0.0 {
+            span += 360.0;
+        }
+        span / (self.x_count().max(2) - 1) as f64
+    }
+
+    /// Latitude increment of regular grids, derived from the first and last points when it is not
+    /// given
+    pub fn j_direction_increment(&self) -> f64 {
+        let increment = self.u16_at(25);
+        if self.has_direction_increments() && increment != u16::MAX {
+            return increment as f64 / 1000.0;
+        }
+
+        let span = self.latitude_of_last_grid_point() - self.latitude_of_first_grid_point();
+        span.abs() / (self.y_count().max(2) - 1) as f64
+    }
+
+    /// Number of parallels between a pole and the equator of gaussian grids
+    pub fn gaussian_parallel_count(&self) -> usize {
+        self.u16_at(25) as usize
+    }
+
+    /// Orientation of projected grids, the meridian parallel to the y axis
+    pub fn orientation_longitude(&self) -> f64 {
+        self.degrees_at(17)
+    }
+
+    /// Grid length along the x axis of projected grids in metres
+    pub fn x_direction_grid_length(&self) -> f64 {
+        self.u24_at(20) as f64
+    }
+
+    /// Grid length along the y axis of projected grids in metres
+    pub fn y_direction_grid_length(&self) -> f64 {
+        self.u24_at(23) as f64
+    }
+
+    pub fn projection_centre(&self) -> ProjectionCenter {
+        match self.data[26] & 0x80 {
+            0 => ProjectionCenter::NorthPole,
+            _ => ProjectionCenter::SouthPole,
+        }
+    }
+
+    pub fn latin_1(&self) -> f64 {
+        self.degrees_at(28)
+    }
+
+    pub fn latin_2(&self) -> f64 {
+        self.degrees_at(31)
+    }
+
+    fn earth_axes(&self) -> (f64, f64) {
+        if self.is_oblate_earth() {
+            (IAU_MAJOR_AXIS, IAU_MINOR_AXIS)
+        } else {
+            (SPHERICAL_EARTH_RADIUS, SPHERICAL_EARTH_RADIUS)
+        }
+    }
+
+    fn earth_ellipsoid(&self) -> Ellipsoid {
+        let (a, b) = self.earth_axes();
+        let f = (a - b) / a;
+        Ellipsoid {
+            A: a,
+            B: b,
+            E: (2.0 * f - f * f).sqrt(),
+            F: f,
+        }
+    }
+
+    fn x_step(&self, increment: f64) -> f64 {
+        if self.scanning_mode_flags()[0] == ScanningMode::PlusI {
+            increment
+        } else {
+            -increment
+        }
+    }
+
+    fn y_step(&self, increment: f64) -> f64 {
+        if self.scanning_mode_flags()[1] == ScanningMode::PlusJ {
+            increment
+        } else {
+            -increment
+        }
+    }
+
+    pub fn is_regular_grid(&self) -> bool {
+        matches!(self.grid_type(), Grib1GridType::LatLng | Grib1GridType::Gaussian)
+    }
+
+    pub fn proj_name(&self) -> String {
+        match self.grid_type() {
+            Grib1GridType::LambertConformal => "lcc".to_string(),
+            Grib1GridType::PolarStereographic => "stere".to_string(),
+            _ => "latlon".to_string(),
+        }
+    }
+
+    pub fn proj_params(&self) -> HashMap<String, f64> {
+        let (a, b) = self.earth_axes();
+        let mut params = HashMap::from([("a".to_string(), a), ("b".to_string(), b)]);
+
+        match self.grid_type() {
+            Grib1GridType::LambertConformal => {
+                params.insert("lon_0".to_string(), self.orientation_longitude());
+                params.insert("lat_0".to_string(), self.latin_1());
+                params.insert("lat_1".to_string(), self.latin_1());
+                params.insert("lat_2".to_string(), self.latin_2());
+            }
+            Grib1GridType::PolarStereographic => {
+                let sign = self.pole_sign();
+                params.insert("lon_0".to_string(), self.orientation_longitude());
+                params.insert("lat_0".to_string(), sign * 90.0);
+                params.insert("lat_ts".to_string(), sign * POLAR_STEREOGRAPHIC_TRUE_LATITUDE);
+            }
+            _ => {}
+        }
+
+        params
+    }
+
+    pub fn proj_string(&self) -> String {
+        let (a, b) = self.earth_axes();
+        match self.grid_type() {
+            Grib1GridType::LambertConformal => format!(
+                "+proj=lcc +lon_0={} +lat_0={} +lat_1={} +lat_2={} +a={a} +b={b}",
+                self.orientation_longitude(),
+                self.latin_1(),
+                self.latin_1(),
+                self.latin_2()
+            ),
+            Grib1GridType::PolarStereographic => format!(
+                "+proj=stere +lat_0={} +lat_ts={} +lon_0={} +a={a} +b={b}",
+                self.pole_sign() * 90.0,
+                self.pole_sign() * POLAR_STEREOGRAPHIC_TRUE_LATITUDE,
+                self.orientation_longitude()
+            ),
+            _ => format!("+proj=latlon +a={a} +b={b}"),
+        }
+    }
+
+    pub fn crs(&self) -> String {
+        if self.is_regular_grid() {
+            "EPSG:4326".to_string()
+        } else {
+            self.proj_string()
+        }
+    }
+
+    fn pole_sign(&self) -> f64 {
+        match self.projection_centre() {
+            ProjectionCenter::SouthPole => -1.0,
+            _ => 1.0,
+        }
+    }
+
+    pub fn projector(&self) -> Result<LatLngProjection, GribberishError> {
+        match self.grid_type() {
+            Grib1GridType::LatLng => Ok(LatLngProjection::PlateCaree(PlateCareeProjection {
+                latitudes: RegularCoordinateIterator::new(
+                    self.latitude_of_first_grid_point(),
+                    self.y_step(self.j_direction_increment()),
+                    self.y_count(),
+                ),
+                longitudes: self.longitudes(),
+                projection_name: self.proj_name(),
+                projection_params: self.proj_params(),
+            })),
+            Grib1GridType::Gaussian => Ok(LatLngProjection::Gaussian(GaussianProjection {
+                latitudes: self.gaussian_grid_latitudes()?,
+                longitudes: self.longitudes(),
+                projection_name: self.proj_name(),
+                projection_params: self.proj_params(),
+            })),
+            Grib1GridType::LambertConformal => {
+                let projection = LambertConformalConic::new(
+                    normalize_longitude(self.orientation_longitude()),
+                    self.latin_1(),
+                    self.latin_1(),
+                    self.latin_2(),
+                    self.earth_ellipsoid(),
+                )
+                .map_err(|e| {
+                    GribberishError::GridTemplateError(format!(
+                        "Failed to create lambert conformal conic projection: {e}"
+                    ))
+                })?;
+                let (x, y) = self.projected_axes(&projection)?;
+
+                Ok(LatLngProjection::LambertConformal(LambertConformalConicProjection {
+                    x,
+                    y,
+                    projection,
+                    projection_name: self.proj_name(),
+                    projection_params: self.proj_params(),
+                }))
+            }
+            Grib1GridType::PolarStereographic => {
+                let projection = PolarStereographic::new(
+                    normalize_longitude(self.orientation_longitude()),
+                    POLAR_STEREOGRAPHIC_TRUE_LATITUDE,
+                    self.earth_axes().0,
+                    self.projection_centre() == ProjectionCenter::SouthPole,
+                );
+                let (x, y) = self.projected_axes(&projection)?;
+
+                Ok(LatLngProjection::PolarStereographic(PolarStereographicProjection {
+                    x,
+                    y,
+                    projection,
+                    projection_name: self.proj_name(),
+                    projection_params: self.proj_params(),
+                }))
+            }
+            Grib1GridType::Other(value) => Err(GribberishError::Unsupported(format!(
+                "GRIB1 data representation type {value}"
+            ))),
+        }
+    }
+
+    fn longitudes(&self) -> RegularCoordinateIterator {
+        RegularCoordinateIterator::new(
+            self.longitude_of_first_grid_point(),
+            self.x_step(self.i_direction_increment()),
+            self.x_count(),
+        )
+    }
+
+    fn projected_axes<P: Projection>(
+        &self,
+        projection: &P,
+    ) -> Result<(RegularCoordinateIterator, RegularCoordinateIterator), GribberishError> {
+        let (start_x, start_y) = projection
+            .project(
+                normalize_longitude(self.longitude_of_first_grid_point()),
+                self.latitude_of_first_grid_point(),
+            )
+            .map_err(|e| {
+                GribberishError::GridTemplateError(format!(
+                    "Failed to project the first grid point: {e}"
+                ))
+            })?;
+
+        Ok((
+            RegularCoordinateIterator::new(
+                start_x,
+                self.x_step(self.x_direction_grid_length()),
+                self.x_count(),
+            ),
+            RegularCoordinateIterator::new(
+                start_y,
+                self.y_step(self.y_direction_grid_length()),
+                self.y_count(),
+            ),
+        ))
+    }
+
+    /// The latitudes of the grid rows, starting at the gaussian latitude closest to the first
+    /// grid point so that sub areas of global gaussian grids are supported
+    fn gaussian_grid_latitudes(&self) -> Result<Vec<f64>, GribberishError> {
+        let mut latitudes = gaussian_latitudes(self.gaussian_parallel_count());
+        if self.scanning_mode_flags()[1] == ScanningMode::PlusJ {
+            latitudes.reverse();
+        }
+
+        let first = self.latitude_of_first_grid_point();
+        let start = latitudes
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (*a - first).abs().total_cmp(&(*b - first).abs()))
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+
+        latitudes
+            .get(start..start + self.y_count())
+            .map(|l| l.to_vec())
+            .ok_or_else(|| {
+                GribberishError::GridTemplateError(format!(
+                    "Gaussian grid with {} parallels between pole and equator has fewer than {} rows",
+                    self.gaussian_parallel_count(),
+                    self.y_count()
+                ))
+            })
+    }
+}
+
+impl GribSection for GridDescriptionSection<'_> {
+    fn len(&self) -> usize {
+        read_u24_from_bytes(self.data, 0).unwrap_or(0) as usize
+    }
+
+    fn number(&self) -> u8 {
+        2
+    }
+}
+
+fn normalize_longitude(longitude: f64) -> f64 {
+    if longitude > 180.0 {
+        longitude - 360.0
+    } else {
+        longitude
+    }
+}
+
+/// Latitudes of a global gaussian grid with `n` parallels between a pole and the equator, from
+/// north to south. These are the roots of the Legendre polynomial of degree 2n.
+pub fn gaussian_latitudes(n: usize) -> Vec<f64> {
+    let count = 2 * n;
+    (0..count)
+        .map(|i| {
+            let mut x = (std::f64::consts::PI * (i as f64 + 0.75) / (count as f64 + 0.5)).cos();
+            for _ in 0..100 {
+                let (p, dp) = legendre(count, x);
+                let dx = p / dp;
+                x -= dx;
+                if dx.abs() < 1e-15 {
+                    break;
+                }
+            }
+            x.asin().to_degrees()
+        })
+        .collect()
+}
+
+/// The Legendre polynomial of degree `n` at `x` along with its derivative
+fn legendre(n: usize, x: f64) -> (f64, f64) {
+    let (mut p0, mut p1) = (1.0, x);
+    for k in 2..=n {
+        let k = k as f64;
+        let p2 = ((2.0 * k - 1.0) * x * p1 - (k - 1.0) * p0) / k;
+        p0 = p1;
+        p1 = p2;
+    }
+    let dp = n as f64 * (x * p1 - p0) / (x * x - 1.0);
+    (p1, dp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::gaussian_latitudes;
+
+    #[test]
+    fn gaussian_latitudes_are_symmetric() {
+        let latitudes = gaussian_latitudes(48);
+        assert_eq!(latitudes.len(), 96);
+        assert!((latitudes[0] - 88.572169).abs() < 1e-5);
+        assert!((latitudes[0] + latitudes[95]).abs() < 1e-9);
+        assert!(latitudes.windows(2).all(|w| w[0] > w[1]));
+    }
+}
@@ -0,0 +1,438 @@
+use chrono::{DateTime, Utc};
+use gribberish_types::Parameter;
+
+use crate::error::GribberishError;
+use crate::message::{has_message_length, message_key};
+use crate::reader::{is_large_grib1_message, message_size};
+use crate::sections::grib_section::GribSection;
+use crate::sections::indicator::{message_edition, Discipline};
+use crate::sections::identification::OriginatingCentre;
+use crate::templates::grid_definition::tables::ScanningMode;
+use crate::templates::product::parameters::category;
+use crate::templates::product::parameters::eccodes::{
+    eccodes_parameter, eccodes_parameter_by_table_128, EccodesParameter,
+};
+use crate::templates::product::tables::{
+    FixedSurfaceType, GeneratingProcess, StatisticalProcessing, TimeUnit, TypeOfStatisticalProcessing,
+};
+use crate::utils::iter::projection::LatLngProjection;
+use crate::utils::read_u24_from_bytes;
+
+use super::binary_data::{BinaryDataSection, BINARY_DATA_HEADER_LENGTH};
+use super::bitmap::{BitmapSection, BITMAP_HEADER_LENGTH};
+use super::grid_description::{GridDescriptionSection, GRID_DESCRIPTION_MIN_LENGTH};
+use super::parameters::{grib1_parameter, grib2_equivalent};
+use super::product_definition::{ProductDefinitionSection, PRODUCT_DEFINITION_MIN_LENGTH};
+
+/// Length of the GRIB1 indicator section
+const INDICATOR_LENGTH: usize = 8;
+
+/// Iterates the GRIB1 messages in the data, skipping over messages of other editions
+pub struct Grib1MessageIterator<'a> {
+    data: &'a [u8],
+    offset: usize,
+    index: usize,
+}
+
+impl<'a> Grib1MessageIterator<'a> {
+    pub fn from_data(data: &'a [u8], offset: usize) -> Self {
+        Grib1MessageIterator {
+            data,
+            offset,
+            index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for Grib1MessageIterator<'a> {
+    type Item = Grib1Message<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let length = match message_edition(self.data, self.offset)? {
+                1 => {
+                    let mut message = Grib1Message::from_data(self.data, self.offset)?;
+                    message.index = Some(self.index);
+                    self.index += 1;
+                    self.offset += message.len();
+                    return Some(message);
+                }
+                2 => message_size(self.data.get(self.offset..)?, self.offset).ok()?,
+                _ => return None,
+            };
+
+            self.offset = self.offset.checked_add(length)?;
+        }
+    }
+}
+
+pub fn read_grib1_messages(data: &[u8]) -> Grib1MessageIterator<'_> {
+    Grib1MessageIterator::from_data(data, 0)
+}
+
+/// A GRIB edition 1 message. GRIB1 messages hold a single field made of the product definition,
+/// optional grid description and bitmap, and binary data sections.
+pub struct Grib1Message<'a> {
+    data: &'a [u8],
+    offset: usize,
+    index: Option<usize>,
+}
+
+impl<'a> Grib1Message<'a> {
+    pub fn from_data(data: &'a [u8], offset: usize) -> Option<Grib1Message<'a>> {
+        match message_edition(data, offset) {
+            Some(1) if has_message_length(data, offset) => Some(Grib1Message {
+                data,
+                offset,
+                index: None,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn byte_data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    pub fn byte_offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Position of the message among those yielded by the [`Grib1MessageIterator`] it was read
+    /// with
+    pub fn message_index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Attaches the message's position to an error raised while reading it
+    pub(crate) fn error_context(&self, error: GribberishError) -> GribberishError {
+        error.in_message(self.index, self.offset)
+    }
+
+    /// Total length of the message, following the ECMWF convention for messages longer than the
+    /// 24 bit length can hold
+    pub fn len(&self) -> usize {
+        self.data
+            .get(self.offset..)
+            .and_then(|data| message_size(data, self.offset).ok())
+            .unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Slices section `number` starting `start` bytes into the message, checking it fits the
+    /// message
+    fn section(&self, number: u8, start: usize, min_length: usize) -> Result<&'a [u8], GribberishError> {
+        let message_end = (self.offset + self.len()).min(self.data.len());
+        let section_start = self.offset + start;
+        let length = read_u24_from_bytes(self.data, section_start).unwrap_or(0) as usize;
+
+        if length < min_length || section_start + length > message_end {
+            return Err(GribberishError::TruncatedSection {
+                section: number,
+                offset: start,
+                length: message_end.saturating_sub(section_start).min(length),
+                expected: length.max(min_length),
+            });
+        }
+
+        Ok(&self.data[section_start..section_start + length])
+    }
+
+    pub fn product_definition(&self) -> Result<ProductDefinitionSection<'a>, GribberishError> {
+        let data = self.section(1, INDICATOR_LENGTH, PRODUCT_DEFINITION_MIN_LENGTH)?;
+        ProductDefinitionSection::from_data(data)
+    }
+
+    fn grid_description_start(&self) -> Result<usize, GribberishError> {
+        Ok(INDICATOR_LENGTH + self.product_definition()?.len())
+    }
+
+    fn grid_description_length(&self) -> Result<usize, GribberishError> {
+        Ok(match self.product_definition()?.has_grid_description() {
+            true => self.grid_description()?.len(),
+            false => 0,
+        })
+    }
+
+    pub fn grid_description(&self) -> Result<GridDescriptionSection<'a>, GribberishError> {
+        let product_definition = self.product_definition()?;
+        if !product_definition.has_grid_description() {
+            return Err(GribberishError::Unsupported(format!(
+                "Predefined GRIB1 grid {} without a grid description section",
+                product_definition.grid_id()
+            )));
+        }
+
+        let data = self.section(2, self.grid_description_start()?, GRID_DESCRIPTION_MIN_LENGTH)?;
+        GridDescriptionSection::from_data(data)
+    }
+
+    fn bitmap_start(&self) -> Result<usize, GribberishError> {
+        Ok(self.grid_description_start()? + self.grid_description_length()?)
+    }
+
+    pub fn bitmap(&self) -> Result<Option<BitmapSection<'a>>, GribberishError> {
+        if !self.product_definition()?.has_bitmap() {
+            return Ok(None);
+        }
+
+        let data = self.section(3, self.bitmap_start()?, BITMAP_HEADER_LENGTH)?;
+        BitmapSection::from_data(data).map(Some)
+    }
+
+    pub fn binary_data(&self) -> Result<BinaryDataSection<'a>, GribberishError> {
+        let bitmap_length = self.bitmap()?.map(|b| b.len()).unwrap_or(0);
+        let start = self.bitmap_start()? + bitmap_length;
+        if !is_large_grib1_message(&self.data[self.offset..]) {
+            let data = self.section(4, start, BINARY_DATA_HEADER_LENGTH)?;
+            return BinaryDataSection::from_data(data);
+        }
+
+        // The length of the binary data section of a large message only completes its total
+        // length, the section itself runs up to the end section or the end of the data read
+        let section_start = self.offset + start;
+        let section_end = (self.offset + self.len()).saturating_sub(4).min(self.data.len());
+        let data = self.data.get(section_start..section_end).unwrap_or_default();
+        BinaryDataSection::from_data(data).map_err(|e| e.at_section_offset(start))
+    }
+
+    pub fn has_bitmap(&self) -> bool {
+        self.product_definition()
+            .map(|p| p.has_bitmap())
+            .unwrap_or(false)
+    }
+
+    pub fn originating_centre_value(&self) -> Result<u8, GribberishError> {
+        Ok(self.product_definition()?.originating_centre())
+    }
+
+    pub fn originating_centre(&self) -> Result<OriginatingCentre, GribberishError> {
+        Ok(OriginatingCentre::from(self.originating_centre_value()? as u16))
+    }
+
+    pub fn originating_sub_centre(&self) -> Result<u8, GribberishError> {
+        Ok(self.product_definition()?.originating_sub_centre())
+    }
+
+    pub fn table_version(&self) -> Result<u8, GribberishError> {
+        Ok(self.product_definition()?.table_version())
+    }
+
+    pub fn parameter_value(&self) -> Result<u8, GribberishError> {
+        Ok(self.product_definition()?.parameter())
+    }
+
+    pub fn parameter(&self) -> Result<Parameter, GribberishError> {
+        let product_definition = self.product_definition()?;
+        let parameter = unwrap_or_return!(
+            grib1_parameter(
+                product_definition.originating_centre(),
+                product_definition.table_version(),
+                product_definition.parameter(),
+            ),
+            GribberishError::Unsupported(format!(
+                "GRIB1 parameter {} of table version {} from centre {}",
+                product_definition.parameter(),
+                product_definition.table_version(),
+                product_definition.originating_centre(),
+            ))
+        );
+
+        Ok(parameter)
+    }
+
+    /// The GRIB2 discipline, category and parameter matching this message's parameter
+    pub fn grib2_equivalent(&self) -> Result<Option<(u8, u8, u8)>, GribberishError> {
+        let product_definition = self.product_definition()?;
+        Ok(grib2_equivalent(
+            product_definition.originating_centre(),
+            product_definition.table_version(),
+            product_definition.parameter(),
+        ))
+    }
+
+    pub fn discipline(&self) -> Result<Discipline, GribberishError> {
+        Ok(self
+            .grib2_equivalent()?
+            .map(|(discipline, _, _)| discipline.into())
+            .unwrap_or(Discipline::Meteorological))
+    }
+
+    pub fn category(&self) -> Result<String, GribberishError> {
+        Ok(self
+            .grib2_equivalent()?
+            .map(|(discipline, c, _)| category(discipline, c))
+            .unwrap_or("")
+            .to_owned())
+    }
+
+    /// ecCodes `shortName` and `paramId` for the parameter on its first fixed surface. The
+    /// parameter numbers of ECMWF's table 128 are ecCodes parameter ids, apart from the few
+    /// ecCodes renumbered for GRIB2.
+    pub fn eccodes_parameter(&self) -> Result<Option<EccodesParameter>, GribberishError> {
+        let product_definition = self.product_definition()?;
+        if product_definition.originating_centre() == 98 && product_definition.table_version() == 128 {
+            return Ok(eccodes_parameter_by_table_128(product_definition.parameter() as u32));
+        }
+
+        let (surface_type, surface_value) = self.first_fixed_surface()?;
+        Ok(self
+            .grib2_equivalent()?
+            .and_then(|(d, c, p)| eccodes_parameter(d, c, p, &surface_type, surface_value)))
+    }
+
+    pub fn variable_name(&self) -> Result<String, GribberishError> {
+        Ok(self.parameter()?.name)
+    }
+
+    pub fn variable_abbrev(&self) -> Result<String, GribberishError> {
+        Ok(self.parameter()?.abbrev)
+    }
+
+    pub fn standard_name(&self) -> Result<Option<String>, GribberishError> {
+        Ok(self.parameter()?.standard_name)
+    }
+
+    pub fn unit(&self) -> Result<String, GribberishError> {
+        Ok(self.parameter()?.unit)
+    }
+
+    pub fn reference_date(&self) -> Result<DateTime<Utc>, GribberishError> {
+        Ok(self.product_definition()?.reference_date())
+    }
+
+    pub fn forecast_date(&self) -> Result<DateTime<Utc>, GribberishError> {
+        Ok(self.product_definition()?.forecast_date())
+    }
+
+    pub fn forecast_end_date(&self) -> Result<Option<DateTime<Utc>>, GribberishError> {
+        Ok(self.product_definition()?.forecast_end_date())
+    }
+
+    pub fn generating_process(&self) -> Result<GeneratingProcess, GribberishError> {
+        Ok(self.product_definition()?.generating_process())
+    }
+
+    pub fn statistical_process_type(
+        &self,
+    ) -> Result<Option<TypeOfStatisticalProcessing>, GribberishError> {
+        Ok(self
+            .statistical_processing()?
+            .and_then(|processing| processing.outermost().cloned()))
+    }
+
+    pub fn statistical_processing(&self) -> Result<Option<StatisticalProcessing>, GribberishError> {
+        Ok(self.product_definition()?.statistical_processing())
+    }
+
+    pub fn time_unit(&self) -> Result<TimeUnit, GribberishError> {
+        Ok(self.product_definition()?.time_unit())
+    }
+
+    pub fn time_increment_unit(&self) -> Result<Option<TimeUnit>, GribberishError> {
+        Ok(self.product_definition()?.time_increment_unit())
+    }
+
+    pub fn time_interval(&self) -> Result<u32, GribberishError> {
+        Ok(self.product_definition()?.time_interval())
+    }
+
+    pub fn time_increment_interval(&self) -> Result<Option<u32>, GribberishError> {
+        Ok(self.product_definition()?.time_increment_interval())
+    }
+
+    pub fn first_fixed_surface(&self) -> Result<(FixedSurfaceType, Option<f64>), GribberishError> {
+        Ok(self.product_definition()?.fixed_surfaces().0)
+    }
+
+    pub fn second_fixed_surface(&self) -> Result<(FixedSurfaceType, Option<f64>), GribberishError> {
+        Ok(self.product_definition()?.fixed_surfaces().1)
+    }
+
+    pub fn key(&self) -> Result<String, GribberishError> {
+        let (first_fixed_surface, second_fixed_surface) = self.product_definition()?.fixed_surfaces();
+        Ok(message_key(
+            &self.variable_abbrev()?,
+            self.forecast_date().ok(),
+            first_fixed_surface,
+            second_fixed_surface,
+            self.statistical_processing()?,
+            self.generating_process()?,
+        ))
+    }
+
+    pub fn proj_string(&self) -> Result<String, GribberishError> {
+        Ok(self.grid_description()?.proj_string())
+    }
+
+    pub fn crs(&self) -> Result<String, GribberishError> {
+        Ok(self.grid_description()?.crs())
+    }
+
+    pub fn is_regular_grid(&self) -> Result<bool, GribberishError> {
+        Ok(self.grid_description()?.is_regular_grid())
+    }
+
+    pub fn grid_dimensions(&self) -> Result<(usize, usize), GribberishError> {
+        let grid_description = self.grid_description()?;
+        Ok((grid_description.y_count(), grid_description.x_count()))
+    }
+
+    pub fn latlng_projector(&self) -> Result<LatLngProjection, GribberishError> {
+        self.grid_description()?.projector()
+    }
+
+    pub fn data_compression_type(&self) -> Result<String, GribberishError> {
+        Ok(self.binary_data()?.compression_type())
+    }
+
+    pub fn data_point_count(&self) -> Result<usize, GribberishError> {
+        let (y, x) = self.grid_dimensions()?;
+        Ok(x * y)
+    }
+
+    /// Decodes the data onto the grid, with NaN for points missing from the bitmap. Grids scanned
+    /// with consecutive points along the y axis are transposed so rows always run along x.
+    pub fn data(&self) -> Result<Vec<f64>, GribberishError> {
+        self.unpack_data().map_err(|e| self.error_context(e))
+    }
+
+    fn unpack_data(&self) -> Result<Vec<f64>, GribberishError> {
+        let point_count = self.data_point_count()?;
+        let decimal_scale_factor = self.product_definition()?.decimal_scale_factor();
+        let binary_data = self.binary_data()?;
+
+        let data = match self.bitmap()? {
+            Some(bitmap) => {
+                if bitmap.predefined_bitmap() != 0 {
+                    return Err(GribberishError::Unsupported(format!(
+                        "Predefined GRIB1 bitmap {}",
+                        bitmap.predefined_bitmap()
+                    )));
+                }
+                let bitmap_length = bitmap.raw_bitmap_data().len() * 8;
+                if bitmap_length < point_count {
+                    return Err(GribberishError::InvalidBitmapLength {
+                        length: bitmap_length,
+                        expected: point_count,
+                    });
+                }
+                let values =
+                    binary_data.unpack(bitmap.present_count(point_count), decimal_scale_factor)?;
+                bitmap.map_data(values, point_count)
+            }
+            None => binary_data.unpack(point_count, decimal_scale_factor)?,
+        };
+
+        let grid_description = self.grid_description()?;
+        if grid_description.scanning_mode_flags()[2] == ScanningMode::ConsecutiveJ {
+            let (y, x) = (grid_description.y_count(), grid_description.x_count());
+            return Ok((0..point_count).map(|i| data[(i % x) * y + i / x]).collect());
+        }
+
+        Ok(data)
+    }
+}
@@ -0,0 +1,8 @@
+pub mod binary_data;
+pub mod bitmap;
+pub mod grid_description;
+pub mod message;
+pub mod parameters;
+pub mod product_definition;
+
+pub use message::{read_grib1_messages, Grib1Message, Grib1MessageIterator};
@@ -0,0 +1,263 @@
+use gribberish_types::Parameter;
+
+use crate::templates::product::parameters::parameter;
+
+/// An entry of a GRIB1 parameter table (code table 2) along with the GRIB2 discipline, category
+/// and parameter describing the same quantity, when there is one
+struct Grib1ParameterEntry {
+    number: u8,
+    abbrev: &'static str,
+    name: &'static str,
+    unit: &'static str,
+    grib2: Option<(u8, u8, u8)>,
+}
+
+const fn entry(
+    number: u8,
+    abbrev: &'static str,
+    name: &'static str,
+    unit: &'static str,
+    grib2: Option<(u8, u8, u8)>,
+) -> Grib1ParameterEntry {
+    Grib1ParameterEntry {
+        number,
+        abbrev,
+        name,
+        unit,
+        grib2,
+    }
+}
+
+/// WMO code table 2, the international part (1 to 127) shared by table versions 1 to 3
+const WMO_TABLE_2: &[Grib1ParameterEntry] = &[
+    entry(1, "PRES", "pressure", "Pa", Some((0, 3, 0))),
+    entry(2, "PRMSL", "pressure reduced to msl", "Pa", Some((0, 3, 1))),
+    entry(3, "PTEND", "pressure tendency", "Pas-1", Some((0, 3, 2))),
+    entry(4, "PVORT", "potential vorticity", "Km2kg-1s-1", Some((0, 2, 14))),
+    entry(5, "ICAHT", "icao standard atmosphere reference height", "m", Some((0, 3, 3))),
+    entry(6, "GP", "geopotential", "m2s-2", Some((0, 3, 4))),
+    entry(7, "HGT", "geopotential height", "gpm", Some((0, 3, 5))),
+    entry(8, "DIST", "geometric height", "m", Some((0, 3, 6))),
+    entry(9, "HSTDV", "standard deviation of height", "m", Some((0, 3, 7))),
+    entry(10, "TOZNE", "total ozone", "DU", Some((0, 14, 0))),
+    entry(11, "TMP", "temperature", "K", Some((0, 0, 0))),
+    entry(12, "VTMP", "virtual temperature", "K", Some((0, 0, 1))),
+    entry(13, "POT", "potential temperature", "K", Some((0, 0, 2))),
+    entry(14, "EPOT", "pseudo-adiabatic potential temperature", "K", Some((0, 0, 3))),
+    entry(15, "TMAX", "maximum temperature", "K", Some((0, 0, 4))),
+    entry(16, "TMIN", "minimum temperature", "K", Some((0, 0, 5))),
+    entry(17, "DPT", "dew point temperature", "K", Some((0, 0, 6))),
+    entry(18, "DEPR", "dew point depression", "K", Some((0, 0, 7))),
+    entry(19, "LAPR", "lapse rate", "Km-1", Some((0, 0, 8))),
+    entry(20, "VIS", "visibility", "m", Some((0, 19, 0))),
+    entry(24, "PLI", "parcel lifted index", "K", Some((0, 7, 0))),
+    entry(25, "TMPA", "temperature anomaly", "K", Some((0, 0, 9))),
+    entry(26, "PRESA", "pressure anomaly", "Pa", Some((0, 3, 8))),
+    entry(27, "GPA", "geopotential height anomaly", "gpm", Some((0, 3, 9))),
+    entry(31, "WDIR", "wind direction", "degrees", Some((0, 2, 0))),
+    entry(32, "WIND", "wind speed", "ms-1", Some((0, 2, 1))),
+    entry(33, "UGRD", "u-component of wind", "ms-1", Some((0, 2, 2))),
+    entry(34, "VGRD", "v-component of wind", "ms-1", Some((0, 2, 3))),
+    entry(35, "STRM", "stream function", "m2s-1", Some((0, 2, 4))),
+    entry(36, "VPOT", "velocity potential", "m2s-1", Some((0, 2, 5))),
+    entry(37, "MNTSF", "montgomery stream function", "m2s-2", Some((0, 2, 6))),
+    entry(38, "SGCVV", "sigma coordinate vertical velocity", "s-1", Some((0, 2, 7))),
+    entry(39, "VVEL", "vertical velocity (pressure)", "Pas-1", Some((0, 2, 8))),
+    entry(40, "DZDT", "vertical velocity (geometric)", "ms-1", Some((0, 2, 9))),
+    entry(41, "ABSV", "absolute vorticity", "s-1", Some((0, 2, 10))),
+    entry(42, "ABSD", "absolute divergence", "s-1", Some((0, 2, 11))),
+    entry(43, "RELV", "relative vorticity", "s-1", Some((0, 2, 12))),
+    entry(44, "RELD", "relative divergence", "s-1", Some((0, 2, 13))),
+    entry(45, "VUCSH", "vertical u-component shear", "s-1", Some((0, 2, 15))),
+    entry(46, "VVCSH", "vertical v-component shear", "s-1", Some((0, 2, 16))),
+    entry(47, "DIRC", "direction of current", "degrees", Some((10, 1, 0))),
+    entry(48, "SPC", "speed of current", "ms-1", Some((10, 1, 1))),
+    entry(49, "UOGRD", "u-component of current", "ms-1", Some((10, 1, 2))),
+    entry(50, "VOGRD", "v-component of current", "ms-1", Some((10, 1, 3))),
+    entry(51, "SPFH", "specific humidity", "kgkg-1", Some((0, 1, 0))),
+    entry(52, "RH", "relative humidity", "%", Some((0, 1, 1))),
+    entry(53, "MIXR", "humidity mixing ratio", "kgkg-1", Some((0, 1, 2))),
+    entry(54, "PWAT", "precipitable water", "kgm-2", Some((0, 1, 3))),
+    entry(55, "VAPP", "vapour pressure", "Pa", Some((0, 1, 4))),
+    entry(56, "SATD", "saturation deficit", "Pa", Some((0, 1, 5))),
+    entry(57, "EVP", "evaporation", "kgm-2", Some((0, 1, 6))),
+    entry(58, "CICE", "cloud ice", "kgm-2", Some((0, 6, 0))),
+    entry(59, "PRATE", "precipitation rate", "kgm-2s-1", Some((0, 1, 7))),
+    entry(60, "TSTM", "thunderstorm probability", "%", Some((0, 19, 2))),
+    entry(61, "APCP", "total precipitation", "kgm-2", Some((0, 1, 8))),
+    entry(62, "NCPCP", "large scale precipitation", "kgm-2", Some((0, 1, 9))),
+    entry(63, "ACPCP", "convective precipitation", "kgm-2", Some((0, 1, 10))),
+    entry(64, "SRWEQ", "snowfall rate water equivalent", "kgm-2s-1", Some((0, 1, 12))),
+    entry(65, "WEASD", "water equivalent of accumulated snow depth", "kgm-2", Some((0, 1, 13))),
+    entry(66, "SNOD", "snow depth", "m", Some((0, 1, 11))),
+    entry(67, "MIXHT", "mixed layer depth", "m", Some((0, 19, 3))),
+    entry(68, "TTHDP", "transient thermocline depth", "m", Some((10, 4, 2))),
+    entry(69, "MTHD", "main thermocline depth", "m", Some((10, 4, 0))),
+    entry(70, "MTHA", "main thermocline anomaly", "m", Some((10, 4, 1))),
+    entry(71, "TCDC", "total cloud cover", "%", Some((0, 6, 1))),
+    entry(72, "CDCON", "convective cloud cover", "%", Some((0, 6, 2))),
+    entry(73, "LCDC", "low cloud cover", "%", Some((0, 6, 3))),
+    entry(74, "MCDC", "medium cloud cover", "%", Some((0, 6, 4))),
+    entry(75, "HCDC", "high cloud cover", "%", Some((0, 6, 5))),
+    entry(76, "CWAT", "cloud water", "kgm-2", Some((0, 6, 6))),
+    entry(77, "BLI", "best lifted index", "K", Some((0, 7, 1))),
+    entry(78, "SNOC", "convective snow", "kgm-2", Some((0, 1, 14))),
+    entry(79, "SNOL", "large scale snow", "kgm-2", Some((0, 1, 15))),
+    entry(80, "WTMP", "water temperature", "K", Some((10, 4, 3))),
+    entry(81, "LAND", "land cover", "proportion", Some((2, 0, 0))),
+    entry(82, "DSLM", "deviation of sea level from mean", "m", Some((10, 3, 1))),
+    entry(83, "SFCR", "surface roughness", "m", Some((2, 0, 1))),
+    entry(84, "ALBDO", "albedo", "%", Some((0, 19, 1))),
+    entry(85, "TSOIL", "soil temperature", "K", Some((2, 0, 2))),
+    entry(86, "SOILM", "soil moisture content", "kgm-2", Some((2, 0, 3))),
+    entry(87, "VEG", "vegetation", "%", Some((2, 0, 4))),
+    entry(88, "SALTY", "salinity", "kgkg-1", Some((10, 4, 4))),
+    entry(89, "DEN", "density", "kgm-3", Some((0, 3, 10))),
+    entry(90, "WATR", "water runoff", "kgm-2", Some((2, 0, 5))),
+    entry(91, "ICEC", "ice cover", "proportion", Some((10, 2, 0))),
+    entry(92, "ICETK", "ice thickness", "m", Some((10, 2, 1))),
+    entry(93, "DICED", "direction of ice drift", "degrees", Some((10, 2, 2))),
+    entry(94, "SICED", "speed of ice drift", "ms-1", Some((10, 2, 3))),
+    entry(95, "UICE", "u-component of ice drift", "ms-1", Some((10, 2, 4))),
+    entry(96, "VICE", "v-component of ice drift", "ms-1", Some((10, 2, 5))),
+    entry(97, "ICEG", "ice growth rate", "ms-1", Some((10, 2, 6))),
+    entry(98, "ICED", "ice divergence", "s-1", Some((10, 2, 7))),
+    entry(99, "SNOM", "snow melt", "kgm-2", Some((0, 1, 16))),
+    entry(100, "HTSGW", "significant height of combined wind waves and swell", "m", Some((10, 0, 3))),
+    entry(101, "WVDIR", "direction of wind waves", "degrees", Some((10, 0, 4))),
+    entry(102, "WVHGT", "significant height of wind waves", "m", Some((10, 0, 5))),
+    entry(103, "WVPER", "mean period of wind waves", "s", Some((10, 0, 6))),
+    entry(104, "SWDIR", "direction of swell waves", "degrees", Some((10, 0, 7))),
+    entry(105, "SWELL", "significant height of swell waves", "m", Some((10, 0, 8))),
+    entry(106, "SWPER", "mean period of swell waves", "s", Some((10, 0, 9))),
+    entry(107, "DIRPW", "primary wave direction", "degrees", Some((10, 0, 10))),
+    entry(108, "PERPW", "primary wave mean period", "s", Some((10, 0, 11))),
+    entry(109, "DIRSW", "secondary wave direction", "degrees", Some((10, 0, 12))),
+    entry(110, "PERSW", "secondary wave mean period", "s", Some((10, 0, 13))),
+    entry(111, "NSWRS", "net short-wave radiation flux (surface)", "Wm-2", Some((0, 4, 0))),
+    entry(112, "NLWRS", "net long-wave radiation flux (surface)", "Wm-2", Some((0, 5, 0))),
+    entry(113, "NSWRT", "net short-wave radiation flux (top of atmosphere)", "Wm-2", Some((0, 4, 1))),
+    entry(114, "NLWRT", "net long-wave radiation flux (top of atmosphere)", "Wm-2", Some((0, 5, 1))),
+    entry(115, "LWAVR", "long-wave radiation flux", "Wm-2", Some((0, 5, 2))),
+    entry(116, "SWAVR", "short-wave radiation flux", "Wm-2", Some((0, 4, 2))),
+    entry(117, "GRAD", "global radiation flux", "Wm-2", Some((0, 4, 3))),
+    entry(118, "BRTMP", "brightness temperature", "K", Some((0, 4, 4))),
+    entry(121, "LHTFL", "latent heat net flux", "Wm-2", Some((0, 0, 10))),
+    entry(122, "SHTFL", "sensible heat net flux", "Wm-2", Some((0, 0, 11))),
+    entry(123, "BLYDP", "boundary layer dissipation", "Wm-2", Some((0, 2, 20))),
+    entry(124, "UFLX", "momentum flux, u-component", "Nm-2", Some((0, 2, 17))),
+    entry(125, "VFLX", "momentum flux, v-component", "Nm-2", Some((0, 2, 18))),
+    entry(126, "WMIXE", "wind mixing energy", "J", Some((0, 2, 19))),
+];
+
+/// Commonly used NCEP local entries (128 to 255) of table version 2, as found in the GFS, NAM
+/// and NARR archives
+const NCEP_TABLE_2: &[Grib1ParameterEntry] = &[
+    entry(130, "MSLET", "mean sea level pressure (eta reduction)", "Pa", Some((0, 3, 192))),
+    entry(131, "LFTX", "surface lifted index", "K", Some((0, 7, 10))),
+    entry(132, "4LFTX", "best (4 layer) lifted index", "K", Some((0, 7, 11))),
+    entry(140, "CRAIN", "categorical rain", "numeric", Some((0, 1, 192))),
+    entry(141, "CFRZR", "categorical freezing rain", "numeric", Some((0, 1, 193))),
+    entry(142, "CICEP", "categorical ice pellets", "numeric", Some((0, 1, 194))),
+    entry(143, "CSNOW", "categorical snow", "numeric", Some((0, 1, 195))),
+    entry(144, "SOILW", "volumetric soil moisture content", "proportion", Some((2, 0, 192))),
+    entry(153, "CLWMR", "cloud water mixing ratio", "kgkg-1", Some((0, 1, 22))),
+    entry(154, "O3MR", "ozone mixing ratio", "kgkg-1", Some((0, 14, 192))),
+    entry(155, "GFLUX", "ground heat flux", "Wm-2", Some((2, 0, 193))),
+    entry(156, "CIN", "convective inhibition", "Jkg-1", Some((0, 7, 7))),
+    entry(157, "CAPE", "convective available potential energy", "Jkg-1", Some((0, 7, 6))),
+    entry(158, "TKE", "turbulent kinetic energy", "Jkg-1", Some((0, 19, 11))),
+    entry(172, "MFLX", "momentum flux", "Nm-2", Some((0, 2, 193))),
+    entry(180, "GUST", "wind speed (gust)", "ms-1", Some((0, 2, 22))),
+    entry(190, "HLCY", "storm relative helicity", "m2s-2", Some((0, 7, 8))),
+    entry(204, "DSWRF", "downward short-wave radiation flux", "Wm-2", Some((0, 4, 192))),
+    entry(205, "DLWRF", "downward long-wave radiation flux", "Wm-2", Some((0, 5, 192))),
+    entry(211, "USWRF", "upward short-wave radiation flux", "Wm-2", Some((0, 4, 193))),
+    entry(212, "ULWRF", "upward long-wave radiation flux", "Wm-2", Some((0, 5, 193))),
+    entry(214, "CPRAT", "convective precipitation rate", "kgm-2s-1", Some((0, 1, 196))),
+    entry(221, "HPBL", "planetary boundary layer height", "m", Some((0, 3, 196))),
+    entry(222, "5WAVH", "5-wave geopotential height", "gpm", Some((0, 3, 193))),
+];
+
+/// Commonly used entries of ECMWF local table 128, named after their NCEP equivalents so GRIB1
+/// and GRIB2 messages share variable names
+const ECMWF_TABLE_128: &[Grib1ParameterEntry] = &[
+    entry(31, "ICEC", "sea ice area fraction", "proportion", Some((10, 2, 0))),
+    entry(34, "WTMP", "sea surface temperature", "K", Some((10, 3, 0))),
+    entry(59, "CAPE", "convective available potential energy", "Jkg-1", Some((0, 7, 6))),
+    entry(129, "GP", "geopotential", "m2s-2", Some((0, 3, 4))),
+    entry(130, "TMP", "temperature", "K", Some((0, 0, 0))),
+    entry(131, "UGRD", "u-component of wind", "ms-1", Some((0, 2, 2))),
+    entry(132, "VGRD", "v-component of wind", "ms-1", Some((0, 2, 3))),
+    entry(133, "SPFH", "specific humidity", "kgkg-1", Some((0, 1, 0))),
+    entry(134, "PRES", "surface pressure", "Pa", Some((0, 3, 0))),
+    entry(135, "VVEL", "vertical velocity", "Pas-1", Some((0, 2, 8))),
+    entry(137, "TCWV", "total column water vapour", "kgm-2", Some((0, 1, 64))),
+    entry(138, "RELV", "vorticity (relative)", "s-1", Some((0, 2, 12))),
+    entry(141, "SNOD", "snow depth", "m", Some((0, 1, 60))),
+    entry(151, "PRMSL", "mean sea level pressure", "Pa", Some((0, 3, 0))),
+    entry(155, "RELD", "divergence", "s-1", Some((0, 2, 13))),
+    entry(157, "RH", "relative humidity", "%", Some((0, 1, 1))),
+    entry(164, "TCDC", "total cloud cover", "proportion", Some((0, 6, 1))),
+    entry(165, "UGRD", "10 metre u wind component", "ms-1", Some((0, 2, 2))),
+    entry(166, "VGRD", "10 metre v wind component", "ms-1", Some((0, 2, 3))),
+    entry(167, "TMP", "2 metre temperature", "K", Some((0, 0, 0))),
+    entry(168, "DPT", "2 metre dewpoint temperature", "K", Some((0, 0, 6))),
+    entry(172, "LAND", "land-sea mask", "proportion", Some((2, 0, 0))),
+    entry(228, "APCP", "total precipitation", "m", Some((0, 1, 8))),
+    entry(235, "SKT", "skin temperature", "K", Some((0, 0, 17))),
+];
+
+const NCEP_CENTRE: u8 = 7;
+const ECMWF_CENTRE: u8 = 98;
+
+fn table_entry(centre: u8, table_version: u8, number: u8) -> Option<&'static Grib1ParameterEntry> {
+    let table = match (centre, table_version) {
+        (ECMWF_CENTRE, 128) => ECMWF_TABLE_128,
+        (NCEP_CENTRE, 1..=3) if number >= 128 => NCEP_TABLE_2,
+        (_, 1..=3) if number < 128 => WMO_TABLE_2,
+        _ => return None,
+    };
+
+    table.iter().find(|e| e.number == number)
+}
+
+/// Looks up a GRIB1 parameter from the originating centre, the parameter table version and the
+/// parameter number. Parameters with a GRIB2 equivalent take their standard name from it.
+pub fn grib1_parameter(centre: u8, table_version: u8, number: u8) -> Option<Parameter> {
+    let entry = table_entry(centre, table_version, number)?;
+    let standard_name = entry
+        .grib2
+        .and_then(|(d, c, p)| parameter(d, c, p))
+        .and_then(|p| p.standard_name);
+
+    Some(Parameter {
+        name: entry.name.to_string(),
+        unit: entry.unit.to_string(),
+        abbrev: entry.abbrev.to_string(),
+        standard_name,
+    })
+}
+
+/// The GRIB2 discipline, category and parameter matching a GRIB1 parameter, if there is one
+pub fn grib2_equivalent(centre: u8, table_version: u8, number: u8) -> Option<(u8, u8, u8)> {
+    table_entry(centre, table_version, number).and_then(|e| e.grib2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_grib1_parameters() {
+        let temperature = grib1_parameter(7, 2, 11).unwrap();
+        assert_eq!(temperature.abbrev, "TMP");
+        assert_eq!(temperature.unit, "K");
+        assert_eq!(temperature.standard_name.as_deref(), Some("air_temperature"));
+
+        assert_eq!(grib1_parameter(7, 2, 157).unwrap().abbrev, "CAPE");
+        assert_eq!(grib1_parameter(98, 128, 167).unwrap().abbrev, "TMP");
+        assert_eq!(grib2_equivalent(98, 128, 165), Some((0, 2, 2)));
+        assert!(grib1_parameter(34, 2, 200).is_none());
+    }
+}
@@ -0,0 +1,257 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::error::GribberishError;
+use crate::sections::grib_section::{check_section_length, GribSection};
+use crate::templates::product::tables::{
+    FixedSurfaceType, GeneratingProcess, StatisticalProcessing, TimeUnit,
+    TypeOfStatisticalProcessing,
+};
+use crate::utils::{read_u16_from_bytes, read_u24_from_bytes};
+
+/// Minimum length of the product definition section, the remaining octets are reserved or local
+pub const PRODUCT_DEFINITION_MIN_LENGTH: usize = 28;
+
+/// GRIB1 section 1, the product definition section (PDS)
+pub struct ProductDefinitionSection<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ProductDefinitionSection<'a> {
+    /// The section stored in `data`, failing when it is too short for the accessors
+    pub fn from_data(data: &'a [u8]) -> Result<Self, GribberishError> {
+        check_section_length(1, data, PRODUCT_DEFINITION_MIN_LENGTH)?;
+        Ok(ProductDefinitionSection { data })
+    }
+
+    pub fn table_version(&self) -> u8 {
+        self.data[3]
+    }
+
+    pub fn originating_centre(&self) -> u8 {
+        self.data[4]
+    }
+
+    /// Generating process or model identifier, defined by the originating centre
+    pub fn generating_process_id(&self) -> u8 {
+        self.data[5]
+    }
+
+    pub fn grid_id(&self) -> u8 {
+        self.data[6]
+    }
+
+    pub fn has_grid_description(&self) -> bool {
+        self.data[7] & 0x80 > 0
+    }
+
+    pub fn has_bitmap(&self) -> bool {
+        self.data[7] & 0x40 > 0
+    }
+
+    pub fn parameter(&self) -> u8 {
+        self.data[8]
+    }
+
+    /// Type of level or layer, code table 3
+    pub fn level_type(&self) -> u8 {
+        self.data[9]
+    }
+
+    pub fn level_value(&self) -> u16 {
+        read_u16_from_bytes(self.data, 10).unwrap_or(0)
+    }
+
+    /// Top and bottom of a layer, each packed into a single octet
+    pub fn layer_values(&self) -> (u8, u8) {
+        (self.data[10], self.data[11])
+    }
+
+    pub fn reference_date(&self) -> DateTime<Utc> {
+        let century = self.data[24] as i32;
+        let year = (century - 1) * 100 + self.data[12] as i32;
+
+        NaiveDate::from_ymd_opt(year, self.data[13] as u32, self.data[14] as u32)
+            .and_then(|d| d.and_hms_opt(self.data[15] as u32, self.data[16] as u32, 0))
+            .map(|d| d.and_utc())
+            .unwrap_or_default()
+    }
+
+    /// Forecast time unit, code table 4. Codes 0-7 match GRIB2 code table 4.4 but the codes
+    /// from 10 do not, as GRIB1 uses 13 and 14 for 15 and 30 minutes and 254 for seconds
+    pub fn time_unit(&self) -> TimeUnit {
+        match self.data[17] {
+            10 => TimeUnit::ThreeHours,
+            11 => TimeUnit::SixHours,
+            12 => TimeUnit::TwelveHours,
+            13 => TimeUnit::FifteenMinutes,
+            14 => TimeUnit::ThirtyMinutes,
+            254 => TimeUnit::Seconds,
+            unit => unit.into(),
+        }
+    }
+
+    pub fn p1(&self) -> u8 {
+        self.data[18]
+    }
+
+    pub fn p2(&self) -> u8 {
+        self.data[19]
+    }
+
+    /// Time range indicator, code table 5
+    pub fn time_range_indicator(&self) -> u8 {
+        self.data[20]
+    }
+
+    pub fn originating_sub_centre(&self) -> u8 {
+        self.data[25]
+    }
+
+    pub fn decimal_scale_factor(&self) -> i16 {
+        let raw = read_u16_from_bytes(self.data, 26).unwrap_or(0);
+        as_signed!(raw, 16, i16)
+    }
+
+    /// Whether the product covers the period from P1 to P2 rather than the single time P1
+    fn is_time_range(&self) -> bool {
+        matches!(self.time_range_indicator(), 2..=5)
+    }
+
+    /// The forecast time, P1 or the 16 bit value spanning P1 and P2 for time range indicator 10
+    pub fn time_interval(&self) -> u32 {
+        match self.time_range_indicator() {
+            10 => read_u16_from_bytes(self.data, 18).unwrap_or(0) as u32,
+            _ => self.p1() as u32,
+        }
+    }
+
+    /// Length of the time range from P1 to P2
+    pub fn time_increment_interval(&self) -> Option<u32> {
+        if self.is_time_range() {
+            Some(self.p2().saturating_sub(self.p1()) as u32)
+        } else {
+            None
+        }
+    }
+
+    pub fn time_increment_unit(&self) -> Option<TimeUnit> {
+        if self.is_time_range() {
+            Some(self.time_unit())
+        } else {
+            None
+        }
+    }
+
+    pub fn statistical_processing(&self) -> Option<StatisticalProcessing> {
+        match self.time_range_indicator() {
+            3 => Some(TypeOfStatisticalProcessing::Average.into()),
+            4 => Some(TypeOfStatisticalProcessing::Accumulation.into()),
+            5 => Some(TypeOfStatisticalProcessing::Difference.into()),
+            _ => None,
+        }
+    }
+
+    pub fn generating_process(&self) -> GeneratingProcess {
+        match self.time_range_indicator() {
+            1 => GeneratingProcess::Analysis,
+            0 | 10 if self.time_interval() == 0 => GeneratingProcess::Analysis,
+            _ => GeneratingProcess::Forecast,
+        }
+    }
+
+    /// The reference date offset by `value` time units, or the reference date itself when the
+    /// offset is out of range
+    fn offset_date(&self, value: u32) -> DateTime<Utc> {
+        let reference_date = self.reference_date();
+        reference_date
+            .checked_add_signed(self.time_unit().duration(value as i64))
+            .unwrap_or(reference_date)
+    }
+
+    pub fn forecast_date(&self) -> DateTime<Utc> {
+        self.offset_date(self.time_interval())
+    }
+
+    pub fn forecast_end_date(&self) -> Option<DateTime<Utc>> {
+        if self.is_time_range() {
+            Some(self.offset_date(self.p2() as u32))
+        } else {
+            None
+        }
+    }
+
+    /// The first and second fixed surfaces of the level, converted from code table 3 to their
+    /// GRIB2 equivalent with values in the GRIB2 units
+    pub fn fixed_surfaces(&self) -> ((FixedSurfaceType, Option<f64>), (FixedSurfaceType, Option<f64>)) {
+        let value = self.level_value() as f64;
+        let (top, bottom) = self.layer_values();
+        let (top, bottom) = (top as f64, bottom as f64);
+
+        let level = |surface: FixedSurfaceType, value: Option<f64>| {
+            ((surface, value), (FixedSurfaceType::Missing, None))
+        };
+        let layer = |surface: FixedSurfaceType, top: f64, bottom: f64| {
+            ((surface.clone(), Some(top)), (surface, Some(bottom)))
+        };
+
+        match self.level_type() {
+            1 => level(FixedSurfaceType::GroundOrWater, None),
+            2 => level(FixedSurfaceType::CloudBase, None),
+            3 => level(FixedSurfaceType::CloudTop, None),
+            4 => level(FixedSurfaceType::ZeroDegreeIsotherm, None),
+            5 => level(FixedSurfaceType::AdiabaticCondensationLifted, None),
+            6 => level(FixedSurfaceType::MaximumWindLevel, None),
+            7 => level(FixedSurfaceType::Tropopause, None),
+            8 => level(FixedSurfaceType::NominalTopOfAtmosphere, None),
+            9 => level(FixedSurfaceType::SeaBottom, None),
+            20 => level(FixedSurfaceType::IsothermalLevel, Some(value / 100.0)),
+            100 => level(FixedSurfaceType::IsobaricSurface, Some(value * 100.0)),
+            101 => layer(FixedSurfaceType::IsobaricSurface, top * 1000.0, bottom * 1000.0),
+            102 => level(FixedSurfaceType::MeanSeaLevel, None),
+            103 => level(FixedSurfaceType::SpecificAltitudeAboveMeanSeaLevel, Some(value)),
+            104 => layer(
+                FixedSurfaceType::SpecificAltitudeAboveMeanSeaLevel,
+                top * 100.0,
+                bottom * 100.0,
+            ),
+            105 => level(FixedSurfaceType::SpecifiedHeightLevelAboveGround, Some(value)),
+            106 => layer(
+                FixedSurfaceType::SpecifiedHeightLevelAboveGround,
+                top * 100.0,
+                bottom * 100.0,
+            ),
+            107 => level(FixedSurfaceType::SigmaLevel, Some(value / 10000.0)),
+            108 => layer(FixedSurfaceType::SigmaLevel, top / 100.0, bottom / 100.0),
+            109 => level(FixedSurfaceType::HybridLevel, Some(value)),
+            110 => layer(FixedSurfaceType::HybridLevel, top, bottom),
+            111 => level(FixedSurfaceType::DepthBelowLandSurface, Some(value / 100.0)),
+            112 => layer(FixedSurfaceType::DepthBelowLandSurface, top / 100.0, bottom / 100.0),
+            113 => level(FixedSurfaceType::IsentropicLevel, Some(value)),
+            114 => layer(FixedSurfaceType::IsentropicLevel, 475.0 - top, 475.0 - bottom),
+            115 => level(
+                FixedSurfaceType::LevelAtSpecifiedPressureDifferenceFromGroundToLevel,
+                Some(value * 100.0),
+            ),
+            116 => layer(
+                FixedSurfaceType::LevelAtSpecifiedPressureDifferenceFromGroundToLevel,
+                top * 100.0,
+                bottom * 100.0,
+            ),
+            117 => level(FixedSurfaceType::PotentialVorticitySurface, Some(value * 1e-9)),
+            160 => level(FixedSurfaceType::DepthBelowSeaLevel, Some(value)),
+            200 => level(FixedSurfaceType::EntireAtmosphereAsSingleLayer, None),
+            201 => level(FixedSurfaceType::EntireOceanAsSingleLayer, None),
+            _ => level(FixedSurfaceType::Missing, None),
+        }
+    }
+}
+
+impl GribSection for ProductDefinitionSection<'_> {
+    fn len(&self) -> usize {
+        read_u24_from_bytes(self.data, 0).unwrap_or(0) as usize
+    }
+
+    fn number(&self) -> u8 {
+        1
+    }
+}
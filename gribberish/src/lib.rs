@@ -1,8 +1,18 @@
 #[macro_use]
 mod utils;
+pub mod cf;
 pub mod data_message;
 pub mod error;
+pub mod grib1;
 pub mod sections;
 pub mod templates;
 pub mod message;
 pub mod message_metadata;
+pub mod reader;
+pub mod units;
+
+#[cfg(feature = "async")]
+pub mod async_reader;
+
+#[cfg(feature = "mmap")]
+pub mod file;
@@ -1,15 +1,39 @@
 use crate::error::GribberishError;
-use crate::sections::{indicator::Discipline, section::Section, section::SectionIterator};
+use crate::grib1::Grib1Message;
+use crate::reader::message_size;
+use crate::sections::{
+    identification::OriginatingCentre,
+    indicator::{message_edition, Discipline},
+    bitmap::BitmapSection,
+    data::DataSection,
+    data_representation::DataRepresentationSection,
+    grid_definition::GridDefinitionSection,
+    identification::IdentificationSection,
+    indicator::IndicatorSection,
+    local_use::LocalUseSection,
+    product_definition::ProductDefinitionSection,
+    section::{field_section_tables, message_section_table, Section, SectionTable, SectionIterator},
+};
+use crate::templates::data_representation::DataRepresentationTemplate;
 use crate::templates::grid_definition::GridDefinitionTemplate;
+use crate::templates::local_use::LocalUseDefinition;
+use crate::templates::product::parameters::eccodes::{eccodes_parameter, EccodesParameter};
+use crate::templates::product::parameters::registry::{registered_category, registered_parameter};
+use crate::templates::product::parameters::{local_category, local_parameter};
 use crate::templates::product::product_template::ProductTemplate;
 use crate::templates::product::tables::{
-    DerivedForecastType, FixedSurfaceType, GeneratingProcess, TimeUnit, TypeOfStatisticalProcessing,
+    DerivedForecastType, FixedSurfaceType, GeneratingProcess, StatisticalProcessing, TimeUnit,
+    TypeOfStatisticalProcessing,
 };
+use crate::templates::product::time_range::TimeRangeSpecification;
+use crate::units::convert_values;
 use crate::utils::iter::projection::LatLngProjection;
 use bitvec::view::BitView;
 use chrono::{DateTime, Utc};
 use gribberish_types::Parameter;
 use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::{Arc, OnceLock};
 use std::vec::Vec;
 
 pub fn scan_messages<'a>(data: &'a [u8]) -> HashMap<String, (usize, usize)> {
@@ -25,64 +49,319 @@ pub fn scan_messages<'a>(data: &'a [u8]) -> HashMap<String, (usize, usize)> {
 }
 
 pub fn read_messages<'a>(data: &'a [u8]) -> MessageIterator<'a> {
-    MessageIterator { data, offset: 0 }
+    MessageIterator::from_data(data, 0)
+}
+
+/// Reads every complete message in the data, skipping over anything else, see
+/// [`MessageIterator::resilient`]
+pub fn read_messages_resilient<'a>(data: &'a [u8]) -> MessageIterator<'a> {
+    MessageIterator::from_data(data, 0).resilient()
 }
 
 pub fn read_message<'a>(data: &'a [u8], offset: usize) -> Option<Message<'a>> {
     Message::from_data(data, offset)
 }
 
+/// Decodes the data of the message at `offset`, reading GRIB1 messages as a single field
+pub fn read_message_data(data: &[u8], offset: usize, field: usize) -> Result<Vec<f64>, GribberishError> {
+    match message_edition(data, offset) {
+        Some(1) => Grib1Message::from_data(data, offset)
+            .ok_or_else(|| GribberishError::MessageError("No GRIB1 message found".into()))
+            .and_then(|message| message.data()),
+        _ => Message::from_data(data, offset)
+            .and_then(|m| m.field(field))
+            .ok_or_else(|| GribberishError::MessageError(format!("No message with field {field} found")))
+            .and_then(|message| message.data()),
+    }
+    .map_err(|e| e.in_message(None, offset))
+}
+
+/// Builds the key identifying a message by variable, time, levels and processing
+pub(crate) fn message_key(
+    var: &str,
+    forecast_date: Option<DateTime<Utc>>,
+    first_fixed_surface: (FixedSurfaceType, Option<f64>),
+    second_fixed_surface: (FixedSurfaceType, Option<f64>),
+    statistical_processing: Option<StatisticalProcessing>,
+    generating_process: GeneratingProcess,
+) -> String {
+    let time = forecast_date
+        .map(|t| format!(":{}", t.format("%Y%m%d%H%M")))
+        .unwrap_or_default();
+    let statistical_process = statistical_processing.map_or("".to_string(), |s| format!("{s} "));
+
+    let level = |surface: (FixedSurfaceType, Option<f64>)| {
+        if surface.0 == FixedSurfaceType::Missing {
+            "".to_string()
+        } else {
+            let level_value = if let Some(value) = surface.1 {
+                format!("{:.0}", value)
+            } else {
+                "".into()
+            };
+
+            format!(":{level_value} in {}", Parameter::from(surface.0).name)
+        }
+    };
+    let first_level = level(first_fixed_surface);
+    let second_level = level(second_fixed_surface);
+
+    format!("{var}{time}{first_level}{second_level}:{statistical_process}{generating_process}")
+}
+
+/// Iterates the fields of every message in the data, yielding a message scoped to each field
 pub struct MessageIterator<'a> {
     data: &'a [u8],
     offset: usize,
+    field: usize,
+    index: usize,
+    resilient: bool,
+    skipped: Vec<Range<usize>>,
+    sections: Arc<[SectionTable]>,
 }
 
 impl<'a> MessageIterator<'a> {
     pub fn from_data(data: &'a [u8], offset: usize) -> Self {
-        MessageIterator { data, offset }
+        MessageIterator {
+            data,
+            offset,
+            field: 0,
+            index: 0,
+            resilient: false,
+            skipped: Vec::new(),
+            sections: Arc::from([]),
+        }
+    }
+
+    /// Scans forward over bytes that are not part of a complete message instead of stopping at
+    /// them, so headers, padding, corrupt and truncated messages are skipped. A message is only
+    /// read once its length fits the data and it ends with the `7777` end marker.
+    pub fn resilient(mut self) -> Self {
+        self.resilient = true;
+        self
     }
 
     pub fn current_offset(&self) -> usize {
         self.offset
     }
+
+    /// Byte ranges skipped so far in resilient mode
+    pub fn skipped_ranges(&self) -> &[Range<usize>] {
+        &self.skipped
+    }
+
+    /// Moves the offset to the start of the next complete GRIB2 message, recording the ranges
+    /// skipped on the way. Returns `false` when no complete message is left.
+    fn resync(&mut self) -> bool {
+        let mut start = self.offset;
+
+        let found = loop {
+            let Some(position) = self.data[self.offset.min(self.data.len())..]
+                .windows(4)
+                .position(|w| w == b"GRIB")
+                .map(|p| self.offset + p)
+            else {
+                self.offset = self.data.len();
+                break false;
+            };
+
+            match complete_message_length(self.data, position) {
+                // GRIB1 messages are complete, skip them without reporting them
+                Some(length) if message_edition(self.data, position) == Some(1) => {
+                    self.record_skipped(start..position);
+                    self.offset = position + length;
+                    start = self.offset;
+                }
+                Some(_) => {
+                    self.offset = position;
+                    break true;
+                }
+                None => self.offset = position + 1,
+            }
+        };
+
+        self.record_skipped(start..self.offset);
+        found
+    }
+
+    fn record_skipped(&mut self, range: Range<usize>) {
+        if !range.is_empty() {
+            self.skipped.push(range);
+        }
+    }
+}
+
+/// Whether a GRIB2 message with a usable length starts at `offset`
+fn is_message(data: &[u8], offset: usize) -> bool {
+    let mut sections = SectionIterator { data, offset };
+
+    matches!(
+        sections.next(),
+        Some(Section::Indicator(indicator)) if indicator.edition() == 2 && has_message_length(data, offset)
+    )
+}
+
+/// Section tables of every field of the message at `offset`, reading messages without a data
+/// section as a single field
+fn message_section_tables(data: &[u8], offset: usize) -> Arc<[SectionTable]> {
+    let mut tables = field_section_tables(data, offset);
+    if tables.is_empty() {
+        tables.push(message_section_table(data, offset));
+    }
+    tables.into()
+}
+
+/// Whether the indicator of the message at `offset` gives a length that can hold the indicator and
+/// end sections, so iterating by message length always moves forward
+pub(crate) fn has_message_length(data: &[u8], offset: usize) -> bool {
+    data.get(offset..)
+        .is_some_and(|message| message_size(message, offset).is_ok())
+}
+
+/// Length of the message at `offset` when it is complete, fitting the data and closed by the end
+/// marker
+fn complete_message_length(data: &[u8], offset: usize) -> Option<usize> {
+    let length = message_size(data.get(offset..)?, offset).ok()?;
+    let end = offset.checked_add(length)?;
+
+    match data.get(end - 4..end) {
+        Some(b"7777") => Some(length),
+        _ => None,
+    }
 }
 
 impl<'a> Iterator for MessageIterator<'a> {
     type Item = Message<'a>;
 
     fn next(&mut self) -> std::option::Option<<Self as std::iter::Iterator>::Item> {
+        if self.field == 0 && self.resilient && !self.resync() {
+            return None;
+        }
+
+        // GRIB1 messages are read with `Grib1Message`, skip over them rather than misparse them
+        while self.field == 0 && message_edition(self.data, self.offset) == Some(1) {
+            self.offset += Grib1Message::from_data(self.data, self.offset)?.len();
+        }
+
         if self.offset >= self.data.len() {
             return None;
         }
 
-        match Message::from_data(&self.data, self.offset) {
-            Some(m) => {
-                self.offset += m.len();
-                Some(m)
+        // Locate the sections of every field when reaching a message, later fields reuse them
+        if self.field == 0 {
+            if !is_message(self.data, self.offset) {
+                return None;
             }
-            None => None,
+            self.sections = message_section_tables(self.data, self.offset);
         }
+
+        let field_count = self.sections.len();
+        if self.field >= field_count {
+            return None;
+        }
+        let mut field = Message::with_sections(self.data, self.offset, self.field, self.sections.clone());
+        let length = field.len();
+        field.index = Some(self.index);
+        self.index += 1;
+
+        if self.field + 1 < field_count {
+            self.field += 1;
+        } else {
+            self.field = 0;
+            self.offset = self.offset.saturating_add(length);
+        }
+
+        Some(field)
     }
 }
 
+/// A GRIB message scoped to one of its fields. Messages can hold several fields by repeating
+/// sections 2 to 7, and every accessor reads the sections in effect for the selected field.
+///
+/// The section tables of every field are located once when the message is created and shared by
+/// the fields it yields, and the product, grid and data representation templates are decoded on
+/// first use and cached, so reading many accessors of the same message does not walk or copy its
+/// sections again.
 pub struct Message<'a> {
     data: &'a [u8],
     offset: usize,
+    field: usize,
+    index: Option<usize>,
+    /// Section tables of every field of the message, indexed by field
+    sections: Arc<[SectionTable]>,
+    product_template: OnceLock<Result<Box<dyn ProductTemplate + 'a>, GribberishError>>,
+    grid_template: OnceLock<Result<Box<dyn GridDefinitionTemplate + 'a>, GribberishError>>,
+    data_representation_template:
+        OnceLock<Result<Box<dyn DataRepresentationTemplate<f64> + 'a>, GribberishError>>,
 }
 
+/// A single field of a message, see [`Message::fields`]
+pub type Field<'a> = Message<'a>;
+
 impl<'a> Message<'a> {
     pub fn from_data(data: &'a [u8], offset: usize) -> Option<Message> {
-        let mut sections = SectionIterator { data: data, offset };
+        if is_message(data, offset) {
+            Message::fields_from_data(data, offset).next()
+        } else {
+            None
+        }
+    }
 
-        match sections.next() {
-            Some(Section::Indicator(_)) => Some(Message {
-                data: &data,
-                offset: offset,
-            }),
-            _ => None,
+    fn with_sections(data: &'a [u8], offset: usize, field: usize, sections: Arc<[SectionTable]>) -> Message<'a> {
+        Message {
+            data,
+            offset,
+            field,
+            index: None,
+            sections,
+            product_template: OnceLock::new(),
+            grid_template: OnceLock::new(),
+            data_representation_template: OnceLock::new(),
         }
     }
 
+    /// Every field of the message at `offset`, locating the sections of all fields in one pass
+    fn fields_from_data(data: &'a [u8], offset: usize) -> impl Iterator<Item = Message<'a>> {
+        Message::fields_with_sections(data, offset, message_section_tables(data, offset))
+    }
+
+    fn fields_with_sections(
+        data: &'a [u8],
+        offset: usize,
+        sections: Arc<[SectionTable]>,
+    ) -> impl Iterator<Item = Message<'a>> {
+        (0..sections.len()).map(move |field| Message::with_sections(data, offset, field, sections.clone()))
+    }
+
+    /// Index of the field this message is scoped to
+    pub fn field_index(&self) -> usize {
+        self.field
+    }
+
+    /// Position of the message among those yielded by the [`MessageIterator`] it was read with
+    pub fn message_index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Attaches the message's position to an error raised while reading it
+    pub(crate) fn error_context(&self, error: GribberishError) -> GribberishError {
+        error.in_message(self.index, self.offset)
+    }
+
+    /// Number of fields in the message. Messages without a data section count as a single field
+    pub fn field_count(&self) -> usize {
+        self.sections.len()
+    }
+
+    pub fn field(&self, index: usize) -> Option<Field<'a>> {
+        (index < self.field_count())
+            .then(|| Message::with_sections(self.data, self.offset, index, self.sections.clone()))
+    }
+
+    pub fn fields(&self) -> impl Iterator<Item = Field<'a>> + 'a {
+        Message::fields_with_sections(self.data, self.offset, self.sections.clone())
+    }
+
     pub fn byte_data(&self) -> &'a [u8] {
         self.data
     }
@@ -91,59 +370,39 @@ impl<'a> Message<'a> {
         self.offset
     }
 
-    pub fn sections(&self) -> SectionIterator {
-        SectionIterator {
-            data: self.data,
-            offset: self.offset,
-        }
+    /// The sections in effect for this message's field
+    pub fn sections(&self) -> impl Iterator<Item = Section<'a>> + 'a {
+        let data = self.data;
+        (*self.section_table())
+            .into_iter()
+            .flatten()
+            .filter_map(move |offset| Section::from_data(data, offset))
     }
 
-    pub fn key(&self) -> Result<String, GribberishError> {
-        let time = self
-            .forecast_date()
-            .map(|t| format!(":{}", t.format("%Y%m%d%H%M")))
-            .unwrap_or("".into())
-            .to_string();
-        let var = self.variable_abbrev()?;
-        let generating_process = self.generating_process()?.to_string();
-        let statistical_process = self
-            .statistical_process_type()
-            .unwrap_or(None)
-            .map_or("".to_string(), |s| format!("{s} "));
-        let first_fixed_surface = self.first_fixed_surface()?;
-        let first_level = if first_fixed_surface.0 == FixedSurfaceType::Missing {
-            "".into()
-        } else {
-            let level_value = if let Some(value) = first_fixed_surface.1 {
-                format!("{:.0}", value)
-            } else {
-                "".into()
-            };
-
-            format!(
-                ":{level_value} in {}",
-                Parameter::from(first_fixed_surface.0).name
-            )
-        };
+    /// Offsets of the sections in effect for this message's field, indexed by section number
+    pub fn section_table(&self) -> &SectionTable {
+        &self.sections[self.field]
+    }
 
-        let second_fixed_surface = self.second_fixed_surface()?;
-        let second_level = if second_fixed_surface.0 == FixedSurfaceType::Missing {
-            "".into()
-        } else {
-            let level_value = if let Some(value) = second_fixed_surface.1 {
-                format!("{:.0}", value)
-            } else {
-                "".into()
-            };
+    fn section(&self, number: u8) -> Option<Section<'a>> {
+        self.section_table()[number as usize].and_then(|offset| Section::from_data(self.data, offset))
+    }
 
-            format!(
-                ":{level_value} in {}",
-                Parameter::from(second_fixed_surface.0).name
-            )
-        };
+    /// Byte offset of section `number` from the start of the message
+    fn section_offset(&self, number: u8) -> usize {
+        self.section_table()[number as usize]
+            .map(|offset| offset.saturating_sub(self.offset))
+            .unwrap_or(0)
+    }
 
-        Ok(format!(
-            "{var}{time}{first_level}{second_level}:{statistical_process}{generating_process}"
+    pub fn key(&self) -> Result<String, GribberishError> {
+        Ok(message_key(
+            &self.variable_abbrev()?,
+            self.forecast_date().ok(),
+            self.first_fixed_surface()?,
+            self.second_fixed_surface()?,
+            self.statistical_processing().unwrap_or(None),
+            self.generating_process()?,
         ))
     }
 
@@ -200,10 +459,9 @@ impl<'a> Message<'a> {
     }
 
     pub fn len(&self) -> usize {
-        match self.sections().next() {
-            Some(Section::Indicator(i)) => i.total_length() as usize,
-            Some(_) => 0,
-            None => 0,
+        match self.indicator_section() {
+            Ok(indicator) => indicator.total_length() as usize,
+            Err(_) => 0,
         }
     }
 
@@ -212,79 +470,63 @@ impl<'a> Message<'a> {
     }
 
     pub fn discipline(&self) -> Result<Discipline, GribberishError> {
-        match self.sections().next().unwrap() {
-            Section::Indicator(indicator) => Ok(indicator.discipline()),
-            _ => Err(GribberishError::MessageError(
-                "Indicator section not found when reading discipline".into(),
-            )),
-        }
-        .clone()
+        Ok(self.indicator_section()?.discipline())
     }
 
     pub fn product_template_id(&self) -> Result<u16, GribberishError> {
-        let mut sections = self.sections();
-
-        let product_definition = unwrap_or_return!(
-            sections.find_map(|s| match s {
-                Section::ProductDefinition(product_definition) => Some(product_definition),
-                _ => None,
-            }),
-            GribberishError::MessageError(
-                "Product definition section not found when reading variable data".into()
-            )
-        );
+        let product_definition = self.product_definition_section()?;
 
         Ok(product_definition.product_definition_template_number())
     }
 
-    pub fn product_template(&self) -> Result<Box<dyn ProductTemplate>, GribberishError> {
-        let mut sections = self.sections();
+    pub fn vertical_coordinate_values(&self) -> Result<Vec<f32>, GribberishError> {
+        let product_definition = self.product_definition_section()?;
 
-        let discipline = unwrap_or_return!(
-            sections.find_map(|s| match s {
-                Section::Indicator(indicator) => Some(indicator.discipline_value()),
-                _ => None,
-            }),
-            GribberishError::MessageError(
-                "Failed to read discipline value from indicator section".into()
-            )
-        );
-
-        let product_definition = unwrap_or_return!(
-            sections.find_map(|s| match s {
-                Section::ProductDefinition(product_definition) => Some(product_definition),
-                _ => None,
-            }),
-            GribberishError::MessageError(
-                "Product definition section not found when reading variable data".into()
-            )
-        );
-
-        let product_template = unwrap_or_return!(
-            product_definition.product_definition_template(discipline),
-            GribberishError::MessageError(
-                "Only HorizontalAnalysisForecast templates are supported at this time".into()
-            )
-        );
+        Ok(product_definition.vertical_coordinate_values())
+    }
 
-        Ok(product_template)
+    /// The product template of the field, decoded on first use
+    pub fn product_template(&self) -> Result<&dyn ProductTemplate, GribberishError> {
+        self.product_template
+            .get_or_init(|| {
+                let discipline = self.indicator_section()?.discipline_value();
+                self.product_definition_section()?
+                    .product_definition_template(discipline)
+                    .map_err(|e| e.at_section_offset(self.section_offset(4)))
+            })
+            .as_deref()
+            .map_err(Clone::clone)
     }
 
-    pub fn grid_template(&self) -> Result<Box<dyn GridDefinitionTemplate>, GribberishError> {
-        let grid_definition = unwrap_or_return!(
-            self.sections().find_map(|s| match s {
-                Section::GridDefinition(grid_definition) => Some(grid_definition),
-                _ => None,
-            }),
-            GribberishError::MessageError("Grid definition section not found when reading variable data".into())
-        );
+    pub fn is_partial_product_template(&self) -> Result<bool, GribberishError> {
+        let product_template = self.product_template()?;
+        Ok(product_template.is_partial())
+    }
 
-        let grid_template = unwrap_or_return!(
-            grid_definition.grid_definition_template(),
-            GribberishError::MessageError("Only latitude longitude templates supported at this time".into())
-        );
+    /// The grid definition template of the field, decoded on first use
+    pub fn grid_template(&self) -> Result<&dyn GridDefinitionTemplate, GribberishError> {
+        self.grid_template
+            .get_or_init(|| {
+                self.grid_definition_section()?
+                    .grid_definition_template()
+                    .map_err(|e| e.at_section_offset(self.section_offset(3)))
+            })
+            .as_deref()
+            .map_err(Clone::clone)
+    }
 
-        Ok(grid_template)
+    /// The data representation template of the field, decoded on first use
+    pub fn data_representation_template(
+        &self,
+    ) -> Result<&dyn DataRepresentationTemplate<f64>, GribberishError> {
+        self.data_representation_template
+            .get_or_init(|| {
+                self.data_representation_section()?
+                    .data_representation_template()
+                    .map_err(|e| e.at_section_offset(self.section_offset(5)))
+            })
+            .as_deref()
+            .map_err(Clone::clone)
     }
 
     pub fn parameter_index(&self) -> Result<String, GribberishError> {
@@ -298,17 +540,34 @@ impl<'a> Message<'a> {
 
     pub fn parameter(&self) -> Result<Parameter, GribberishError> {
         let product_template = self.product_template()?;
+        let centre = self.originating_centre_value()?;
+        let local_table_version = self.local_table_version()?;
+        let registered_parameter = registered_parameter(
+            centre,
+            self.originating_sub_centre()?,
+            local_table_version,
+            product_template.discipline(),
+            product_template.category_value(),
+            product_template.parameter_value(),
+        );
 
         let parameter = unwrap_or_return!(
-            product_template.parameter(),
-            GribberishError::MessageError(
-                format!(
-                    "This Product and Parameter is currently not supported: ({}, {})",
-                    product_template.category_value(),
-                    product_template.parameter_value()
-                )
-                .into()
-            )
+            registered_parameter
+                .or_else(|| {
+                    local_parameter(
+                        centre,
+                        local_table_version,
+                        product_template.discipline(),
+                        product_template.category_value(),
+                        product_template.parameter_value(),
+                    )
+                })
+                .or_else(|| product_template.parameter()),
+            GribberishError::UnsupportedParameter {
+                discipline: product_template.discipline(),
+                category: product_template.category_value(),
+                number: product_template.parameter_value(),
+            }
         );
 
         Ok(parameter)
@@ -316,7 +575,40 @@ impl<'a> Message<'a> {
 
     pub fn category(&self) -> Result<String, GribberishError> {
         let product_template = self.product_template()?;
-        Ok(product_template.category().to_owned())
+        let centre = self.originating_centre_value()?;
+        let local_table_version = self.local_table_version()?;
+        if let Some(category) = registered_category(
+            centre,
+            self.originating_sub_centre()?,
+            local_table_version,
+            product_template.discipline(),
+            product_template.category_value(),
+        ) {
+            return Ok(category);
+        }
+
+        let local_category = local_category(
+            centre,
+            local_table_version,
+            product_template.discipline(),
+            product_template.category_value(),
+        );
+        Ok(local_category
+            .unwrap_or_else(|| product_template.category())
+            .to_owned())
+    }
+
+    /// ecCodes `shortName` and `paramId` for the parameter on its first fixed surface
+    pub fn eccodes_parameter(&self) -> Result<Option<EccodesParameter>, GribberishError> {
+        let product_template = self.product_template()?;
+        let (surface_type, surface_value) = self.first_fixed_surface()?;
+        Ok(eccodes_parameter(
+            product_template.discipline(),
+            product_template.category_value(),
+            product_template.parameter_value(),
+            &surface_type,
+            surface_value,
+        ))
     }
 
     pub fn variable_name(&self) -> Result<String, GribberishError> {
@@ -324,6 +616,11 @@ impl<'a> Message<'a> {
         Ok(parameter.name)
     }
 
+    pub fn standard_name(&self) -> Result<Option<String>, GribberishError> {
+        let parameter = self.parameter()?;
+        Ok(parameter.standard_name)
+    }
+
     pub fn variable_abbrev(&self) -> Result<String, GribberishError> {
         let parameter = self.parameter()?;
         Ok(parameter.abbrev)
@@ -334,17 +631,48 @@ impl<'a> Message<'a> {
         Ok(parameter.unit)
     }
 
+    /// Decodes the data converted from the parameter's unit to `target`, returning the converted
+    /// values along with the new unit
+    pub fn data_in_units(&self, target: &str) -> Result<(Vec<f64>, String), GribberishError> {
+        let unit = self.unit()?;
+        let data = convert_values(&self.data()?, &unit, target)?;
+        Ok((data, target.to_string()))
+    }
+
+    pub fn originating_centre_value(&self) -> Result<u16, GribberishError> {
+        Ok(self.identification_section()?.originating_centre_value())
+    }
+
+    pub fn originating_centre(&self) -> Result<OriginatingCentre, GribberishError> {
+        Ok(self.identification_section()?.originating_centre())
+    }
+
+    pub fn originating_sub_centre(&self) -> Result<u16, GribberishError> {
+        Ok(self.identification_section()?.originating_sub_centre())
+    }
+
+    pub fn master_table_version(&self) -> Result<u8, GribberishError> {
+        Ok(self.identification_section()?.master_table_version())
+    }
+
+    pub fn local_table_version(&self) -> Result<u8, GribberishError> {
+        Ok(self.identification_section()?.local_table_version())
+    }
+
+    pub fn local_use_data(&self) -> Option<Vec<u8>> {
+        self.local_use_section()
+            .map(|local_use| local_use.local_data().to_vec())
+    }
+
+    pub fn local_use_definition(&self) -> Result<Option<LocalUseDefinition>, GribberishError> {
+        let centre = self.originating_centre()?;
+        Ok(self
+            .local_use_section()
+            .and_then(|local_use| local_use.local_use_definition(centre)))
+    }
+
     pub fn reference_date(&self) -> Result<DateTime<Utc>, GribberishError> {
-        let reference_date = unwrap_or_return!(
-            self.sections().find_map(|s| match s {
-                Section::Identification(identification) => Some(identification.reference_date()),
-                _ => None,
-            }),
-            GribberishError::MessageError(
-                "Identification section not found when reading reference date".into()
-            )
-        );
-        Ok(reference_date)
+        Ok(self.identification_section()?.reference_date())
     }
 
     pub fn generating_process(&self) -> Result<GeneratingProcess, GribberishError> {
@@ -364,6 +692,18 @@ impl<'a> Message<'a> {
         Ok(product_template.statistical_process_type())
     }
 
+    /// Every statistical process applied to the field, from the outermost time range to the
+    /// innermost one
+    pub fn statistical_processing(&self) -> Result<Option<StatisticalProcessing>, GribberishError> {
+        let product_template = self.product_template()?;
+        Ok(product_template.statistical_processing())
+    }
+
+    pub fn time_ranges(&self) -> Result<Vec<TimeRangeSpecification>, GribberishError> {
+        let product_template = self.product_template()?;
+        Ok(product_template.time_ranges())
+    }
+
     pub fn forecast_date(&self) -> Result<DateTime<Utc>, GribberishError> {
         let product_template = self.product_template()?;
         let reference_date = self.reference_date()?;
@@ -416,15 +756,7 @@ impl<'a> Message<'a> {
     }
 
     pub fn grid_template_id(&self) -> Result<u16, GribberishError> {
-        let grid_definition = unwrap_or_return!(
-            self.sections().find_map(|s| match s {
-                Section::GridDefinition(grid_definition) => Some(grid_definition),
-                _ => None,
-            }),
-            GribberishError::MessageError(
-                "Grid definition section not found when reading variable data".into()
-            )
-        );
+        let grid_definition = self.grid_definition_section()?;
 
         Ok(grid_definition.grid_definition_template_number())
     }
@@ -446,123 +778,131 @@ impl<'a> Message<'a> {
 
     pub fn latlng_projector(&self) -> Result<LatLngProjection, GribberishError> {
         let grid_template = self.grid_template()?;
-        Ok(grid_template.projector())
+        grid_template.projector()
     }
 
     pub fn data_template_number(&self) -> Result<u16, GribberishError> {
-        let data_representation = unwrap_or_return!(
-            self.sections().find_map(|s| match s {
-                Section::DataRepresentation(data_representation) => Some(data_representation),
-                _ => None,
-            }),
-            GribberishError::MessageError(
-                "Product definition section not found when reading variable data".into()
-            )
-        );
+        let data_representation = self.data_representation_section()?;
 
         Ok(data_representation.data_representation_template_number())
     }
 
     pub fn data_compression_type(&self) -> Result<String, GribberishError> {
-        let data_representation = unwrap_or_return!(
-            self.sections().find_map(|s| match s {
-                Section::DataRepresentation(data_representation) => Some(data_representation),
-                _ => None,
-            }),
-            GribberishError::MessageError(
-                "Product definition section not found when reading variable data".into()
-            )
-        );
-
-        let data_representation_template = unwrap_or_return!(
-            data_representation.data_representation_template(),
-            GribberishError::MessageError(
-                "Failed to unpack the data representation template".into()
-            )
-        );
-
-        Ok(data_representation_template.compression_type())
+        Ok(self.data_representation_template()?.compression_type())
     }
 
     pub fn data_point_count(&self) -> Result<usize, GribberishError> {
-        let data_representation = unwrap_or_return!(
-            self.sections().find_map(|s| match s {
-                Section::DataRepresentation(data_representation) => Some(data_representation),
-                _ => None,
-            }),
-            GribberishError::MessageError(
-                "Product definition section not found when reading variable data".into()
-            )
-        );
+        let data_representation = self.data_representation_section()?;
 
         Ok(data_representation.data_point_count())
     }
 
     pub fn has_bitmap(&self) -> bool {
-        let bitmap_section = self.sections().find_map(|s| match s {
-            Section::Bitmap(bitmap_section) => Some(bitmap_section),
-            _ => None,
-        });
-
-        match bitmap_section {
-            Some(b) => b.has_bitmap(),
-            None => false,
-        }
+        self.bitmap_section()
+            .is_ok_and(|bitmap_section| bitmap_section.has_bitmap())
     }
 
     pub fn data(&self) -> Result<Vec<f64>, GribberishError> {
-        let data_section = unwrap_or_return!(
-            self.sections().find_map(|s| match s {
-                Section::Data(data_section) => Some(data_section),
-                _ => None,
-            }),
-            GribberishError::MessageError(
-                "Data section not found when reading message data".into()
-            )
-        );
+        self.unpack_data().map_err(|e| self.error_context(e))
+    }
+
+    fn unpack_data(&self) -> Result<Vec<f64>, GribberishError> {
+        let data_section = self.data_section()?;
 
         let raw_packed_data = data_section.raw_data_array().view_bits();
 
-        let data_representation_section = unwrap_or_return!(
-            self.sections().find_map(|s| match s {
-                Section::DataRepresentation(data_representation_section) =>
-                    Some(data_representation_section),
-                _ => None,
-            }),
-            GribberishError::MessageError(
-                "Data representation section not found when reading message data".into()
-            )
-        );
+        let data_representation_section = self.data_representation_section()?;
+
+        let data_representation_template = self.data_representation_template()?;
 
-        let data_representation_template = unwrap_or_return!(
-            data_representation_section.data_representation_template(),
-            GribberishError::MessageError(
-                "Failed to unpack the data representation template".into()
-            )
+        let shape = self.grid_dimensions()?;
+        let count = unwrap_or_return!(
+            shape.0.checked_mul(shape.1),
+            GribberishError::GridTemplateError(format!("Grid of {} by {} points is too large", shape.0, shape.1))
         );
+        if data_representation_section.data_point_count() > count {
+            return Err(GribberishError::DataRepresentationTemplateError(format!(
+                "{} data points do not fit the grid of {count} points",
+                data_representation_section.data_point_count()
+            )));
+        }
 
         let scaled_unpacked_data = data_representation_template.unpack(raw_packed_data)?;
 
-        let bitmap_section = unwrap_or_return!(
-            self.sections().find_map(|s| match s {
-                Section::Bitmap(bitmap_section) => Some(bitmap_section),
-                _ => None,
-            }),
-            GribberishError::MessageError(
-                "Bitmap section not found when reading message data".into()
-            )
-        );
+        let bitmap_section = self.bitmap_section()?;
 
         let mut data = if bitmap_section.has_bitmap() {
+            let bitmap_length = bitmap_section.raw_bitmap_data().len() * 8;
+            if bitmap_length < count {
+                return Err(GribberishError::InvalidBitmapLength {
+                    length: bitmap_length,
+                    expected: count,
+                });
+            }
+
             let mapped_scaled_data = bitmap_section.map_data(scaled_unpacked_data);
             mapped_scaled_data
         } else {
             scaled_unpacked_data
         };
 
-        let shape = self.grid_dimensions()?;
-        let count = shape.0 * shape.1;
         data.resize(count, 0.0);
         Ok(data)
     }
+
+    fn indicator_section(&self) -> Result<IndicatorSection<'a>, GribberishError> {
+        match self.section(0) {
+            Some(Section::Indicator(indicator)) => Ok(indicator),
+            _ => Err(GribberishError::MissingSection { section: 0 }),
+        }
+    }
+
+    fn identification_section(&self) -> Result<IdentificationSection<'a>, GribberishError> {
+        match self.section(1) {
+            Some(Section::Identification(identification)) => Ok(identification),
+            _ => Err(GribberishError::MissingSection { section: 1 }),
+        }
+    }
+
+    fn local_use_section(&self) -> Option<LocalUseSection<'a>> {
+        match self.section(2) {
+            Some(Section::LocalUse(local_use)) => Some(local_use),
+            _ => None,
+        }
+    }
+
+    fn grid_definition_section(&self) -> Result<GridDefinitionSection<'a>, GribberishError> {
+        match self.section(3) {
+            Some(Section::GridDefinition(grid_definition)) => Ok(grid_definition),
+            _ => Err(GribberishError::MissingSection { section: 3 }),
+        }
+    }
+
+    fn product_definition_section(&self) -> Result<ProductDefinitionSection<'a>, GribberishError> {
+        match self.section(4) {
+            Some(Section::ProductDefinition(product_definition)) => Ok(product_definition),
+            _ => Err(GribberishError::MissingSection { section: 4 }),
+        }
+    }
+
+    fn data_representation_section(&self) -> Result<DataRepresentationSection<'a>, GribberishError> {
+        match self.section(5) {
+            Some(Section::DataRepresentation(data_representation)) => Ok(data_representation),
+            _ => Err(GribberishError::MissingSection { section: 5 }),
+        }
+    }
+
+    fn bitmap_section(&self) -> Result<BitmapSection<'a>, GribberishError> {
+        match self.section(6) {
+            Some(Section::Bitmap(bitmap)) => Ok(bitmap),
+            _ => Err(GribberishError::MissingSection { section: 6 }),
+        }
+    }
+
+    fn data_section(&self) -> Result<DataSection<'a>, GribberishError> {
+        match self.section(7) {
+            Some(Section::Data(data)) => Ok(data),
+            _ => Err(GribberishError::MissingSection { section: 7 }),
+        }
+    }
 }
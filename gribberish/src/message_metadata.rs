@@ -3,8 +3,9 @@ use std::collections::HashMap;
 use chrono::{DateTime, Utc};
 
 use crate::{
-    error::GribberishError, message::{Message, MessageIterator}, templates::product::tables::{
-        FixedSurfaceType, GeneratingProcess, TimeUnit, TypeOfStatisticalProcessing,
+    cf::{self, Attributes}, error::GribberishError, grib1::Grib1Message, message::{message_key, Message}, sections::{identification::OriginatingCentre, indicator::message_edition}, templates::local_use::LocalUseDefinition, templates::product::parameters::eccodes::EccodesParameter, templates::product::tables::{
+        FixedSurfaceType, GeneratingProcess, StatisticalProcessing, TimeUnit,
+        TypeOfStatisticalProcessing,
     }, utils::iter::projection::LatLngProjection
 };
 
@@ -13,11 +14,22 @@ pub struct MessageMetadata {
     pub key: String,
     pub byte_offset: usize,
     pub message_size: usize,
+    pub field_index: usize,
+    pub originating_centre: OriginatingCentre,
+    /// Raw originating centre number, kept for centres that `OriginatingCentre` does not list
+    pub originating_centre_id: u16,
+    pub originating_sub_centre: u16,
+    pub master_table_version: u8,
+    pub local_table_version: u8,
+    pub local_use: Option<LocalUseDefinition>,
     pub var: String,
     pub name: String,
     pub units: String,
+    pub standard_name: Option<String>,
+    pub eccodes_parameter: Option<EccodesParameter>,
     pub generating_process: GeneratingProcess,
     pub statistical_process: Option<TypeOfStatisticalProcessing>,
+    pub statistical_processing: Option<StatisticalProcessing>,
     pub time_unit: TimeUnit,
     pub time_increment_unit: Option<TimeUnit>,
     pub time_interval: u32,
@@ -30,6 +42,7 @@ pub struct MessageMetadata {
     pub category: String,
     pub data_compression: String,
     pub has_bitmap: bool,
+    pub is_partial_product_template: bool,
     pub reference_date: DateTime<Utc>,
     pub forecast_date: DateTime<Utc>,
     pub forecast_end_date: Option<DateTime<Utc>>,
@@ -45,6 +58,28 @@ impl MessageMetadata {
         self.grid_shape.0 * self.grid_shape.1
     }
 
+    pub fn mars_keys(&self) -> Option<HashMap<&'static str, String>> {
+        self.local_use
+            .as_ref()
+            .and_then(|local_use| local_use.mars_labeling())
+            .map(|labeling| labeling.keys())
+    }
+
+    /// CF `cell_methods` describing the statistical processing over time, if any
+    pub fn cell_methods(&self) -> Option<String> {
+        self.statistical_processing.as_ref().and_then(cf::cell_methods)
+    }
+
+    /// CF attributes for the vertical coordinate of the first fixed surface
+    pub fn vertical_coordinate_attributes(&self) -> Option<Attributes> {
+        cf::vertical_coordinate_attributes(&self.first_fixed_surface_type)
+    }
+
+    /// CF `grid_mapping` variable attributes for the message's projection
+    pub fn grid_mapping(&self) -> Option<Attributes> {
+        cf::grid_mapping_attributes(&self.projector.proj_name(), &self.projector.proj_params())
+    }
+
     pub fn latlng(&self) -> (Vec<f64>, Vec<f64>) {
         self.projector.lat_lng()
     }
@@ -78,7 +113,7 @@ impl MessageMetadata {
             }
         };
 
-        let statistical_process = if let Some(statistical_process) = self.statistical_process.as_ref() {
+        let statistical_process = if let Some(statistical_process) = self.statistical_processing.as_ref() {
             format!("{} ", statistical_process.abbv())
         } else {
             "".to_string()
@@ -106,60 +141,201 @@ impl<'a> TryFrom<&Message<'a>> for MessageMetadata {
     type Error = GribberishError;
 
     fn try_from(message: &Message<'a>) -> Result<Self, Self::Error> {
-        let (first_fixed_surface_type, first_fixed_surface_value) =
-            message.first_fixed_surface()?;
-        let (second_fixed_surface_type, second_fixed_surface_value) =
-            message.second_fixed_surface()?;
-
-        Ok(MessageMetadata {
-            key: message.key()?,
-            byte_offset: message.byte_offset(),
-            message_size: message.len(),
-            var: message.variable_abbrev()?,
-            name: message.variable_name()?,
-            units: message.unit()?,
-            generating_process: message.generating_process()?,
-            statistical_process: message.statistical_process_type()?,
-            time_unit: message.time_unit()?,
-            time_increment_unit: message.time_increment_unit()?,
-            time_interval: message.time_interval()?,
-            time_increment_interval: message.time_increment_interval()?,
-            first_fixed_surface_type,
-            first_fixed_surface_value,
-            second_fixed_surface_type,
-            second_fixed_surface_value,
-            discipline: message.discipline()?.to_string(),
-            category: message.category()?,
-            data_compression: format!(
-                "{}: {}",
-                message.data_template_number().unwrap_or(99),
-                message
-                    .data_compression_type()
-                    .unwrap_or("Unknown".to_string())
-            ),
-            has_bitmap: message.has_bitmap(),
-            reference_date: message.reference_date()?,
-            forecast_date: message.forecast_date()?,
-            forecast_end_date: message.forecast_end_date()?,
-            proj: message.proj_string()?,
-            crs: message.crs()?,
-            is_regular_grid: message.is_regular_grid()?,
-            grid_shape: message.grid_dimensions()?,
-            projector: message.latlng_projector()?,
-        })
+        message_metadata(message).map_err(|e| message.error_context(e))
+    }
+}
+
+impl<'a> TryFrom<&Grib1Message<'a>> for MessageMetadata {
+    type Error = GribberishError;
+
+    fn try_from(message: &Grib1Message<'a>) -> Result<Self, Self::Error> {
+        grib1_message_metadata(message).map_err(|e| message.error_context(e))
+    }
+}
+
+/// Builds the metadata of a field, reading the parameter and each template once from the
+/// message's cached section table
+fn message_metadata(message: &Message) -> Result<MessageMetadata, GribberishError> {
+    let parameter = message.parameter()?;
+    let first_fixed_surface = message.first_fixed_surface()?;
+    let second_fixed_surface = message.second_fixed_surface()?;
+    let statistical_processing = message.statistical_processing()?;
+    let generating_process = message.generating_process()?;
+    let forecast_date = message.forecast_date()?;
+
+    let key = message_key(
+        &parameter.abbrev,
+        Some(forecast_date),
+        first_fixed_surface.clone(),
+        second_fixed_surface.clone(),
+        statistical_processing.clone(),
+        generating_process.clone(),
+    );
+    let (first_fixed_surface_type, first_fixed_surface_value) = first_fixed_surface;
+    let (second_fixed_surface_type, second_fixed_surface_value) = second_fixed_surface;
+
+    Ok(MessageMetadata {
+        key,
+        byte_offset: message.byte_offset(),
+        message_size: message.len(),
+        field_index: message.field_index(),
+        originating_centre: message.originating_centre()?,
+        originating_centre_id: message.originating_centre_value()?,
+        originating_sub_centre: message.originating_sub_centre()?,
+        master_table_version: message.master_table_version()?,
+        local_table_version: message.local_table_version()?,
+        local_use: message.local_use_definition()?,
+        var: parameter.abbrev,
+        name: parameter.name,
+        units: parameter.unit,
+        standard_name: parameter.standard_name,
+        eccodes_parameter: message.eccodes_parameter()?,
+        generating_process,
+        statistical_process: message.statistical_process_type()?,
+        statistical_processing,
+        time_unit: message.time_unit()?,
+        time_increment_unit: message.time_increment_unit()?,
+        time_interval: message.time_interval()?,
+        time_increment_interval: message.time_increment_interval()?,
+        first_fixed_surface_type,
+        first_fixed_surface_value,
+        second_fixed_surface_type,
+        second_fixed_surface_value,
+        discipline: message.discipline()?.to_string(),
+        category: message.category()?,
+        data_compression: format!(
+            "{}: {}",
+            message.data_template_number().unwrap_or(99),
+            message
+                .data_compression_type()
+                .unwrap_or("Unknown".to_string())
+        ),
+        has_bitmap: message.has_bitmap(),
+        is_partial_product_template: message.is_partial_product_template()?,
+        reference_date: message.reference_date()?,
+        forecast_date,
+        forecast_end_date: message.forecast_end_date()?,
+        proj: message.proj_string()?,
+        crs: message.crs()?,
+        is_regular_grid: message.is_regular_grid()?,
+        grid_shape: message.grid_dimensions()?,
+        projector: message.latlng_projector()?,
+    })
+}
+
+fn grib1_message_metadata(message: &Grib1Message) -> Result<MessageMetadata, GribberishError> {
+    let (first_fixed_surface_type, first_fixed_surface_value) =
+        message.first_fixed_surface()?;
+    let (second_fixed_surface_type, second_fixed_surface_value) =
+        message.second_fixed_surface()?;
+
+    Ok(MessageMetadata {
+        key: message.key()?,
+        byte_offset: message.byte_offset(),
+        message_size: message.len(),
+        field_index: 0,
+        originating_centre: message.originating_centre()?,
+        originating_centre_id: message.originating_centre_value()? as u16,
+        originating_sub_centre: message.originating_sub_centre()? as u16,
+        master_table_version: message.table_version()?,
+        local_table_version: 0,
+        local_use: None,
+        var: message.variable_abbrev()?,
+        name: message.variable_name()?,
+        units: message.unit()?,
+        standard_name: message.standard_name()?,
+        eccodes_parameter: message.eccodes_parameter()?,
+        generating_process: message.generating_process()?,
+        statistical_process: message.statistical_process_type()?,
+        statistical_processing: message.statistical_processing()?,
+        time_unit: message.time_unit()?,
+        time_increment_unit: message.time_increment_unit()?,
+        time_interval: message.time_interval()?,
+        time_increment_interval: message.time_increment_interval()?,
+        first_fixed_surface_type,
+        first_fixed_surface_value,
+        second_fixed_surface_type,
+        second_fixed_surface_value,
+        discipline: message.discipline()?.to_string(),
+        category: message.category()?,
+        data_compression: format!(
+            "grib1: {}",
+            message
+                .data_compression_type()
+                .unwrap_or("Unknown".to_string())
+        ),
+        has_bitmap: message.has_bitmap(),
+        is_partial_product_template: false,
+        reference_date: message.reference_date()?,
+        forecast_date: message.forecast_date()?,
+        forecast_end_date: message.forecast_end_date()?,
+        proj: message.proj_string()?,
+        crs: message.crs()?,
+        is_regular_grid: message.is_regular_grid()?,
+        grid_shape: message.grid_dimensions()?,
+        projector: message.latlng_projector()?,
+    })
+}
+
+/// Reads the metadata of a field of the message at `offset`, reading GRIB1 messages as a single
+/// field
+pub fn read_message_metadata(
+    data: &[u8],
+    offset: usize,
+    field: usize,
+) -> Result<MessageMetadata, GribberishError> {
+    match message_edition(data, offset) {
+        Some(1) => Grib1Message::from_data(data, offset)
+            .ok_or_else(|| GribberishError::MessageError("No GRIB1 message found".into()))
+            .and_then(|message| MessageMetadata::try_from(&message)),
+        _ => Message::from_data(data, offset)
+            .and_then(|m| m.field(field))
+            .ok_or_else(|| GribberishError::MessageError(format!("No message with field {field} found")))
+            .and_then(|message| MessageMetadata::try_from(&message)),
     }
+    .map_err(|e| e.in_message(None, offset))
+}
+
+/// Iterates the metadata of every field of every message, GRIB1 and GRIB2 alike, stopping at the
+/// first bytes that do not start a message
+pub fn iter_message_metadata(
+    data: &[u8],
+) -> impl Iterator<Item = Result<MessageMetadata, GribberishError>> + '_ {
+    let mut offset = 0;
+
+    std::iter::from_fn(move || {
+        let (length, fields) = match message_edition(data, offset)? {
+            1 => {
+                let message = Grib1Message::from_data(data, offset)?;
+                (message.len(), vec![MessageMetadata::try_from(&message)])
+            }
+            _ => {
+                let message = Message::from_data(data, offset)?;
+                let fields = message
+                    .fields()
+                    .map(|field| MessageMetadata::try_from(&field))
+                    .collect::<Vec<_>>();
+                (message.len(), fields)
+            }
+        };
+
+        offset = match length {
+            0 => data.len(),
+            length => offset.saturating_add(length),
+        };
+        Some(fields)
+    })
+    .flatten()
 }
 
+/// Scans the metadata of every field of every message, GRIB1 and GRIB2 alike, keyed by message
+/// key with the index of the field in the data and the message's byte offset
 pub fn scan_message_metadata<'a>(
     data: &'a [u8],
 ) -> HashMap<String, (usize, usize, MessageMetadata)> {
-    let message_iter = MessageIterator::from_data(data, 0);
-
-    message_iter
+    iter_message_metadata(data)
         .enumerate()
-        .filter_map(|(index, m)| match MessageMetadata::try_from(&m) {
-            Ok(mm) => Some(((&mm.key).clone(), (index, m.byte_offset(), mm))),
-            Err(_) => None,
-        })
+        .filter_map(|(index, metadata)| metadata.ok().map(|mm| (index, mm)))
+        .map(|(index, mm)| (mm.key.clone(), (index, mm.byte_offset, mm)))
         .collect()
 }
@@ -0,0 +1,466 @@
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
+
+use crate::data_message::{read_data_message, DataMessage};
+use crate::error::GribberishError;
+use crate::grib1::Grib1Message;
+use crate::message::{read_message_data, Message};
+use crate::message_metadata::{read_message_metadata, MessageMetadata};
+use crate::sections::indicator::message_edition;
+use crate::utils::{read_u24_from_bytes, read_u32_from_bytes, read_u64_from_bytes};
+
+pub(crate) const GRIB1_INDICATOR_LENGTH: usize = 8;
+const GRIB2_INDICATOR_LENGTH: usize = 16;
+const SECTION_HEADER_LENGTH: usize = 5;
+const GRIB1_BINARY_DATA_HEADER_LENGTH: usize = 11;
+const END_SECTION: &[u8; 4] = b"7777";
+/// Set by ECMWF in the GRIB1 total length of messages too long for its 24 bits
+const GRIB1_LARGE_MESSAGE_FLAG: u32 = 0x800000;
+/// Size of the blocks counted by the total length of large GRIB1 messages
+const GRIB1_LARGE_MESSAGE_BLOCK: usize = 120;
+
+/// Length of the indicator section of the message starting with the first eight `data` bytes,
+/// which is all of a GRIB1 indicator but only the start of a GRIB2 indicator
+fn indicator_length(data: &[u8], offset: usize) -> Result<usize, GribberishError> {
+    match message_edition(data, 0) {
+        Some(1) => Ok(GRIB1_INDICATOR_LENGTH),
+        Some(2) => Ok(GRIB2_INDICATOR_LENGTH),
+        Some(edition) => Err(GribberishError::Unsupported(format!("GRIB edition {edition}"))
+            .in_message(None, offset)),
+        None => Err(GribberishError::MessageError("No GRIB indicator found".into())
+            .in_message(None, offset)),
+    }
+}
+
+/// Size of the whole message read from its complete indicator section. Large GRIB1 messages
+/// also need their headers up to the length of the binary data section, see
+/// [`missing_size_bytes`].
+pub(crate) fn message_size(indicator: &[u8], offset: usize) -> Result<usize, GribberishError> {
+    let indicator_length = indicator_length(indicator, offset)?;
+    let message_size = match indicator_length {
+        GRIB1_INDICATOR_LENGTH => grib1_message_size(indicator, offset)?,
+        _ => read_u64_from_bytes(indicator, 8).unwrap_or(0) as usize,
+    };
+
+    if message_size < indicator_length + END_SECTION.len() {
+        return Err(GribberishError::MessageError(format!("Invalid message length {message_size}"))
+            .in_message(None, offset));
+    }
+    Ok(message_size)
+}
+
+/// Whether the GRIB1 message starting `data` follows the ECMWF convention for messages longer
+/// than its 24 bit total length can hold
+pub(crate) fn is_large_grib1_message(data: &[u8]) -> bool {
+    grib1_large_message_blocks(data).is_some()
+}
+
+/// Size of the 120 byte blocks counted by the lower 23 bits of the total length of a large GRIB1
+/// message, or `None` for messages within the 24 bit length
+fn grib1_large_message_blocks(data: &[u8]) -> Option<usize> {
+    let length = read_u24_from_bytes(data, 4)?;
+    (length & GRIB1_LARGE_MESSAGE_FLAG > 0)
+        .then(|| (length & !GRIB1_LARGE_MESSAGE_FLAG) as usize * GRIB1_LARGE_MESSAGE_BLOCK)
+}
+
+/// The blocks of a large GRIB1 message overshoot it by the length of its binary data section,
+/// less the four bytes of the end section
+fn grib1_message_size(data: &[u8], offset: usize) -> Result<usize, GribberishError> {
+    let Some(blocks_size) = grib1_large_message_blocks(data) else {
+        return Ok(read_u24_from_bytes(data, 4).unwrap_or(0) as usize);
+    };
+
+    match grib1_binary_data(data, blocks_size, 3, offset)? {
+        Grib1BinaryData::Start(start) => {
+            let overshoot = read_u24_from_bytes(data, start).unwrap_or(0) as usize;
+            Ok((blocks_size + END_SECTION.len()).saturating_sub(overshoot))
+        }
+        Grib1BinaryData::Missing(_) => Err(GribberishError::MessageError(
+            "Large GRIB1 message is missing the headers giving its length".into(),
+        )
+        .in_message(None, offset)),
+    }
+}
+
+/// Number of bytes to read after the indicator section before the size of the message is known,
+/// or `None` when the indicator is enough
+fn missing_size_bytes(data: &[u8], offset: usize) -> Result<Option<usize>, GribberishError> {
+    let blocks_size = match message_edition(data, 0) {
+        Some(1) => grib1_large_message_blocks(data),
+        _ => None,
+    };
+    let Some(blocks_size) = blocks_size else {
+        return Ok(None);
+    };
+
+    match grib1_binary_data(data, blocks_size, 3, offset)? {
+        Grib1BinaryData::Start(_) => Ok(None),
+        Grib1BinaryData::Missing(missing) => Ok(Some(missing)),
+    }
+}
+
+/// How much of a message read from a stream is needed to know its size, see [`message_framing`]
+pub(crate) enum MessageFraming {
+    /// Number of bytes to append before the size of the message is known
+    Missing(usize),
+    /// Size of the whole message
+    Size(usize),
+}
+
+/// Frames the message read from a stream so far into `data`, which holds at least the first
+/// [`GRIB1_INDICATOR_LENGTH`] bytes of the message. Readers append the missing bytes until the
+/// size of the message is known, so both GRIB editions and large GRIB1 messages are framed alike
+/// by the blocking and async readers.
+pub(crate) fn message_framing(data: &[u8], offset: usize) -> Result<MessageFraming, GribberishError> {
+    let indicator_length = indicator_length(data, offset)?;
+    if data.len() < indicator_length {
+        return Ok(MessageFraming::Missing(indicator_length - data.len()));
+    }
+
+    match missing_size_bytes(data, offset)? {
+        Some(missing) => Ok(MessageFraming::Missing(missing)),
+        None => message_size(data, offset).map(MessageFraming::Size),
+    }
+}
+
+/// The error for a stream ending inside the message at `offset`
+pub(crate) fn stream_ended(offset: usize) -> GribberishError {
+    GribberishError::IoError("Stream ended inside the message".into()).in_message(None, offset)
+}
+
+/// Where the binary data section of a GRIB1 message starts, or how many more bytes are needed
+/// to find it
+pub(crate) enum Grib1BinaryData {
+    Start(usize),
+    Missing(usize),
+}
+
+/// Walks the sections of the GRIB1 message starting `data` up to `header_length` bytes into its
+/// binary data section, checking each section fits in `message_size`
+pub(crate) fn grib1_binary_data(
+    data: &[u8],
+    message_size: usize,
+    header_length: usize,
+    offset: usize,
+) -> Result<Grib1BinaryData, GribberishError> {
+    // The product definition section flags which of the grid description and bitmap
+    // sections follow it, the binary data section always comes last
+    let mut present = [true, false, false];
+    let mut position = GRIB1_INDICATOR_LENGTH;
+
+    for section in 0..present.len() {
+        if !present[section] {
+            continue;
+        }
+        if data.len() < position + 3 {
+            return Ok(Grib1BinaryData::Missing(position + 3 - data.len()));
+        }
+
+        let section_length = read_u24_from_bytes(data, position).unwrap_or(0) as usize;
+        if section_length < 3 || position + section_length > message_size {
+            return Err(GribberishError::MessageError(format!(
+                "Invalid section length {section_length}"
+            ))
+            .in_message(None, offset));
+        }
+        if data.len() < position + section_length {
+            return Ok(Grib1BinaryData::Missing(position + section_length - data.len()));
+        }
+
+        if section == 0 {
+            let flags = data.get(position + 7).copied().unwrap_or(0);
+            present[1] = flags & 0x80 > 0;
+            present[2] = flags & 0x40 > 0;
+        }
+        position += section_length;
+    }
+
+    match data.len() < position + header_length {
+        true => Ok(Grib1BinaryData::Missing(position + header_length - data.len())),
+        false => Ok(Grib1BinaryData::Start(position)),
+    }
+}
+
+/// A single GRIB message read from a stream, owning its bytes
+pub struct OwnedMessage {
+    data: Vec<u8>,
+    byte_offset: usize,
+}
+
+impl OwnedMessage {
+    pub(crate) fn new(data: Vec<u8>, byte_offset: usize) -> Self {
+        OwnedMessage { data, byte_offset }
+    }
+
+    /// Byte offset of the message in the stream it was read from
+    pub fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+
+    pub fn byte_data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Size of the message in the stream, as given by its indicator section
+    pub fn message_size(&self) -> usize {
+        message_size(&self.data, self.byte_offset).unwrap_or(0)
+    }
+
+    pub fn edition(&self) -> Option<u8> {
+        message_edition(&self.data, 0)
+    }
+
+    /// The message as a GRIB2 message, or `None` for GRIB1 messages
+    pub fn message(&self) -> Option<Message<'_>> {
+        Message::from_data(&self.data, 0)
+    }
+
+    /// The message as a GRIB1 message, or `None` for GRIB2 messages
+    pub fn grib1_message(&self) -> Option<Grib1Message<'_>> {
+        Grib1Message::from_data(&self.data, 0)
+    }
+
+    pub fn field_count(&self) -> usize {
+        match self.edition() {
+            Some(1) => 1,
+            _ => self.message().map(|m| m.field_count()).unwrap_or(0),
+        }
+    }
+
+    pub fn metadata(&self, field: usize) -> Result<MessageMetadata, GribberishError> {
+        let mut metadata = read_message_metadata(&self.data, 0, field)
+            .map_err(|e| e.in_message(None, self.byte_offset))?;
+        metadata.byte_offset = self.byte_offset;
+        Ok(metadata)
+    }
+
+    pub fn data(&self, field: usize) -> Result<Vec<f64>, GribberishError> {
+        read_message_data(&self.data, 0, field).map_err(|e| e.in_message(None, self.byte_offset))
+    }
+
+    pub fn data_message(&self, field: usize) -> Result<DataMessage, GribberishError> {
+        let mut data_message = read_data_message(&self.data, 0, field)
+            .map_err(|e| e.in_message(None, self.byte_offset))?;
+        data_message.metadata.byte_offset = self.byte_offset;
+        Ok(data_message)
+    }
+}
+
+/// Reads GRIB1 and GRIB2 messages one at a time from a stream, so only the message being read
+/// is held in memory. Reads are small and frequent, wrap unbuffered readers in a `BufReader`.
+pub struct GribReader<R> {
+    reader: R,
+    offset: usize,
+    finished: bool,
+}
+
+impl<R: Read> GribReader<R> {
+    pub fn new(reader: R) -> Self {
+        GribReader {
+            reader,
+            offset: 0,
+            finished: false,
+        }
+    }
+
+    /// Byte offset in the stream of the next message to be read
+    pub fn current_offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads the next message, or `None` once the stream ends between messages
+    pub fn read_message(&mut self) -> Result<Option<OwnedMessage>, GribberishError> {
+        let Some((mut data, message_size)) = self.read_indicator()? else {
+            return Ok(None);
+        };
+
+        let remaining = message_size - data.len();
+        self.read_into(&mut data, remaining)?;
+        Ok(Some(self.finish_message(data, message_size)))
+    }
+
+    /// Reads the indicator section of the next message, returning its bytes and the size of the
+    /// whole message
+    fn read_indicator(&mut self) -> Result<Option<(Vec<u8>, usize)>, GribberishError> {
+        let mut data = vec![0; GRIB1_INDICATOR_LENGTH];
+        if !self.read_or_eof(&mut data)? {
+            return Ok(None);
+        }
+
+        loop {
+            match message_framing(&data, self.offset)? {
+                MessageFraming::Missing(missing) => self.read_into(&mut data, missing)?,
+                MessageFraming::Size(message_size) => return Ok(Some((data, message_size))),
+            }
+        }
+    }
+
+    /// Fills `data` from the stream, returning `false` when the stream has already ended
+    fn read_or_eof(&mut self, data: &mut [u8]) -> Result<bool, GribberishError> {
+        let mut filled = 0;
+        while filled < data.len() {
+            match self.reader.read(&mut data[filled..]) {
+                Ok(0) if filled == 0 => return Ok(false),
+                Ok(0) => return Err(stream_ended(self.offset)),
+                Ok(read) => filled += read,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(GribberishError::IoError(e.to_string())),
+            }
+        }
+        Ok(true)
+    }
+
+    /// Appends the next `length` bytes of the stream to `data`
+    fn read_into(&mut self, data: &mut Vec<u8>, length: usize) -> Result<(), GribberishError> {
+        let read = (&mut self.reader)
+            .take(length as u64)
+            .read_to_end(data)
+            .map_err(|e| GribberishError::IoError(e.to_string()))?;
+
+        if read < length {
+            return Err(stream_ended(self.offset));
+        }
+        Ok(())
+    }
+
+    fn finish_message(&mut self, data: Vec<u8>, message_size: usize) -> OwnedMessage {
+        let message = OwnedMessage::new(data, self.offset);
+        self.offset += message_size;
+        message
+    }
+}
+
+impl<R: Read + Seek> GribReader<R> {
+    /// Reads the next message without its packed data, seeking past the data sections. The
+    /// returned message holds the headers of its data sections, so its metadata can be read but
+    /// decoding its data fails.
+    pub fn read_message_headers(&mut self) -> Result<Option<OwnedMessage>, GribberishError> {
+        let Some((mut data, message_size)) = self.read_indicator()? else {
+            return Ok(None);
+        };
+
+        match message_edition(&data, 0) {
+            Some(1) => self.read_grib1_headers(&mut data, message_size)?,
+            _ => self.read_grib2_headers(&mut data, message_size)?,
+        }
+
+        data.extend_from_slice(END_SECTION);
+        Ok(Some(self.finish_message(data, message_size)))
+    }
+
+    /// Reads the metadata of every field of the next message, seeking past its data
+    pub fn read_metadata(&mut self) -> Result<Option<Vec<MessageMetadata>>, GribberishError> {
+        let Some(message) = self.read_message_headers()? else {
+            return Ok(None);
+        };
+
+        (0..message.field_count())
+            .map(|field| message.metadata(field))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some)
+    }
+
+    /// Iterates the metadata of every field of the remaining messages, seeking past their data
+    pub fn metadata(&mut self) -> impl Iterator<Item = Result<MessageMetadata, GribberishError>> + '_ {
+        std::iter::from_fn(move || {
+            if self.finished {
+                return None;
+            }
+
+            match self.read_metadata() {
+                Ok(Some(metadata)) => Some(metadata.into_iter().map(Ok).collect::<Vec<_>>()),
+                Ok(None) => {
+                    self.finished = true;
+                    None
+                }
+                Err(e) => {
+                    self.finished = true;
+                    Some(vec![Err(e)])
+                }
+            }
+        })
+        .flatten()
+    }
+
+    fn read_grib2_headers(&mut self, data: &mut Vec<u8>, message_size: usize) -> Result<(), GribberishError> {
+        let mut position = data.len();
+
+        while position + END_SECTION.len() < message_size {
+            let header_start = data.len();
+            self.read_into(data, SECTION_HEADER_LENGTH)?;
+
+            let section_length = read_u32_from_bytes(data, header_start).unwrap_or(0) as usize;
+            if section_length < SECTION_HEADER_LENGTH || position + section_length > message_size {
+                return Err(GribberishError::MessageError(format!(
+                    "Invalid section length {section_length}"
+                ))
+                .in_message(None, self.offset));
+            }
+
+            if data[header_start + 4] == 7 {
+                data[header_start..header_start + 4]
+                    .copy_from_slice(&(SECTION_HEADER_LENGTH as u32).to_be_bytes());
+                self.skip(section_length - SECTION_HEADER_LENGTH)?;
+            } else {
+                self.read_into(data, section_length - SECTION_HEADER_LENGTH)?;
+            }
+
+            position += section_length;
+        }
+
+        self.skip(message_size - position)
+    }
+
+    fn read_grib1_headers(&mut self, data: &mut Vec<u8>, message_size: usize) -> Result<(), GribberishError> {
+        let header_start = loop {
+            match grib1_binary_data(data, message_size, GRIB1_BINARY_DATA_HEADER_LENGTH, self.offset)? {
+                Grib1BinaryData::Start(start) => break start,
+                Grib1BinaryData::Missing(missing) => self.read_into(data, missing)?,
+            }
+        };
+
+        // The binary data section length of a large message completes the message length, and the
+        // section is read up to the end of the data instead
+        if !is_large_grib1_message(data) {
+            data[header_start..header_start + 3]
+                .copy_from_slice(&(GRIB1_BINARY_DATA_HEADER_LENGTH as u32).to_be_bytes()[1..]);
+        }
+
+        self.skip(message_size.saturating_sub(data.len()))
+    }
+
+    fn skip(&mut self, length: usize) -> Result<(), GribberishError> {
+        self.reader
+            .seek(SeekFrom::Current(length as i64))
+            .map(|_| ())
+            .map_err(|e| GribberishError::IoError(e.to_string()))
+    }
+}
+
+impl<R: Read> Iterator for GribReader<R> {
+    type Item = Result<OwnedMessage, GribberishError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let message = self.read_message().transpose();
+        if !matches!(message, Some(Ok(_))) {
+            self.finished = true;
+        }
+        message
+    }
+}
+
+/// Reads GRIB messages one at a time from `reader`
+pub fn read_grib_stream<R: Read>(reader: R) -> GribReader<R> {
+    GribReader::new(reader)
+}
@@ -3,17 +3,18 @@ use bitvec::prelude::*;
 use std::vec::Vec;
 use std::iter::Iterator;
 use crate::utils::read_u32_from_bytes;
-use super::grib_section::GribSection;
+use crate::error::GribberishError;
+use super::grib_section::{check_section_length, minimum_section_length, GribSection};
 
 pub struct BitmapSection<'a> {
     data: &'a [u8],
 }
 
 impl <'a> BitmapSection<'a> {
-    pub fn from_data(data: &'a [u8]) -> Self {
-        BitmapSection {
-            data,
-        }
+    /// The section stored in `data`, failing when it is too short for the accessors
+    pub fn from_data(data: &'a [u8]) -> Result<Self, GribberishError> {
+        check_section_length(6, data, minimum_section_length(6))?;
+        Ok(BitmapSection { data })
     }
 
     pub fn has_bitmap(&self) -> bool {
@@ -36,10 +37,10 @@ impl <'a> BitmapSection<'a> {
 
         for (i, mask) in bitmask.iter().enumerate() {
             data[i] = match *mask {
-                true => unmapped_data[i - nan_count],
+                true => unmapped_data.get(i - nan_count).copied().unwrap_or(f64::NAN),
                 _ => {
                     nan_count += 1;
-                    std::f64::NAN
+                    f64::NAN
                 }
             };
         }
@@ -95,7 +96,7 @@ impl <'a> BitmapSection<'a> {
 
 impl <'a> GribSection for BitmapSection<'a> {
     fn len(&self) -> usize {
-        read_u32_from_bytes(&self.data[0..4], 0).unwrap_or(0) as usize
+        read_u32_from_bytes(self.data, 0).unwrap_or(0) as usize
     }
 
     fn number(&self) -> u8 {
@@ -1,15 +1,16 @@
 use crate::utils::read_u32_from_bytes;
-use super::grib_section::GribSection;
+use crate::error::GribberishError;
+use super::grib_section::{check_section_length, minimum_section_length, GribSection};
 
 pub struct DataSection<'a> {
     data: &'a [u8],
 }
 
 impl <'a> DataSection<'a> {
-    pub fn from_data(data: &'a [u8]) -> Self {
-        DataSection {
-            data,
-        }
+    /// The section stored in `data`, failing when it is too short for the accessors
+    pub fn from_data(data: &'a [u8]) -> Result<Self, GribberishError> {
+        check_section_length(7, data, minimum_section_length(7))?;
+        Ok(DataSection { data })
     }
 
     pub fn raw_data_array(&self) -> &[u8] {
@@ -19,7 +20,7 @@ impl <'a> DataSection<'a> {
 
 impl <'a> GribSection for DataSection<'a> {
     fn len(&self) -> usize {
-        read_u32_from_bytes(&self.data[0..4], 0).unwrap_or(0) as usize
+        read_u32_from_bytes(self.data, 0).unwrap_or(0) as usize
     }
 
     fn number(&self) -> u8 {
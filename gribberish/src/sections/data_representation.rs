@@ -1,3 +1,4 @@
+use crate::error::GribberishError;
 use crate::utils::{read_u16_from_bytes, read_u32_from_bytes};
 use crate::templates::data_representation::{DataRepresentationTemplate, SimplePackingDataRepresentationTemplate, ComplexPackingDataRepresentationTemplate, ComplexSpatialPackingDataRepresentationTemplate};
 #[cfg(feature = "jpeg")]
@@ -5,17 +6,17 @@ use crate::templates::data_representation::JPEGDataRepresentationTemplate;
 #[cfg(feature = "png")]
 use crate::templates::data_representation::PNGDataRepresentationTemplate;
 
-use super::grib_section::GribSection;
+use super::grib_section::{check_section_length, minimum_section_length, GribSection};
 
 pub struct DataRepresentationSection<'a> {
     data: &'a [u8],
 }
 
 impl <'a> DataRepresentationSection<'a> {
-    pub fn from_data(data: &'a [u8]) -> Self {
-        DataRepresentationSection {
-            data,
-        }
+    /// The section stored in `data`, failing when it is too short for the accessors
+    pub fn from_data(data: &'a [u8]) -> Result<Self, GribberishError> {
+        check_section_length(5, data, minimum_section_length(5))?;
+        Ok(DataRepresentationSection { data })
     }
     
     pub fn data_point_count(&self) -> usize {
@@ -26,24 +27,24 @@ impl <'a> DataRepresentationSection<'a> {
         read_u16_from_bytes(self.data, 9).unwrap_or(0)
     }
 
-    pub fn data_representation_template(&self) -> Option<Box<dyn DataRepresentationTemplate<f64>>> {
+    pub fn data_representation_template(&self) -> Result<Box<dyn DataRepresentationTemplate<f64> + 'a>, GribberishError> {
         let template_number = self.data_representation_template_number();
         match template_number {
-            0 => Some(Box::new(SimplePackingDataRepresentationTemplate::new(self.data.to_vec()))),
-            2 => Some(Box::new(ComplexPackingDataRepresentationTemplate::new(self.data.to_vec()))),
-            3 => Some(Box::new(ComplexSpatialPackingDataRepresentationTemplate::new(self.data.to_vec()))),
+            0 => Ok(Box::new(SimplePackingDataRepresentationTemplate::new(self.data)?)),
+            2 => Ok(Box::new(ComplexPackingDataRepresentationTemplate::new(self.data)?)),
+            3 => Ok(Box::new(ComplexSpatialPackingDataRepresentationTemplate::new(self.data)?)),
             #[cfg(feature = "jpeg")]
-            40 => Some(Box::new(JPEGDataRepresentationTemplate::new(self.data.to_vec()))),
+            40 => Ok(Box::new(JPEGDataRepresentationTemplate::new(self.data)?)),
             #[cfg(feature = "png")]
-            41 => Some(Box::new(PNGDataRepresentationTemplate::new(self.data.to_vec()))),
-            _ => None,
+            41 => Ok(Box::new(PNGDataRepresentationTemplate::new(self.data)?)),
+            template => Err(GribberishError::UnsupportedTemplate { section: 5, template }),
         }
     }
 }
 
 impl <'a> GribSection for DataRepresentationSection<'a> {
     fn len(&self) -> usize {
-        read_u32_from_bytes(&self.data[0..4], 0).unwrap_or(0) as usize
+        read_u32_from_bytes(self.data, 0).unwrap_or(0) as usize
     }
 
     fn number(&self) -> u8 {
@@ -1,9 +1,10 @@
 use std::str;
-use super::grib_section::GribSection;
+use crate::error::GribberishError;
+use super::grib_section::{check_section_length, minimum_section_length, GribSection};
 
 fn validate_end_section(data: &[u8]) -> bool {
-    match str::from_utf8(&data[0..4]) {
-		Ok(s) => s == "7777",
+    match data.get(0..4).map(str::from_utf8) {
+		Some(Ok(s)) => s == "7777",
 		_ => false
 	}
 }
@@ -13,18 +14,18 @@ pub struct EndSection<'a> {
 }
 
 impl <'a> EndSection<'a> {
-    pub fn from_data(data: &'a [u8]) -> Self {
-        EndSection {
-            data,
-        }
+    /// The section stored in `data`, failing when it is too short for the accessors
+    pub fn from_data(data: &'a [u8]) -> Result<Self, GribberishError> {
+        check_section_length(8, data, minimum_section_length(8))?;
+        Ok(EndSection { data })
     }
 
     pub fn is_end_section(data: &[u8], offset: usize) -> bool {
-        validate_end_section(&data[offset..offset+4])
+        data.get(offset..).is_some_and(validate_end_section)
     }
 
     pub fn valid(&self) -> bool {
-        validate_end_section(&self.data[0..4])
+        validate_end_section(self.data)
     }
 }
 
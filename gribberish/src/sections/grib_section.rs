@@ -1,5 +1,30 @@
+use crate::error::GribberishError;
 
 pub trait GribSection {
 	fn len(&self) -> usize;
 	fn number(&self) -> u8;
 }
+
+/// Minimum length of each GRIB2 section, covering the octets read by its fixed accessors
+pub(crate) fn minimum_section_length(section: u8) -> usize {
+    match section {
+        0 => 16,
+        1 => 21,
+        2 => 5,
+        3 => 14,
+        4 => 9,
+        5 => 11,
+        6 => 6,
+        7 => 5,
+        _ => 4,
+    }
+}
+
+/// Fails when `data` is shorter than the `expected` octets read by the accessors of section
+/// `section`, so sections and templates can index their data once constructed
+pub(crate) fn check_section_length(section: u8, data: &[u8], expected: usize) -> Result<(), GribberishError> {
+    if data.len() < expected {
+        return Err(GribberishError::TruncatedSection { section, offset: 0, length: data.len(), expected });
+    }
+    Ok(())
+}
@@ -1,6 +1,6 @@
-use crate::{utils::{read_u16_from_bytes, read_u32_from_bytes}, templates::grid_definition::{GridDefinitionTemplate, LatLngTemplate, LambertConformalTemplate}};
+use crate::{error::GribberishError, utils::{read_u16_from_bytes, read_u32_from_bytes}, templates::grid_definition::{GridDefinitionTemplate, LatLngTemplate, LambertConformalTemplate}};
 use gribberish_macros::{DisplayDescription, FromValue};
-use super::grib_section::GribSection;
+use super::grib_section::{check_section_length, minimum_section_length, GribSection};
 
 #[repr(u8)]
 #[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
@@ -31,10 +31,10 @@ pub struct GridDefinitionSection<'a> {
 }
 
 impl <'a> GridDefinitionSection<'a> {
-    pub fn from_data(data: &'a [u8]) -> Self {
-        GridDefinitionSection {
-            data,
-        }
+    /// The section stored in `data`, failing when it is too short for the accessors
+    pub fn from_data(data: &'a [u8]) -> Result<Self, GribberishError> {
+        check_section_length(3, data, minimum_section_length(3))?;
+        Ok(GridDefinitionSection { data })
     }
 
     pub fn grid_source(&self) -> GridSource {
@@ -57,19 +57,19 @@ impl <'a> GridDefinitionSection<'a> {
         read_u16_from_bytes(self.data, 12).unwrap_or(0)
     }
 
-    pub fn grid_definition_template(&self) -> Option<Box<dyn GridDefinitionTemplate>> {
+    pub fn grid_definition_template(&self) -> Result<Box<dyn GridDefinitionTemplate + 'a>, GribberishError> {
         let template_number = self.grid_definition_template_number();
        		match template_number {
-			0 => Some(Box::new(LatLngTemplate::new(self.data.to_vec()))),
-            30 => Some(Box::new(LambertConformalTemplate::new(self.data.to_vec()))),
-			_ => None,
+			0 => Ok(Box::new(LatLngTemplate::new(self.data)?)),
+            30 => Ok(Box::new(LambertConformalTemplate::new(self.data)?)),
+			template => Err(GribberishError::UnsupportedTemplate { section: 3, template }),
 		}
     }
 }
 
 impl <'a> GribSection for GridDefinitionSection<'a> {
     fn len(&self) -> usize {
-        read_u32_from_bytes(&self.data[0..4], 0).unwrap_or(0) as usize
+        read_u32_from_bytes(self.data, 0).unwrap_or(0) as usize
     }
 
     fn number(&self) -> u8 {
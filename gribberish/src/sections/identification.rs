@@ -1,6 +1,7 @@
 use gribberish_macros::{DisplayDescription, FromValue};
 use crate::utils::{read_u32_from_bytes, read_u16_from_bytes};
-use super::grib_section::GribSection;
+use crate::error::GribberishError;
+use super::grib_section::{check_section_length, minimum_section_length, GribSection};
 use chrono::prelude::*;
 
 #[repr(u8)]
@@ -62,17 +63,178 @@ pub enum GribDataType {
     Missing = 255,
 }
 
+/// Originating centres, WMO Common Code Table C-11
+#[repr(u8)]
+#[derive(Clone, Copy, Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+pub enum OriginatingCentre {
+    #[description = "WMO Secretariat"]
+    WMOSecretariat = 0,
+    #[description = "Melbourne"]
+    Melbourne = 1,
+    #[description = "Moscow"]
+    Moscow = 4,
+    #[description = "US National Weather Service - NCEP"]
+    NCEP = 7,
+    #[description = "US National Weather Service - NWSTG"]
+    NWSTG = 8,
+    #[description = "US National Weather Service - other"]
+    NWSOther = 9,
+    #[description = "Cairo (RSMC)"]
+    Cairo = 10,
+    #[description = "Dakar (RSMC)"]
+    Dakar = 12,
+    #[description = "Nairobi (RSMC)"]
+    Nairobi = 14,
+    #[description = "Casablanca (RSMC)"]
+    Casablanca = 16,
+    #[description = "Tunis (RSMC)"]
+    Tunis = 18,
+    #[description = "Las Palmas"]
+    LasPalmas = 20,
+    #[description = "Algiers (RSMC)"]
+    Algiers = 21,
+    #[description = "ACMAD"]
+    ACMAD = 22,
+    #[description = "Pretoria (RSMC)"]
+    Pretoria = 24,
+    #[description = "Khabarovsk (RSMC)"]
+    Khabarovsk = 26,
+    #[description = "New Delhi (RSMC)"]
+    NewDelhi = 28,
+    #[description = "Novosibirsk (RSMC)"]
+    Novosibirsk = 30,
+    #[description = "Tashkent (RSMC)"]
+    Tashkent = 32,
+    #[description = "Tokyo (RSMC), Japan Meteorological Agency"]
+    JMA = 34,
+    #[description = "Bangkok"]
+    Bangkok = 36,
+    #[description = "Beijing (RSMC)"]
+    Beijing = 38,
+    #[description = "Seoul"]
+    Seoul = 40,
+    #[description = "Buenos Aires (RSMC)"]
+    BuenosAires = 41,
+    #[description = "Brasilia (RSMC)"]
+    Brasilia = 43,
+    #[description = "Santiago"]
+    Santiago = 45,
+    #[description = "Brazilian Space Agency - INPE"]
+    INPE = 46,
+    #[description = "Miami (RSMC)"]
+    Miami = 51,
+    #[description = "Miami (RSMC), National Hurricane Center"]
+    NHC = 52,
+    #[description = "Canadian Meteorological Service - Montreal (RSMC)"]
+    CMC = 54,
+    #[description = "San Francisco"]
+    SanFrancisco = 55,
+    #[description = "US Air Force - Air Force Global Weather Central"]
+    AFGWC = 57,
+    #[description = "US Navy - Fleet Numerical Meteorology and Oceanography Center"]
+    FNMOC = 58,
+    #[description = "NOAA Forecast Systems Laboratory"]
+    NOAAFSL = 59,
+    #[description = "National Center for Atmospheric Research"]
+    NCAR = 60,
+    #[description = "Service ARGOS - Landover"]
+    ARGOS = 61,
+    #[description = "US Naval Oceanographic Office"]
+    NAVO = 62,
+    #[description = "Honolulu (RSMC)"]
+    Honolulu = 64,
+    #[description = "Darwin (RSMC)"]
+    Darwin = 65,
+    #[description = "Melbourne (RSMC)"]
+    MelbourneRSMC = 67,
+    #[description = "Wellington (RSMC)"]
+    Wellington = 69,
+    #[description = "UK Met Office - Exeter (RSMC)"]
+    UKMO = 74,
+    #[description = "Moscow (RSMC)"]
+    MoscowRSMC = 76,
+    #[description = "Offenbach (RSMC), Deutscher Wetterdienst"]
+    DWD = 78,
+    #[description = "Rome (RSMC)"]
+    Rome = 80,
+    #[description = "Norrkoping"]
+    Norrkoping = 82,
+    #[description = "Toulouse (RSMC), Meteo-France"]
+    MeteoFrance = 84,
+    #[description = "Toulouse (RSMC)"]
+    Toulouse = 85,
+    #[description = "Helsinki"]
+    Helsinki = 86,
+    #[description = "Oslo"]
+    Oslo = 88,
+    #[description = "Copenhagen"]
+    Copenhagen = 94,
+    #[description = "Athens"]
+    Athens = 96,
+    #[description = "European Space Agency"]
+    ESA = 97,
+    #[description = "European Centre for Medium-Range Weather Forecasts"]
+    ECMWF = 98,
+    #[description = "De Bilt"]
+    DeBilt = 99,
+    #[description = "Hong Kong"]
+    HongKong = 110,
+    #[description = "US NOAA/NESDIS"]
+    NESDIS = 160,
+    #[description = "US NOAA Office of Oceanic and Atmospheric Research"]
+    NOAAOAR = 161,
+    #[description = "US National Aeronautics and Space Administration"]
+    NASA = 173,
+    #[description = "Madrid"]
+    Madrid = 214,
+    #[description = "Zurich"]
+    Zurich = 215,
+    #[description = "Dublin"]
+    Dublin = 233,
+    #[description = "EUMETSAT operation centre"]
+    EUMETSAT = 254,
+    Missing = 255,
+}
+
+impl From<u16> for OriginatingCentre {
+    fn from(value: u16) -> Self {
+        u8::try_from(value)
+            .map(OriginatingCentre::from)
+            .unwrap_or(OriginatingCentre::Missing)
+    }
+}
+
 pub struct IdentificationSection<'a> {
     data: &'a [u8],
 }
 
 impl <'a> IdentificationSection<'a> {
-    pub fn from_data(data: &'a [u8]) -> Self {
-        IdentificationSection {
-            data,
-        }
+    /// The section stored in `data`, failing when it is too short for the accessors
+    pub fn from_data(data: &'a [u8]) -> Result<Self, GribberishError> {
+        check_section_length(1, data, minimum_section_length(1))?;
+        Ok(IdentificationSection { data })
     }
  
+    pub fn originating_centre_value(&self) -> u16 {
+        read_u16_from_bytes(self.data, 5).unwrap_or(0)
+    }
+
+    pub fn originating_centre(&self) -> OriginatingCentre {
+        self.originating_centre_value().into()
+    }
+
+    pub fn originating_sub_centre(&self) -> u16 {
+        read_u16_from_bytes(self.data, 7).unwrap_or(0)
+    }
+
+    pub fn master_table_version(&self) -> u8 {
+        self.data[9]
+    }
+
+    pub fn local_table_version(&self) -> u8 {
+        self.data[10]
+    }
+
     pub fn reference_date_significance(&self) -> ReferenceDataSignificance {
         self.data[11].into()
     }
@@ -85,7 +247,7 @@ impl <'a> IdentificationSection<'a> {
         let minute = self.data[17] as u32;
         let second = self.data[18] as u32;
 
-        Utc.with_ymd_and_hms(year, month, day, hour, minute, second).unwrap()
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, second).single().unwrap_or_default()
     }
 
     pub fn production_status(&self) -> ProductionStatus {
@@ -99,7 +261,7 @@ impl <'a> IdentificationSection<'a> {
 
 impl <'a> GribSection for IdentificationSection<'a> {
     fn len(&self) -> usize {
-        read_u32_from_bytes(&self.data[0..4], 0).unwrap_or(0) as usize
+        read_u32_from_bytes(self.data, 0).unwrap_or(0) as usize
     }
 
     fn number(&self) -> u8 {
@@ -1,11 +1,12 @@
 use std::str;
 use gribberish_macros::{DisplayDescription, FromValue};
 use crate::utils::read_u64_from_bytes;
-use super::grib_section::GribSection;
+use crate::error::GribberishError;
+use super::grib_section::{check_section_length, minimum_section_length, GribSection};
 
 fn validate_indicator_section(data: &[u8]) -> bool {
-	match str::from_utf8(&data[0..4]) {
-		Ok(s) => s == "GRIB",
+	match data.get(0..4).map(str::from_utf8) {
+		Some(Ok(s)) => s == "GRIB",
 		_ => false
 	}
 }
@@ -17,29 +18,40 @@ pub enum Discipline {
 	Hydrological = 1,
 	LandSurface = 2,
 	Space = 3,
+	#[description = "space weather"]
+	SpaceWeather = 4,
 	Oceanographic = 10,
 	MultiRadarMultiSensor = 209,
 	Missing = 255,
 }
 
+/// Reads the GRIB edition of the message starting at `offset`, or `None` when there is no
+/// indicator section there
+pub fn message_edition(data: &[u8], offset: usize) -> Option<u8> {
+    match data.get(offset..offset + 8) {
+        Some(indicator) if validate_indicator_section(indicator) => Some(indicator[7]),
+        _ => None,
+    }
+}
+
 pub struct IndicatorSection<'a> {
     data: &'a [u8],
 }
 
 impl <'a> IndicatorSection<'a> {
 
-	pub fn from_data(data: &'a [u8]) -> Self {
-		IndicatorSection {
-            data,
-		}
+	/// The section stored in `data`, failing when it is too short for the accessors
+	pub fn from_data(data: &'a [u8]) -> Result<Self, GribberishError> {
+		check_section_length(0, data, minimum_section_length(0))?;
+		Ok(IndicatorSection { data })
 	}
 
     pub fn is_indicator_section(data: &[u8], offset: usize) -> bool {
-        validate_indicator_section(&data[offset..offset+4])
+        data.get(offset..).is_some_and(validate_indicator_section)
     }
 
 	pub fn valid(&self) -> bool {
-        validate_indicator_section(&self.data[0..4])
+        validate_indicator_section(self.data)
     }
 
 	pub fn discipline_value(&self) -> u8 {
@@ -76,7 +88,7 @@ mod tests {
 		use super::{IndicatorSection, Discipline};
 
 		let raw = [0x47u8, 0x52, 0x49, 0x42, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xb3];
-		let indicator = IndicatorSection::from_data(&raw);
+		let indicator = IndicatorSection::from_data(&raw).unwrap();
         assert!(indicator.valid());
 		assert!(indicator.discipline() == Discipline::Meteorological);
 	}
@@ -1,21 +1,36 @@
+use crate::templates::local_use::LocalUseDefinition;
 use crate::utils::read_u32_from_bytes;
-use super::grib_section::GribSection;
+use crate::error::GribberishError;
+use super::grib_section::{check_section_length, minimum_section_length, GribSection};
+use super::identification::OriginatingCentre;
 
 pub struct LocalUseSection<'a> {
     data: &'a [u8],
 }
 
 impl <'a> LocalUseSection<'a> {
-    pub fn from_data(data: &'a [u8]) -> Self {
-        LocalUseSection {
-            data,
+    /// The section stored in `data`, failing when it is too short for the accessors
+    pub fn from_data(data: &'a [u8]) -> Result<Self, GribberishError> {
+        check_section_length(2, data, minimum_section_length(2))?;
+        Ok(LocalUseSection { data })
+    }
+
+    pub fn local_data(&self) -> &'a [u8] {
+        let end = self.len().min(self.data.len());
+        if end <= 5 {
+            return &[];
         }
+        &self.data[5..end]
+    }
+
+    pub fn local_use_definition(&self, centre: OriginatingCentre) -> Option<LocalUseDefinition> {
+        LocalUseDefinition::from_data(centre, self.local_data())
     }
 }
 
 impl <'a> GribSection for LocalUseSection<'a> {
     fn len(&self) -> usize {
-        read_u32_from_bytes(&self.data[0..4], 0).unwrap_or(0) as usize
+        read_u32_from_bytes(self.data, 0).unwrap_or(0) as usize
     }
 
     fn number(&self) -> u8 {
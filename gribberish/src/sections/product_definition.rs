@@ -1,42 +1,361 @@
-use crate::{utils::{read_u16_from_bytes, read_u32_from_bytes}, templates::product::{product_template::ProductTemplate, HorizontalAnalysisForecastTemplate, AverageAccumulationExtremeHorizontalAnalysisForecastTemplate, DerivedEnsembleHorizontalAnalysisForecastTemplate, derived_ensemble_horizontal_forecast_time_interval_template::DerivedEnsembleHorizontalForecastTimeIntervalTemplate}};
-use super::grib_section::GribSection;
+use crate::{error::GribberishError, utils::{read_f32_from_bytes, read_u16_from_bytes, read_u32_from_bytes}, templates::product::{product_template::ProductTemplate, HorizontalAnalysisForecastTemplate, AverageAccumulationExtremeHorizontalAnalysisForecastTemplate, DerivedEnsembleHorizontalAnalysisForecastTemplate, derived_ensemble_horizontal_forecast_time_interval_template::DerivedEnsembleHorizontalForecastTimeIntervalTemplate, SpatiallyProcessedHorizontalAnalysisForecastTemplate, AerosolAverageAccumulationExtremeHorizontalForecastTemplate, AerosolEnsembleHorizontalForecastTimeIntervalTemplate, EnsembleReforecastHorizontalAnalysisForecastTemplate, EnsembleReforecastHorizontalForecastTimeIntervalTemplate, GenericProductTemplate}};
+use super::grib_section::{check_section_length, minimum_section_length, GribSection};
 
 pub struct ProductDefinitionSection<'a> {
     data: &'a [u8],
 }
 
 impl <'a> ProductDefinitionSection<'a> {
-    pub fn from_data(data: &'a [u8]) -> Self {
-        ProductDefinitionSection {
-            data,
-        }
+    /// The section stored in `data`, failing when it is too short for the accessors
+    pub fn from_data(data: &'a [u8]) -> Result<Self, GribberishError> {
+        check_section_length(4, data, minimum_section_length(4))?;
+        Ok(ProductDefinitionSection { data })
     }
 
     pub fn coord_values_after_template(&self) -> u16 {
         read_u16_from_bytes(self.data, 5).unwrap_or(0)
     }
 
+    /// The list of vertical coordinate values following the product template, such as the
+    /// a and b coefficients of hybrid levels. Empty when the section does not carry any.
+    pub fn vertical_coordinate_values(&self) -> Vec<f32> {
+        let count = self.coord_values_after_template() as usize;
+        let end = self.len().min(self.data.len());
+        let start = match end.checked_sub(count * 4) {
+            Some(start) if start >= 9 => start,
+            _ => return Vec::new(),
+        };
+
+        (0..count)
+            .filter_map(|i| read_f32_from_bytes(self.data, start + i * 4))
+            .collect()
+    }
+
     pub fn product_definition_template_number(&self) -> u16 {
         read_u16_from_bytes(self.data, 7).unwrap_or(0)
     }
 
-    pub fn product_definition_template(&self, discipline: u8) -> Option<Box<dyn ProductTemplate>> {
+    pub fn product_definition_template(&self, discipline: u8) -> Result<Box<dyn ProductTemplate + 'a>, GribberishError> {
         match self.product_definition_template_number() {
-            0 => Some(Box::new(HorizontalAnalysisForecastTemplate::new(self.data.to_vec(), discipline))),
-            2 => Some(Box::new(DerivedEnsembleHorizontalAnalysisForecastTemplate::new(self.data.to_vec(), discipline))),
-            8 => Some(Box::new(AverageAccumulationExtremeHorizontalAnalysisForecastTemplate::new(self.data.to_vec(), discipline))),
-            12 => Some(Box::new(DerivedEnsembleHorizontalForecastTimeIntervalTemplate::new(self.data.to_vec(), discipline))),
-            _ => None
+            0 => Ok(Box::new(HorizontalAnalysisForecastTemplate::new(self.data, discipline)?)),
+            2 => Ok(Box::new(DerivedEnsembleHorizontalAnalysisForecastTemplate::new(self.data, discipline)?)),
+            8 => Ok(Box::new(AverageAccumulationExtremeHorizontalAnalysisForecastTemplate::new(self.data, discipline)?)),
+            12 => Ok(Box::new(DerivedEnsembleHorizontalForecastTimeIntervalTemplate::new(self.data, discipline)?)),
+            15 => Ok(Box::new(SpatiallyProcessedHorizontalAnalysisForecastTemplate::new(self.data, discipline)?)),
+            46 => Ok(Box::new(AerosolAverageAccumulationExtremeHorizontalForecastTemplate::new(self.data, discipline)?)),
+            47 => Ok(Box::new(AerosolEnsembleHorizontalForecastTimeIntervalTemplate::new(self.data, discipline)?)),
+            60 => Ok(Box::new(EnsembleReforecastHorizontalAnalysisForecastTemplate::new(self.data, discipline)?)),
+            61 => Ok(Box::new(EnsembleReforecastHorizontalForecastTimeIntervalTemplate::new(self.data, discipline)?)),
+            n if GenericProductTemplate::has_common_layout(n) => Ok(Box::new(GenericProductTemplate::new(self.data, discipline, n)?)),
+            template => Err(GribberishError::UnsupportedTemplate { section: 4, template }),
         }
     }
 }
 
 impl <'a> GribSection for ProductDefinitionSection<'a> {
     fn len(&self) -> usize {
-        read_u32_from_bytes(&self.data[0..4], 0).unwrap_or(0) as usize
+        read_u32_from_bytes(self.data, 0).unwrap_or(0) as usize
     }
 
     fn number(&self) -> u8 {
         self.data[4]
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProductDefinitionSection;
+    use crate::error::GribberishError;
+    use crate::templates::product::tables::{
+        FixedSurfaceType, TimeUnit, TypeOfEnsembleForecast, TypeOfSpatialProcessing, TypeOfStatisticalProcessing,
+    };
+    use crate::templates::product::{
+        AerosolAverageAccumulationExtremeHorizontalForecastTemplate, AerosolEnsembleHorizontalForecastTimeIntervalTemplate,
+        EnsembleReforecastHorizontalAnalysisForecastTemplate, EnsembleReforecastHorizontalForecastTimeIntervalTemplate,
+        HorizontalAnalysisForecastTemplate, SpatiallyProcessedHorizontalAnalysisForecastTemplate,
+    };
+    use chrono::TimeZone;
+    use crate::templates::template::Template;
+
+    #[test]
+    fn read_vertical_coordinate_values() {
+        let mut raw = vec![0u8; 34];
+        raw[4] = 4;
+        raw[6] = 2;
+        raw.extend_from_slice(&1.5f32.to_be_bytes());
+        raw.extend_from_slice(&0.25f32.to_be_bytes());
+        let len = raw.len() as u32;
+        raw[0..4].copy_from_slice(&len.to_be_bytes());
+
+        let section = ProductDefinitionSection::from_data(&raw).unwrap();
+        assert_eq!(section.coord_values_after_template(), 2);
+        assert_eq!(section.vertical_coordinate_values(), vec![1.5, 0.25]);
+    }
+
+    #[test]
+    fn short_template_is_an_error() {
+        assert!(matches!(
+            HorizontalAnalysisForecastTemplate::new(&[0u8; 20][..], 0),
+            Err(GribberishError::TruncatedSection { section: 4, offset: 0, length: 20, expected: 34 })
+        ));
+        assert!(ProductDefinitionSection::from_data(&[0, 0, 0, 8, 4, 0, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn truncated_time_interval_template() {
+        let mut raw = vec![0u8; 42];
+        raw[3] = 42;
+        raw[4] = 4;
+        raw[8] = 8;
+
+        let section = ProductDefinitionSection::from_data(&raw).unwrap();
+        assert!(matches!(
+            section.product_definition_template(0),
+            Err(GribberishError::TruncatedSection { section: 4, offset: 0, length: 42, expected: 58 })
+        ));
+
+        // Holding the first time range is enough to read every accessor
+        raw.resize(58, 0);
+        raw[3] = 58;
+        raw[41] = 1;
+        let section = ProductDefinitionSection::from_data(&raw).unwrap();
+        let template = section.product_definition_template(0).unwrap();
+        assert!(template.statistical_processing().is_some());
+        assert!(template.time_increment_unit().is_some());
+        assert_eq!(template.time_ranges().len(), 1);
+        assert!(template.forecast_end_datetime(chrono::Utc::now()).is_some());
+    }
+
+    /// A zeroed product definition section of `length` octets holding `template`
+    fn product_section(template: u16, length: usize) -> Vec<u8> {
+        let mut raw = vec![0u8; length];
+        raw[0..4].copy_from_slice(&(length as u32).to_be_bytes());
+        raw[4] = 4;
+        raw[7..9].copy_from_slice(&template.to_be_bytes());
+        raw
+    }
+
+    /// Writes `bytes` starting at the one based `octet`, as numbered in the WMO template tables
+    fn put(raw: &mut [u8], octet: usize, bytes: &[u8]) {
+        raw[octet - 1..octet - 1 + bytes.len()].copy_from_slice(bytes);
+    }
+
+    /// Writes a single time range specification starting at `octet`
+    fn put_time_range(raw: &mut [u8], octet: usize, process: u8, hours: u32) {
+        put(raw, octet, &[process, 2, 1]);
+        put(raw, octet + 3, &hours.to_be_bytes());
+        put(raw, octet + 7, &[1]);
+    }
+
+    fn end_date() -> chrono::DateTime<chrono::Utc> {
+        chrono::Utc.with_ymd_and_hms(2024, 1, 2, 3, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn spatially_processed_template() {
+        let mut raw = product_section(15, 37);
+        put(&mut raw, 11, &[4]);
+        put(&mut raw, 18, &[1]);
+        put(&mut raw, 19, &6u32.to_be_bytes());
+        put(&mut raw, 23, &[103, 0]);
+        put(&mut raw, 25, &2u32.to_be_bytes());
+        put(&mut raw, 29, &[255]);
+        put(&mut raw, 35, &[2, 3, 9]);
+
+        let section = ProductDefinitionSection::from_data(&raw).unwrap();
+        let template = section.product_definition_template(0).unwrap();
+        assert_eq!(template.parameter().unwrap().abbrev, "TMAX");
+        assert_eq!(template.time_interval(), 6);
+        assert_eq!(template.first_fixed_surface_type(), FixedSurfaceType::SpecifiedHeightLevelAboveGround);
+        assert_eq!(template.first_fixed_surface_value(), Some(2.0));
+        assert!(template.forecast_end_datetime(end_date()).is_none());
+
+        // The maximum is taken over the spatial area, so there is no time statistic
+        assert!(template.statistical_process_type().is_none());
+        assert!(template.statistical_processing().is_none());
+
+        let template = SpatiallyProcessedHorizontalAnalysisForecastTemplate::new(raw.as_slice(), 0).unwrap();
+        assert_eq!(template.spatial_statistical_process(), TypeOfStatisticalProcessing::Maximum);
+        assert_eq!(template.spatial_processing_type(), TypeOfSpatialProcessing::NearestNeighbor);
+        assert_eq!(template.number_of_points_used_in_spatial_processing(), 9);
+    }
+
+    #[test]
+    fn aerosol_time_interval_template() {
+        let mut raw = product_section(46, 71);
+        put(&mut raw, 10, &[20, 2]);
+        put(&mut raw, 12, &62001u16.to_be_bytes());
+        put(&mut raw, 31, &[1]);
+        put(&mut raw, 32, &3u32.to_be_bytes());
+        put(&mut raw, 36, &[100, 0]);
+        put(&mut raw, 38, &50000u32.to_be_bytes());
+        put(&mut raw, 42, &[255]);
+        put(&mut raw, 48, &[0x07, 0xe8, 1, 2, 3, 0, 0]);
+        put(&mut raw, 55, &[1]);
+        put_time_range(&mut raw, 60, 1, 3);
+
+        let template = AerosolAverageAccumulationExtremeHorizontalForecastTemplate::new(raw.as_slice(), 0).unwrap();
+        assert_eq!(template.aerosol_type(), 62001);
+        assert_eq!(template.forecast_time(), 3);
+
+        let section = ProductDefinitionSection::from_data(&raw).unwrap();
+        let template = section.product_definition_template(0).unwrap();
+        assert_eq!((template.category_value(), template.parameter_value()), (20, 2));
+        assert_eq!(template.time_unit(), TimeUnit::Hour);
+        assert_eq!(template.time_interval(), 3);
+        assert_eq!(template.first_fixed_surface_type(), FixedSurfaceType::IsobaricSurface);
+        assert_eq!(template.first_fixed_surface_value(), Some(50000.0));
+        assert_eq!(template.second_fixed_surface_type(), FixedSurfaceType::Missing);
+        assert_eq!(template.forecast_end_datetime(end_date()), Some(end_date()));
+        assert_eq!(template.time_increment_unit(), Some(TimeUnit::Hour));
+
+        let time_ranges = template.time_ranges();
+        assert_eq!(time_ranges.len(), 1);
+        assert_eq!(time_ranges[0].statistical_process, TypeOfStatisticalProcessing::Accumulation);
+        assert_eq!(time_ranges[0].time_range_length, 3);
+    }
+
+    #[test]
+    fn aerosol_ensemble_time_interval_template() {
+        let mut raw = product_section(47, 86);
+        put(&mut raw, 10, &[20, 2]);
+        put(&mut raw, 12, &62001u16.to_be_bytes());
+        put(&mut raw, 31, &[1]);
+        put(&mut raw, 32, &3u32.to_be_bytes());
+        put(&mut raw, 36, &[1]);
+        put(&mut raw, 42, &[255]);
+        put(&mut raw, 48, &[3, 4, 20]);
+        put(&mut raw, 51, &[0x07, 0xe8, 1, 2, 3, 0, 0]);
+        put(&mut raw, 58, &[2]);
+        put_time_range(&mut raw, 63, 0, 3);
+        put_time_range(&mut raw, 75, 2, 1);
+
+        let template = AerosolEnsembleHorizontalForecastTimeIntervalTemplate::new(raw.as_slice(), 0).unwrap();
+        assert_eq!(template.aerosol_type(), 62001);
+        assert_eq!(template.ensemble_forecast_type(), TypeOfEnsembleForecast::PositivelyPerturbed);
+        assert_eq!(template.perturbation_number(), 4);
+        assert_eq!(template.number_of_forecasts_in_ensemble(), 20);
+
+        let section = ProductDefinitionSection::from_data(&raw).unwrap();
+        let template = section.product_definition_template(0).unwrap();
+        assert_eq!((template.category_value(), template.parameter_value()), (20, 2));
+        assert_eq!(template.time_interval(), 3);
+        assert_eq!(template.first_fixed_surface_type(), FixedSurfaceType::GroundOrWater);
+        assert_eq!(template.second_fixed_surface_type(), FixedSurfaceType::Missing);
+        assert_eq!(template.forecast_end_datetime(end_date()), Some(end_date()));
+
+        let time_ranges = template.time_ranges();
+        assert_eq!(time_ranges.len(), 2);
+        assert_eq!(time_ranges[0].statistical_process, TypeOfStatisticalProcessing::Average);
+        assert_eq!(time_ranges[1].statistical_process, TypeOfStatisticalProcessing::Maximum);
+        assert_eq!(time_ranges[1].time_range_length, 1);
+    }
+
+    #[test]
+    fn ensemble_reforecast_template() {
+        let mut raw = product_section(60, 44);
+        put(&mut raw, 10, &[0, 0]);
+        put(&mut raw, 18, &[1]);
+        put(&mut raw, 19, &6u32.to_be_bytes());
+        put(&mut raw, 23, &[103, 0]);
+        put(&mut raw, 25, &2u32.to_be_bytes());
+        put(&mut raw, 29, &[255]);
+        put(&mut raw, 35, &[3, 4, 11]);
+        put(&mut raw, 38, &[0x07, 0xe8, 1, 2, 3, 0, 0]);
+
+        let template = EnsembleReforecastHorizontalAnalysisForecastTemplate::new(raw.as_slice(), 0).unwrap();
+        assert_eq!(template.ensemble_forecast_type(), TypeOfEnsembleForecast::PositivelyPerturbed);
+        assert_eq!(template.perturbation_number(), 4);
+        assert_eq!(template.number_of_forecasts_in_ensemble(), 11);
+        assert_eq!(template.model_version_date(), end_date());
+
+        let section = ProductDefinitionSection::from_data(&raw).unwrap();
+        let template = section.product_definition_template(0).unwrap();
+        assert_eq!(template.parameter().unwrap().abbrev, "TMP");
+        assert_eq!(template.time_interval(), 6);
+        assert_eq!(template.first_fixed_surface_type(), FixedSurfaceType::SpecifiedHeightLevelAboveGround);
+        assert_eq!(template.first_fixed_surface_value(), Some(2.0));
+        assert_eq!(template.second_fixed_surface_type(), FixedSurfaceType::Missing);
+        assert!(template.forecast_end_datetime(end_date()).is_none());
+        assert!(template.time_ranges().is_empty());
+    }
+
+    #[test]
+    fn ensemble_reforecast_time_interval_template() {
+        let mut raw = product_section(61, 68);
+        put(&mut raw, 10, &[1, 8]);
+        put(&mut raw, 18, &[1]);
+        put(&mut raw, 19, &3u32.to_be_bytes());
+        put(&mut raw, 23, &[1]);
+        put(&mut raw, 29, &[255]);
+        put(&mut raw, 35, &[3, 4, 11]);
+        put(&mut raw, 38, &[0x07, 0xe0, 6, 1, 0, 0, 0]);
+        put(&mut raw, 45, &[0x07, 0xe8, 1, 2, 3, 0, 0]);
+        put(&mut raw, 52, &[1]);
+        put_time_range(&mut raw, 57, 1, 3);
+
+        let template = EnsembleReforecastHorizontalForecastTimeIntervalTemplate::new(raw.as_slice(), 0).unwrap();
+        assert_eq!(template.ensemble_forecast_type(), TypeOfEnsembleForecast::PositivelyPerturbed);
+        assert_eq!(template.perturbation_number(), 4);
+        assert_eq!(template.number_of_forecasts_in_ensemble(), 11);
+        assert_eq!(template.model_version_date(), chrono::Utc.with_ymd_and_hms(2016, 6, 1, 0, 0, 0).unwrap());
+
+        let section = ProductDefinitionSection::from_data(&raw).unwrap();
+        let template = section.product_definition_template(0).unwrap();
+        assert_eq!(template.parameter().unwrap().abbrev, "APCP");
+        assert_eq!(template.time_interval(), 3);
+        assert_eq!(template.first_fixed_surface_type(), FixedSurfaceType::GroundOrWater);
+        assert_eq!(template.second_fixed_surface_type(), FixedSurfaceType::Missing);
+        assert_eq!(template.forecast_end_datetime(end_date()), Some(end_date()));
+        assert_eq!(template.statistical_process_type(), Some(TypeOfStatisticalProcessing::Accumulation));
+
+        let time_ranges = template.time_ranges();
+        assert_eq!(time_ranges.len(), 1);
+        assert_eq!(time_ranges[0].time_range_unit, TimeUnit::Hour);
+        assert_eq!(time_ranges[0].time_range_length, 3);
+    }
+
+    #[test]
+    fn generic_template() {
+        let mut raw = product_section(1, 37);
+        put(&mut raw, 11, &[4]);
+        put(&mut raw, 18, &[1]);
+        put(&mut raw, 19, &6u32.to_be_bytes());
+        put(&mut raw, 23, &[103, 0]);
+        put(&mut raw, 25, &2u32.to_be_bytes());
+        put(&mut raw, 29, &[255]);
+
+        // Template 4.1 is not supported, but shares the leading octets of template 4.0
+        let section = ProductDefinitionSection::from_data(&raw).unwrap();
+        let template = section.product_definition_template(0).unwrap();
+        assert!(template.is_partial());
+        assert_eq!(template.parameter().unwrap().abbrev, "TMAX");
+        assert_eq!(template.time_interval(), 6);
+        assert_eq!(template.first_fixed_surface_type(), FixedSurfaceType::SpecifiedHeightLevelAboveGround);
+        assert_eq!(template.first_fixed_surface_value(), Some(2.0));
+        assert!(template.statistical_process_type().is_none());
+
+        let raw = product_section(0, 34);
+        let section = ProductDefinitionSection::from_data(&raw).unwrap();
+        assert!(!section.product_definition_template(0).unwrap().is_partial());
+
+        // Aerosol templates insert octets ahead of the generating process
+        let raw = product_section(48, 58);
+        let section = ProductDefinitionSection::from_data(&raw).unwrap();
+        assert!(matches!(
+            section.product_definition_template(0),
+            Err(GribberishError::UnsupportedTemplate { section: 4, template: 48 })
+        ));
+    }
+
+    #[test]
+    fn template_borrows_section_data() {
+        let raw = vec![0u8; 34];
+        let template = HorizontalAnalysisForecastTemplate::new(raw.as_slice(), 0).unwrap();
+        assert!(std::ptr::eq(template.data(), raw.as_slice()));
+
+        let owned = template.into_owned();
+        assert!(!std::ptr::eq(owned.data(), raw.as_slice()));
+        assert_eq!(owned.data(), raw.as_slice());
+    }
+}
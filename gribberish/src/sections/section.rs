@@ -2,7 +2,7 @@ use super::bitmap::BitmapSection;
 use super::data::DataSection;
 use super::data_representation::DataRepresentationSection;
 use super::end::EndSection;
-use super::grib_section::GribSection;
+use super::grib_section::{minimum_section_length, GribSection};
 use super::grid_definition::GridDefinitionSection;
 use super::identification::IdentificationSection;
 use super::indicator::IndicatorSection;
@@ -24,25 +24,20 @@ pub enum Section<'a> {
 
 impl <'a> Section<'a> {
     pub fn from_data(data: &[u8], offset: usize) -> Option<Section> {
-        let section_len = section_length(data, offset)?;
-        let section_num = section_number(data, offset)?;
+        let (section_len, section_num) = section_bounds(data, offset)?;
 
         let section_data = &data[offset..offset + section_len];
 
         match section_num {
-            0 => Some(Section::Indicator(IndicatorSection::from_data(section_data))),
-            1 => Some(Section::Identification(IdentificationSection::from_data(section_data))),
-            2 => Some(Section::LocalUse(LocalUseSection::from_data(section_data))),
-            3 => Some(Section::GridDefinition(GridDefinitionSection::from_data(section_data ))),
-            4 => Some(Section::ProductDefinition(
-                ProductDefinitionSection::from_data(section_data),
-            )),
-            5 => Some(Section::DataRepresentation(
-                DataRepresentationSection::from_data(section_data),
-            )),
-            6 => Some(Section::Bitmap(BitmapSection::from_data(section_data))),
-            7 => Some(Section::Data(DataSection::from_data(section_data))),
-            8 => Some(Section::End(EndSection::from_data(section_data))),
+            0 => IndicatorSection::from_data(section_data).map(Section::Indicator).ok(),
+            1 => IdentificationSection::from_data(section_data).map(Section::Identification).ok(),
+            2 => LocalUseSection::from_data(section_data).map(Section::LocalUse).ok(),
+            3 => GridDefinitionSection::from_data(section_data).map(Section::GridDefinition).ok(),
+            4 => ProductDefinitionSection::from_data(section_data).map(Section::ProductDefinition).ok(),
+            5 => DataRepresentationSection::from_data(section_data).map(Section::DataRepresentation).ok(),
+            6 => BitmapSection::from_data(section_data).map(Section::Bitmap).ok(),
+            7 => DataSection::from_data(section_data).map(Section::Data).ok(),
+            8 => EndSection::from_data(section_data).map(Section::End).ok(),
             _ => None,
         }
     }
@@ -79,7 +74,7 @@ impl <'a> Section<'a> {
 // TODO: IMPL TRY FROMS FOR INNER TYPES HERE
 
 fn section_length(data: &[u8], offset: usize) -> Option<usize> {
-    if data.len() <= offset + 4 { 
+    if data.len() <= offset.checked_add(4)? {
         None
     } else if IndicatorSection::is_indicator_section(data, offset) {
         Some(16)
@@ -91,7 +86,7 @@ fn section_length(data: &[u8], offset: usize) -> Option<usize> {
 }
 
 fn section_number(data: &[u8], offset: usize) -> Option<u8> {
-    if data.len() <= offset + 4 {
+    if data.len() <= offset.checked_add(4)? {
         None
     } else if IndicatorSection::is_indicator_section(data, offset) {
         Some(0)
@@ -102,6 +97,19 @@ fn section_number(data: &[u8], offset: usize) -> Option<u8> {
     }
 }
 
+/// Length and number of the section at `offset`, when it is at least the minimum length for its
+/// number and fits within the data
+fn section_bounds(data: &[u8], offset: usize) -> Option<(usize, u8)> {
+    let section_len = section_length(data, offset)?;
+    let section_num = section_number(data, offset)?;
+
+    if section_len < minimum_section_length(section_num) || offset.checked_add(section_len)? > data.len() {
+        return None;
+    }
+
+    Some((section_len, section_num))
+}
+
 pub struct SectionIterator<'a> {
     pub data: &'a [u8],
     pub offset: usize,
@@ -111,37 +119,96 @@ impl <'a> Iterator for SectionIterator<'a> {
     type Item = Section<'a>;
 
     fn next(&mut self) -> std::option::Option<<Self as std::iter::Iterator>::Item> {
-        let section_len = section_length(self.data, self.offset)?;
-        let section_num = section_number(self.data, self.offset)?;
+        let (section_len, _) = section_bounds(self.data, self.offset)?;
 
         if (self.offset + section_len) >= self.data.len() {
             return None;
         }
 
-        let section_data = &self.data[self.offset..self.offset + section_len];
+        let section = Section::from_data(self.data, self.offset);
         self.offset += section_len;
+        section
+    }
+}
 
+/// Offsets of the sections in effect for a field, indexed by section number
+pub type SectionTable = [Option<usize>; 8];
+
+/// Section tables of each field of the message starting at `offset`. GRIB2 allows sections 2 to 7
+/// to repeat before the end section, each data section closing a field that inherits the most
+/// recently seen local use, grid definition and bitmap sections. A bitmap indicator of 254 reuses
+/// the previously defined bitmap.
+pub fn field_section_tables(data: &[u8], offset: usize) -> Vec<SectionTable> {
+    let mut fields = Vec::new();
+    let mut current: SectionTable = [None; 8];
+    let mut position = offset;
+
+    while let Some((section_len, section_num)) = section_bounds(data, position) {
         match section_num {
-            0 => Some(Section::Indicator(IndicatorSection::from_data(
-                section_data,
-            ))),
-            1 => Some(Section::Identification(IdentificationSection::from_data(
-                section_data,
-            ))),
-            2 => Some(Section::LocalUse(LocalUseSection::from_data(section_data))),
-            3 => Some(Section::GridDefinition(GridDefinitionSection::from_data(
-                section_data,
-            ))),
-            4 => Some(Section::ProductDefinition(
-                ProductDefinitionSection::from_data(section_data),
-            )),
-            5 => Some(Section::DataRepresentation(
-                DataRepresentationSection::from_data(section_data),
-            )),
-            6 => Some(Section::Bitmap(BitmapSection::from_data(section_data))),
-            7 => Some(Section::Data(DataSection::from_data(section_data))),
-            8 => Some(Section::End(EndSection::from_data(section_data))),
-            _ => None,
+            0 if position != offset => break,
+            6 if data.get(position + 5) == Some(&254) && current[6].is_some() => {}
+            0..=6 => current[section_num as usize] = Some(position),
+            7 => {
+                current[7] = Some(position);
+                fields.push(current);
+                current[7] = None;
+            }
+            _ => break,
         }
+
+        position += section_len;
+    }
+
+    fields
+}
+
+/// Section table of a message without any data section, holding the first of each section found
+pub fn message_section_table(data: &[u8], offset: usize) -> SectionTable {
+    let mut table: SectionTable = [None; 8];
+    let mut sections = SectionIterator { data, offset };
+
+    loop {
+        let position = sections.offset;
+        match sections.next() {
+            Some(section) => {
+                if let Some(entry) = table.get_mut(section.number() as usize) {
+                    entry.get_or_insert(position);
+                }
+            }
+            None => break,
+        }
+    }
+
+    table
+}
+
+/// Offsets of the sections in effect for each field of the message starting at `offset`, see
+/// [`field_section_tables`]
+pub fn field_section_offsets(data: &[u8], offset: usize) -> Vec<Vec<usize>> {
+    field_section_tables(data, offset)
+        .iter()
+        .map(|table| table.iter().flatten().copied().collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{section_length, section_number, Section};
+    use crate::error::GribberishError;
+    use crate::sections::bitmap::BitmapSection;
+    use crate::sections::indicator::IndicatorSection;
+
+    #[test]
+    fn short_sections_do_not_panic() {
+        assert!(matches!(
+            BitmapSection::from_data(&[0, 0, 0, 5, 6]),
+            Err(GribberishError::TruncatedSection { section: 6, offset: 0, length: 5, expected: 6 })
+        ));
+        assert!(IndicatorSection::from_data(b"GRIB").is_err());
+
+        // A section whose length octets claim more than its number byte holds
+        assert!(Section::from_data(&[0, 0, 0, 5, 6], 0).is_none());
+        assert_eq!(section_length(&[0, 0, 0, 5, 6], usize::MAX), None);
+        assert_eq!(section_number(&[0, 0, 0, 5, 6], usize::MAX), None);
     }
 }
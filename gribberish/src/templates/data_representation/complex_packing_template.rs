@@ -1,3 +1,4 @@
+use crate::sections::grib_section::check_section_length;
 use bitvec::prelude::*;
 
 use crate::{error::GribberishError, utils::iter::ScaleGribValueIterator};
@@ -9,17 +10,19 @@ use crate::{
 };
 
 use super::{
+    data_representation_template::{packed_values_bits, validate_groups},
     tables::{GroupSplittingMethod, MissingValueManagement, OriginalFieldValue},
     DataRepresentationTemplate,
 };
+use std::borrow::Cow;
 
-pub struct ComplexPackingDataRepresentationTemplate {
-    data: Vec<u8>,
+pub struct ComplexPackingDataRepresentationTemplate<'a> {
+    data: Cow<'a, [u8]>,
 }
 
-impl Template for ComplexPackingDataRepresentationTemplate {
+impl<'a> Template for ComplexPackingDataRepresentationTemplate<'a> {
     fn data(&self) -> &[u8] {
-        self.data.as_slice()
+        &self.data
     }
 
     fn template_number(&self) -> u16 {
@@ -35,18 +38,30 @@ impl Template for ComplexPackingDataRepresentationTemplate {
     }
 }
 
-impl ComplexPackingDataRepresentationTemplate {
-    pub fn new(data: Vec<u8>) -> ComplexPackingDataRepresentationTemplate {
-        ComplexPackingDataRepresentationTemplate { data }
+impl<'a> ComplexPackingDataRepresentationTemplate<'a> {
+    /// The template read from section data holding at least its 47 octets
+    pub fn new(data: impl Into<Cow<'a, [u8]>>) -> Result<Self, GribberishError> {
+        let data = data.into();
+        check_section_length(5, &data, 47)?;
+        Ok(ComplexPackingDataRepresentationTemplate { data })
+    }
+
+    pub fn into_owned(self) -> ComplexPackingDataRepresentationTemplate<'static> {
+        ComplexPackingDataRepresentationTemplate { data: Cow::Owned(self.data.into_owned()) }
+    }
+
+    /// Number of data points where one or more values are specified in the data section
+    pub fn data_point_count(&self) -> usize {
+        read_u32_from_bytes(&self.data, 5).unwrap_or(0) as usize
     }
 
     pub fn reference_value(&self) -> f32 {
-        read_f32_from_bytes(self.data.as_slice(), 11).unwrap_or(0.0)
+        read_f32_from_bytes(&self.data, 11).unwrap_or(0.0)
     }
 
     pub fn binary_scale_factor(&self) -> i16 {
         as_signed!(
-            read_u16_from_bytes(self.data.as_slice(), 15).unwrap_or(0),
+            read_u16_from_bytes(&self.data, 15).unwrap_or(0),
             16,
             i16
         )
@@ -54,7 +69,7 @@ impl ComplexPackingDataRepresentationTemplate {
 
     pub fn decimal_scale_factor(&self) -> i16 {
         as_signed!(
-            read_u16_from_bytes(self.data.as_slice(), 17).unwrap_or(0),
+            read_u16_from_bytes(&self.data, 17).unwrap_or(0),
             16,
             i16
         )
@@ -77,15 +92,15 @@ impl ComplexPackingDataRepresentationTemplate {
     }
 
     pub fn primary_missing_value_substitute(&self) -> f32 {
-        read_f32_from_bytes(self.data.as_slice(), 23).unwrap_or(0.0)
+        read_f32_from_bytes(&self.data, 23).unwrap_or(0.0)
     }
 
     pub fn secondary_missing_value_substitute(&self) -> f32 {
-        read_f32_from_bytes(self.data.as_slice(), 27).unwrap_or(0.0)
+        read_f32_from_bytes(&self.data, 27).unwrap_or(0.0)
     }
 
     pub fn number_of_groups(&self) -> u32 {
-        read_u32_from_bytes(self.data.as_slice(), 31).unwrap()
+        read_u32_from_bytes(&self.data, 31).unwrap_or(0)
     }
 
     pub fn group_width_reference(&self) -> u8 {
@@ -97,7 +112,7 @@ impl ComplexPackingDataRepresentationTemplate {
     }
 
     pub fn group_length_reference(&self) -> u32 {
-        read_u32_from_bytes(self.data.as_slice(), 37).unwrap()
+        read_u32_from_bytes(&self.data, 37).unwrap_or(0)
     }
 
     pub fn group_length_increment(&self) -> u8 {
@@ -105,7 +120,7 @@ impl ComplexPackingDataRepresentationTemplate {
     }
 
     pub fn group_last_length(&self) -> u32 {
-        read_u32_from_bytes(self.data.as_slice(), 42).unwrap()
+        read_u32_from_bytes(&self.data, 42).unwrap_or(0)
     }
 
     pub fn group_length_bits(&self) -> u8 {
@@ -113,7 +128,7 @@ impl ComplexPackingDataRepresentationTemplate {
     }
 }
 
-impl DataRepresentationTemplate<f64> for ComplexPackingDataRepresentationTemplate {
+impl<'a> DataRepresentationTemplate<f64> for ComplexPackingDataRepresentationTemplate<'a> {
     fn compression_type(&self) -> String {
         "Complex Grid Packing".into()
     }
@@ -124,45 +139,63 @@ impl DataRepresentationTemplate<f64> for ComplexPackingDataRepresentationTemplat
 
     fn unpack(&self, bits: &BitSlice<u8, Msb0>) -> Result<Vec<f64>, GribberishError> {
         let ng = self.number_of_groups() as usize;
-        let nbits = self.bit_count() as usize;
+        if ng > self.data_point_count() {
+            return Err(GribberishError::DataRepresentationTemplateError(format!(
+                "{ng} groups exceed the {} data points",
+                self.data_point_count()
+            )));
+        }
 
+        let nbits = self.bit_count() as usize;
+        let reference_bits = packed_values_bits(bits, 0, ng, nbits)?;
         let group_references = (0..ng).map(|ig| {
             if nbits == 0 {
                 0
             } else {
                 let start = ig * nbits;
-                bits[start..start + nbits].load::<u32>()
+                reference_bits[start..start + nbits].load::<u32>()
             }
         });
 
         let group_widths_start = ((ng * nbits) as f32 / 8.0).ceil() as usize * 8;
         let n_width_bits = self.group_width_bits() as usize;
-        let group_widths = (0..ng).map(|ig| {
-            if n_width_bits == 0 {
-                0
-            } else {
-                let start = group_widths_start + ig * n_width_bits;
-                bits[start..start + n_width_bits].load::<u32>()
-                    + self.group_width_reference() as u32
-            }
-        });
+        let width_bits = packed_values_bits(bits, group_widths_start, ng, n_width_bits)?;
+        let group_widths = (0..ng)
+            .map(|ig| {
+                if n_width_bits == 0 {
+                    0
+                } else {
+                    let start = ig * n_width_bits;
+                    width_bits[start..start + n_width_bits]
+                        .load::<u32>()
+                        .saturating_add(self.group_width_reference() as u32)
+                }
+            })
+            .collect::<Vec<_>>();
 
         let group_lengths_start =
             group_widths_start + (((n_width_bits * ng) as f32 / 8.0).ceil() as usize * 8);
         let n_length_bits = self.group_length_bits() as usize;
-        let group_lengths = (0..ng).map(|ig| {
-            if n_length_bits == 0 {
-                0
-            } else {
-                let start = group_lengths_start + ig * n_length_bits;
-                bits[start..start + n_length_bits].load::<u32>()
-                    * self.group_length_increment() as u32
-                    + self.group_length_reference()
-            }
-        });
-
-        let mut pos =
+        let length_bits = packed_values_bits(bits, group_lengths_start, ng, n_length_bits)?;
+        let group_lengths = (0..ng)
+            .map(|ig| {
+                if n_length_bits == 0 {
+                    0
+                } else {
+                    let start = ig * n_length_bits;
+                    length_bits[start..start + n_length_bits]
+                        .load::<u32>()
+                        .saturating_mul(self.group_length_increment() as u32)
+                        .saturating_add(self.group_length_reference())
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let start =
             group_lengths_start + (((n_length_bits * ng) as f32 / 8.0).ceil() as usize * 8);
+        validate_groups(bits, start, &group_widths, &group_lengths, self.data_point_count())?;
+
+        let mut pos = start;
         let values = izip!(group_references, group_widths, group_lengths)
         .flat_map(|(reference, width, length)| {
             let n_bits = (width * length) as usize;
@@ -175,7 +208,7 @@ impl DataRepresentationTemplate<f64> for ComplexPackingDataRepresentationTemplat
                         .load_be::<u32>()
                 };
                 let raw = as_signed!(value, 32, i32);
-                raw + reference as i32
+                raw.wrapping_add(reference as i32)
             });
 
             pos += n_bits;
@@ -1,3 +1,4 @@
+use crate::sections::grib_section::check_section_length;
 use bitvec::prelude::*;
 
 use std::iter;
@@ -12,19 +13,21 @@ use crate::{
 };
 
 use super::{
+    data_representation_template::{packed_values_bits, validate_groups},
     tables::{
         GroupSplittingMethod, MissingValueManagement, OriginalFieldValue, SpatialDifferencingOrder,
     },
     DataRepresentationTemplate,
 };
+use std::borrow::Cow;
 
-pub struct ComplexSpatialPackingDataRepresentationTemplate {
-    data: Vec<u8>,
+pub struct ComplexSpatialPackingDataRepresentationTemplate<'a> {
+    data: Cow<'a, [u8]>,
 }
 
-impl Template for ComplexSpatialPackingDataRepresentationTemplate {
+impl<'a> Template for ComplexSpatialPackingDataRepresentationTemplate<'a> {
     fn data(&self) -> &[u8] {
-        self.data.as_slice()
+        &self.data
     }
 
     fn template_number(&self) -> u16 {
@@ -40,18 +43,30 @@ impl Template for ComplexSpatialPackingDataRepresentationTemplate {
     }
 }
 
-impl ComplexSpatialPackingDataRepresentationTemplate {
-    pub fn new(data: Vec<u8>) -> ComplexSpatialPackingDataRepresentationTemplate {
-        ComplexSpatialPackingDataRepresentationTemplate { data }
+impl<'a> ComplexSpatialPackingDataRepresentationTemplate<'a> {
+    /// The template read from section data holding at least its 49 octets
+    pub fn new(data: impl Into<Cow<'a, [u8]>>) -> Result<Self, GribberishError> {
+        let data = data.into();
+        check_section_length(5, &data, 49)?;
+        Ok(ComplexSpatialPackingDataRepresentationTemplate { data })
+    }
+
+    pub fn into_owned(self) -> ComplexSpatialPackingDataRepresentationTemplate<'static> {
+        ComplexSpatialPackingDataRepresentationTemplate { data: Cow::Owned(self.data.into_owned()) }
+    }
+
+    /// Number of data points where one or more values are specified in the data section
+    pub fn data_point_count(&self) -> usize {
+        read_u32_from_bytes(&self.data, 5).unwrap_or(0) as usize
     }
 
     pub fn reference_value(&self) -> f32 {
-        read_f32_from_bytes(self.data.as_slice(), 11).unwrap_or(0.0)
+        read_f32_from_bytes(&self.data, 11).unwrap_or(0.0)
     }
 
     pub fn binary_scale_factor(&self) -> i16 {
         as_signed!(
-            read_u16_from_bytes(self.data.as_slice(), 15).unwrap_or(0),
+            read_u16_from_bytes(&self.data, 15).unwrap_or(0),
             16,
             i16
         )
@@ -59,7 +74,7 @@ impl ComplexSpatialPackingDataRepresentationTemplate {
 
     pub fn decimal_scale_factor(&self) -> i16 {
         as_signed!(
-            read_u16_from_bytes(self.data.as_slice(), 17).unwrap_or(0),
+            read_u16_from_bytes(&self.data, 17).unwrap_or(0),
             16,
             i16
         )
@@ -82,15 +97,15 @@ impl ComplexSpatialPackingDataRepresentationTemplate {
     }
 
     pub fn primary_missing_value_substitute(&self) -> f32 {
-        read_f32_from_bytes(self.data.as_slice(), 23).unwrap_or(0.0)
+        read_f32_from_bytes(&self.data, 23).unwrap_or(0.0)
     }
 
     pub fn secondary_missing_value_substitute(&self) -> f32 {
-        read_f32_from_bytes(self.data.as_slice(), 27).unwrap_or(0.0)
+        read_f32_from_bytes(&self.data, 27).unwrap_or(0.0)
     }
 
     pub fn number_of_groups(&self) -> u32 {
-        read_u32_from_bytes(self.data.as_slice(), 31).unwrap()
+        read_u32_from_bytes(&self.data, 31).unwrap_or(0)
     }
 
     pub fn group_width_reference(&self) -> u8 {
@@ -102,7 +117,7 @@ impl ComplexSpatialPackingDataRepresentationTemplate {
     }
 
     pub fn group_length_reference(&self) -> u32 {
-        read_u32_from_bytes(self.data.as_slice(), 37).unwrap()
+        read_u32_from_bytes(&self.data, 37).unwrap_or(0)
     }
 
     pub fn group_length_increment(&self) -> u8 {
@@ -110,7 +125,7 @@ impl ComplexSpatialPackingDataRepresentationTemplate {
     }
 
     pub fn group_last_length(&self) -> u32 {
-        read_u32_from_bytes(self.data.as_slice(), 42).unwrap()
+        read_u32_from_bytes(&self.data, 42).unwrap_or(0)
     }
 
     pub fn group_length_bits(&self) -> u8 {
@@ -126,7 +141,7 @@ impl ComplexSpatialPackingDataRepresentationTemplate {
     }
 }
 
-impl DataRepresentationTemplate<f64> for ComplexSpatialPackingDataRepresentationTemplate {
+impl<'a> DataRepresentationTemplate<f64> for ComplexSpatialPackingDataRepresentationTemplate<'a> {
     fn compression_type(&self) -> String {
         "Complex Grid Packing with Spatial Differencing".into()
     }
@@ -136,68 +151,94 @@ impl DataRepresentationTemplate<f64> for ComplexSpatialPackingDataRepresentation
     }
 
     fn unpack(&self, bits: &BitSlice<u8, Msb0>) -> Result<Vec<f64>, GribberishError> {
-        let bits_for_differencing = self.number_of_octets_for_differencing() as usize * 8;
-        let mut idx = 0;
-        let d1: u32 = (&bits[idx..idx + bits_for_differencing]).load_be();
-        let d1: i32 = as_signed!(d1, bits_for_differencing, i32);
-        idx += bits_for_differencing;
-
-        let d2: u32 = if self.spatial_differencing_order() == SpatialDifferencingOrder::Second {
-            let val = (&bits[idx..idx + bits_for_differencing]).load_be();
-            idx += bits_for_differencing;
-            val
+        let octets_for_differencing = self.number_of_octets_for_differencing() as usize;
+        if !(1..=4).contains(&octets_for_differencing) {
+            return Err(GribberishError::Unsupported(format!(
+                "Spatial differencing values of {octets_for_differencing} octets"
+            )));
+        }
+
+        let bits_for_differencing = octets_for_differencing * 8;
+        let differencing_count = match self.spatial_differencing_order() {
+            SpatialDifferencingOrder::Second => 3,
+            _ => 2,
+        };
+        let differencing_bits =
+            packed_values_bits(bits, 0, differencing_count, bits_for_differencing)?;
+        let mut differencing_values = differencing_bits
+            .chunks_exact(bits_for_differencing)
+            .map(|chunk| as_signed!(chunk.load_be::<u32>(), bits_for_differencing, i32));
+
+        let d1 = differencing_values.next().unwrap_or(0);
+        let d2 = if differencing_count == 3 {
+            differencing_values.next().unwrap_or(0)
         } else {
             0
         };
-        let d2: i32 = as_signed!(d2, bits_for_differencing, i32);
+        let dmin = differencing_values.next().unwrap_or(0);
 
-        let dmin: u32 = (&bits[idx..idx + bits_for_differencing]).load_be();
-        let dmin: i32 = as_signed!(dmin, bits_for_differencing, i32);
-        idx += bits_for_differencing;
-
-        let group_reference_start = idx;
+        let group_reference_start = differencing_bits.len();
         let ng = self.number_of_groups() as usize;
+        if ng > self.data_point_count() {
+            return Err(GribberishError::DataRepresentationTemplateError(format!(
+                "{ng} groups exceed the {} data points",
+                self.data_point_count()
+            )));
+        }
+
         let n_reference_bits = self.bit_count() as usize;
+        let reference_bits = packed_values_bits(bits, group_reference_start, ng, n_reference_bits)?;
         let group_references = (0..ng).map(|ig| {
             if n_reference_bits == 0 {
                 0
             } else {
-                let start = group_reference_start + ig * n_reference_bits;
-                bits[start..start + n_reference_bits].load_be::<u32>()
+                let start = ig * n_reference_bits;
+                reference_bits[start..start + n_reference_bits].load_be::<u32>()
             }
         });
 
         let group_widths_start =
             group_reference_start + (((ng * n_reference_bits) as f32 / 8.0).ceil() as usize * 8);
         let n_width_bits = self.group_width_bits() as usize;
-
-        let group_widths = (0..ng).map(|ig| {
-            if n_width_bits == 0 {
-                0
-            } else {
-                let start = group_widths_start + ig * n_width_bits;
-                let value = bits[start..start + n_width_bits].load_be::<u32>();
-                value + self.group_width_reference() as u32
-            }
-        });
+        let width_bits = packed_values_bits(bits, group_widths_start, ng, n_width_bits)?;
+        let group_widths = (0..ng)
+            .map(|ig| {
+                if n_width_bits == 0 {
+                    0
+                } else {
+                    let start = ig * n_width_bits;
+                    let value = width_bits[start..start + n_width_bits].load_be::<u32>();
+                    value.saturating_add(self.group_width_reference() as u32)
+                }
+            })
+            .collect::<Vec<_>>();
 
         let group_lengths_start =
             group_widths_start + (((ng * n_width_bits) as f32 / 8.0).ceil() as usize * 8);
         let n_length_bits = self.group_length_bits() as usize;
-        let group_lengths = (0..ng - 1)
+        let length_bits =
+            packed_values_bits(bits, group_lengths_start, ng.saturating_sub(1), n_length_bits)?;
+        let group_lengths = (0..ng.saturating_sub(1))
             .map(|ig| {
                 if n_length_bits == 0 {
                     0
                 } else {
-                    let start = group_lengths_start + ig * n_length_bits;
-                    let value = bits[start..start + n_length_bits].load_be::<u32>();
-                    value * self.group_length_increment() as u32 + self.group_length_reference()
+                    let start = ig * n_length_bits;
+                    let value = length_bits[start..start + n_length_bits].load_be::<u32>();
+                    value
+                        .saturating_mul(self.group_length_increment() as u32)
+                        .saturating_add(self.group_length_reference())
                 }
             })
-            .chain(iter::once(self.group_last_length()));
+            .chain(iter::once(self.group_last_length()))
+            .take(ng)
+            .collect::<Vec<_>>();
 
-        let mut pos =
+        let start =
             group_lengths_start + (((ng * n_length_bits) as f32 / 8.0).ceil() as usize * 8);
+        validate_groups(bits, start, &group_widths, &group_lengths, self.data_point_count())?;
+
+        let mut pos = start;
         let raw_values = izip!(group_references, group_widths, group_lengths).flat_map(
             |(reference, width, length)| {
                 let n_bits = (width * length) as usize;
@@ -210,7 +251,7 @@ impl DataRepresentationTemplate<f64> for ComplexSpatialPackingDataRepresentation
                             .load_be::<u32>()
                     };
                     let raw = as_signed!(value, 32, i32);
-                    raw + reference as i32
+                    raw.wrapping_add(reference as i32)
                 });
 
                 pos += n_bits;
@@ -2,8 +2,73 @@ use bitvec::prelude::*;
 
 use crate::error::GribberishError;
 
-pub trait DataRepresentationTemplate<T> {
+pub trait DataRepresentationTemplate<T>: Send + Sync {
     fn compression_type(&self) -> String;
     fn bit_count_per_datapoint(&self) -> usize;
     fn unpack(&self, bits: &BitSlice<u8, Msb0>) -> Result<Vec<T>, GribberishError>;
 }
+
+/// The bits holding `count` values packed with `width` bits each, starting at bit `start`. Fails
+/// when the values run past the end of the packed data or do not fit a 32 bit integer.
+pub(crate) fn packed_values_bits(
+    bits: &BitSlice<u8, Msb0>,
+    start: usize,
+    count: usize,
+    width: usize,
+) -> Result<&BitSlice<u8, Msb0>, GribberishError> {
+    if width > 32 {
+        return Err(GribberishError::Unsupported(format!(
+            "Values packed with {width} bits"
+        )));
+    }
+
+    match count
+        .checked_mul(width)
+        .and_then(|length| start.checked_add(length))
+        .and_then(|end| bits.get(start..end))
+    {
+        Some(values) => Ok(values),
+        None => Err(GribberishError::TruncatedData {
+            available: bits.len(),
+            expected: start.saturating_add(count.saturating_mul(width)),
+        }),
+    }
+}
+
+/// Checks the groups of values packed with complex packing, starting at bit `start`, hold no
+/// more than `point_count` values, each fitting a 32 bit integer, within the packed data
+pub(crate) fn validate_groups(
+    bits: &BitSlice<u8, Msb0>,
+    start: usize,
+    widths: &[u32],
+    lengths: &[u32],
+    point_count: usize,
+) -> Result<(), GribberishError> {
+    let mut value_count: u64 = 0;
+    let mut bit_count: u64 = 0;
+    for (width, length) in widths.iter().zip(lengths) {
+        if *width > 32 {
+            return Err(GribberishError::Unsupported(format!(
+                "Group values packed with {width} bits"
+            )));
+        }
+        value_count += *length as u64;
+        bit_count += *width as u64 * *length as u64;
+    }
+
+    if value_count > point_count as u64 {
+        return Err(GribberishError::DataRepresentationTemplateError(format!(
+            "Groups hold {value_count} values, more than the {point_count} data points"
+        )));
+    }
+
+    let end = start as u64 + bit_count;
+    if end > bits.len() as u64 {
+        return Err(GribberishError::TruncatedData {
+            available: bits.len(),
+            expected: usize::try_from(end).unwrap_or(usize::MAX),
+        });
+    }
+
+    Ok(())
+}
@@ -1,17 +1,19 @@
+use crate::sections::grib_section::check_section_length;
 use bitvec::prelude::*;
 
 use crate::{error::GribberishError, templates::template::{Template, TemplateType}, utils::{extract_jpeg_data, iter::ScaleGribValueIterator, read_u16_from_bytes}};
 use super::data_representation_template::DataRepresentationTemplate;
 use super::tables::{CompressionType, OriginalFieldValue};
 use crate::utils::read_f32_from_bytes;
+use std::borrow::Cow;
 
-pub struct JPEGDataRepresentationTemplate {
-    data: Vec<u8>,
+pub struct JPEGDataRepresentationTemplate<'a> {
+    data: Cow<'a, [u8]>,
 }
 
-impl Template for JPEGDataRepresentationTemplate {
+impl<'a> Template for JPEGDataRepresentationTemplate<'a> {
     fn data(&self) -> &[u8] {
-        self.data.as_slice()
+        &self.data
     }
 
     fn template_number(&self) -> u16 {
@@ -27,21 +29,28 @@ impl Template for JPEGDataRepresentationTemplate {
     }
 }
 
-impl JPEGDataRepresentationTemplate {
-    pub fn new(data: Vec<u8>) -> JPEGDataRepresentationTemplate {
-        JPEGDataRepresentationTemplate { data }
+impl<'a> JPEGDataRepresentationTemplate<'a> {
+    /// The template read from section data holding at least its 23 octets
+    pub fn new(data: impl Into<Cow<'a, [u8]>>) -> Result<Self, GribberishError> {
+        let data = data.into();
+        check_section_length(5, &data, 23)?;
+        Ok(JPEGDataRepresentationTemplate { data })
+    }
+
+    pub fn into_owned(self) -> JPEGDataRepresentationTemplate<'static> {
+        JPEGDataRepresentationTemplate { data: Cow::Owned(self.data.into_owned()) }
     }
 
     pub fn reference_value(&self) -> f32 {
-        read_f32_from_bytes(self.data.as_slice(), 11).unwrap_or(0.0)
+        read_f32_from_bytes(&self.data, 11).unwrap_or(0.0)
     }
 
     pub fn binary_scale_factor(&self) -> i16 {
-        as_signed!(read_u16_from_bytes(self.data.as_slice(), 15).unwrap_or(0), 16, i16)
+        as_signed!(read_u16_from_bytes(&self.data, 15).unwrap_or(0), 16, i16)
     }
 
     pub fn decimal_scale_factor(&self) -> i16 {
-        as_signed!(read_u16_from_bytes(self.data.as_slice(), 17).unwrap_or(0), 16, i16)
+        as_signed!(read_u16_from_bytes(&self.data, 17).unwrap_or(0), 16, i16)
     }
 
     pub fn bit_count(&self) -> u8 {
@@ -61,7 +70,7 @@ impl JPEGDataRepresentationTemplate {
     }
 }
 
-impl DataRepresentationTemplate<f64> for JPEGDataRepresentationTemplate {
+impl<'a> DataRepresentationTemplate<f64> for JPEGDataRepresentationTemplate<'a> {
     fn compression_type(&self) -> String {
         "JPEG2000".into()
     }
@@ -1,16 +1,18 @@
+use crate::sections::grib_section::check_section_length;
 use bitvec::prelude::*;
 
 use crate::{error::GribberishError, templates::template::{Template, TemplateType}, utils::{iter::ScaleGribValueIterator, read_f32_from_bytes, read_u16_from_bytes}};
 use super::{DataRepresentationTemplate, tables::OriginalFieldValue};
 use png::Decoder;
+use std::borrow::Cow;
 
-pub struct PNGDataRepresentationTemplate {
-    data: Vec<u8>,
+pub struct PNGDataRepresentationTemplate<'a> {
+    data: Cow<'a, [u8]>,
 }
 
-impl Template for PNGDataRepresentationTemplate {
+impl<'a> Template for PNGDataRepresentationTemplate<'a> {
     fn data(&self) -> &[u8] {
-        self.data.as_slice()
+        &self.data
     }
 
     fn template_number(&self) -> u16 {
@@ -26,21 +28,28 @@ impl Template for PNGDataRepresentationTemplate {
     }
 }
 
-impl PNGDataRepresentationTemplate {
-    pub fn new(data: Vec<u8>) -> PNGDataRepresentationTemplate {
-        PNGDataRepresentationTemplate { data }
+impl<'a> PNGDataRepresentationTemplate<'a> {
+    /// The template read from section data holding at least its 21 octets
+    pub fn new(data: impl Into<Cow<'a, [u8]>>) -> Result<Self, GribberishError> {
+        let data = data.into();
+        check_section_length(5, &data, 21)?;
+        Ok(PNGDataRepresentationTemplate { data })
+    }
+
+    pub fn into_owned(self) -> PNGDataRepresentationTemplate<'static> {
+        PNGDataRepresentationTemplate { data: Cow::Owned(self.data.into_owned()) }
     }
 
     pub fn reference_value(&self) -> f32 {
-        read_f32_from_bytes(self.data.as_slice(), 11).unwrap_or(0.0)
+        read_f32_from_bytes(&self.data, 11).unwrap_or(0.0)
     }
 
     pub fn binary_scale_factor(&self) -> i16 {
-        as_signed!(read_u16_from_bytes(self.data.as_slice(), 15).unwrap_or(0), 16, i16)
+        as_signed!(read_u16_from_bytes(&self.data, 15).unwrap_or(0), 16, i16)
     }
 
     pub fn decimal_scale_factor(&self) -> i16 {
-        as_signed!(read_u16_from_bytes(self.data.as_slice(), 17).unwrap_or(0), 16, i16)
+        as_signed!(read_u16_from_bytes(&self.data, 17).unwrap_or(0), 16, i16)
     }
 
     pub fn bit_count(&self) -> u8 {
@@ -52,7 +61,7 @@ impl PNGDataRepresentationTemplate {
     }
 }
 
-impl DataRepresentationTemplate<f64> for PNGDataRepresentationTemplate {
+impl<'a> DataRepresentationTemplate<f64> for PNGDataRepresentationTemplate<'a> {
     fn compression_type(&self) -> String {
         "PNG".into()
     }
@@ -65,16 +74,26 @@ impl DataRepresentationTemplate<f64> for PNGDataRepresentationTemplate {
         let bytes: Vec<u8> = bits.to_bitvec().into();
 
         let decoder = Decoder::new(bytes.as_slice());
-        let mut reader = decoder.read_info().unwrap();
+        let mut reader = decoder
+            .read_info()
+            .map_err(|e| GribberishError::DecodeError { codec: "PNG", reason: e.to_string() })?;
 
         let mut image_data: Vec<u8> = vec![0; reader.output_buffer_size()];
-        let _ = reader.next_frame(&mut image_data).unwrap();
+        reader
+            .next_frame(&mut image_data)
+            .map_err(|e| GribberishError::DecodeError { codec: "PNG", reason: e.to_string() })?;
 
         let bytes_per_datapoint = self.bit_count_per_datapoint() / 8;
+        if bytes_per_datapoint == 0 {
+            return Err(GribberishError::Unsupported(format!(
+                "PNG values packed with {} bits",
+                self.bit_count_per_datapoint()
+            )));
+        }
 
         let values = (0..image_data.len())
             .step_by(bytes_per_datapoint)
-            .map(|ib| read_u16_from_bytes(&image_data, ib).unwrap())
+            .map(|ib| read_u16_from_bytes(&image_data, ib).unwrap_or(0))
             .scale_value_by(self.binary_scale_factor(), self.decimal_scale_factor(), self.reference_value())
             .collect();
 
@@ -1,21 +1,23 @@
+use crate::sections::grib_section::check_section_length;
 use bitvec::prelude::*;
 
 use super::data_representation_template::DataRepresentationTemplate;
 use super::tables::OriginalFieldValue;
 use crate::error::GribberishError;
-use crate::utils::read_f32_from_bytes;
+use crate::utils::{read_f32_from_bytes, read_u32_from_bytes};
 use crate::{
     templates::template::{Template, TemplateType},
     utils::{iter::ScaleGribValueIterator, read_u16_from_bytes},
 };
+use std::borrow::Cow;
 
-pub struct SimplePackingDataRepresentationTemplate {
-    data: Vec<u8>,
+pub struct SimplePackingDataRepresentationTemplate<'a> {
+    data: Cow<'a, [u8]>,
 }
 
-impl Template for SimplePackingDataRepresentationTemplate {
+impl<'a> Template for SimplePackingDataRepresentationTemplate<'a> {
     fn data(&self) -> &[u8] {
-        self.data.as_slice()
+        &self.data
     }
 
     fn template_number(&self) -> u16 {
@@ -31,18 +33,29 @@ impl Template for SimplePackingDataRepresentationTemplate {
     }
 }
 
-impl SimplePackingDataRepresentationTemplate {
-    pub fn new(data: Vec<u8>) -> SimplePackingDataRepresentationTemplate {
-        SimplePackingDataRepresentationTemplate { data }
+impl<'a> SimplePackingDataRepresentationTemplate<'a> {
+    /// The template read from section data holding at least its 21 octets
+    pub fn new(data: impl Into<Cow<'a, [u8]>>) -> Result<Self, GribberishError> {
+        let data = data.into();
+        check_section_length(5, &data, 21)?;
+        Ok(SimplePackingDataRepresentationTemplate { data })
+    }
+
+    pub fn into_owned(self) -> SimplePackingDataRepresentationTemplate<'static> {
+        SimplePackingDataRepresentationTemplate { data: Cow::Owned(self.data.into_owned()) }
+    }
+
+    pub fn data_point_count(&self) -> usize {
+        read_u32_from_bytes(&self.data, 5).unwrap_or(0) as usize
     }
 
     pub fn reference_value(&self) -> f32 {
-        read_f32_from_bytes(self.data.as_slice(), 11).unwrap_or(0.0)
+        read_f32_from_bytes(&self.data, 11).unwrap_or(0.0)
     }
 
     pub fn binary_scale_factor(&self) -> i16 {
         as_signed!(
-            read_u16_from_bytes(self.data.as_slice(), 15).unwrap_or(0),
+            read_u16_from_bytes(&self.data, 15).unwrap_or(0),
             16,
             i16
         )
@@ -50,7 +63,7 @@ impl SimplePackingDataRepresentationTemplate {
 
     pub fn decimal_scale_factor(&self) -> i16 {
         as_signed!(
-            read_u16_from_bytes(self.data.as_slice(), 17).unwrap_or(0),
+            read_u16_from_bytes(&self.data, 17).unwrap_or(0),
             16,
             i16
         )
@@ -65,7 +78,7 @@ impl SimplePackingDataRepresentationTemplate {
     }
 }
 
-impl DataRepresentationTemplate<f64> for SimplePackingDataRepresentationTemplate {
+impl<'a> DataRepresentationTemplate<f64> for SimplePackingDataRepresentationTemplate<'a> {
     fn compression_type(&self) -> String {
         "Simple Grid Packing".into()
     }
@@ -78,6 +91,18 @@ impl DataRepresentationTemplate<f64> for SimplePackingDataRepresentationTemplate
         let bits_per_val: usize = self.bit_count().into();
         if bits_per_val == 0 {
             return Ok(vec![]);
+        } else if bits_per_val > 32 {
+            return Err(GribberishError::Unsupported(format!(
+                "Values packed with {bits_per_val} bits"
+            )));
+        }
+
+        let expected_bits = self.data_point_count().saturating_mul(bits_per_val);
+        if bits.len() < expected_bits {
+            return Err(GribberishError::TruncatedData {
+                available: bits.len(),
+                expected: expected_bits,
+            });
         }
 
         let values = (0..bits.len())
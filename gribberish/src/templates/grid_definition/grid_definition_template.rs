@@ -1,8 +1,9 @@
 use std::collections::HashMap;
 
+use crate::error::GribberishError;
 use crate::utils::iter::projection::LatLngProjection;
 
-pub trait GridDefinitionTemplate {
+pub trait GridDefinitionTemplate: Send + Sync {
     fn proj_string(&self) -> String;
     fn proj_name(&self) -> String;
     fn proj_params(&self) -> HashMap<String, f64>;
@@ -11,5 +12,5 @@ pub trait GridDefinitionTemplate {
     fn is_regular_grid(&self) -> bool;
     fn y_count(&self) -> usize;
     fn x_count(&self) -> usize;
-    fn projector(&self) -> LatLngProjection;
+    fn projector(&self) -> Result<LatLngProjection, GribberishError>;
 }
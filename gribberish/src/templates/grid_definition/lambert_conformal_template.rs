@@ -1,3 +1,4 @@
+use crate::sections::grib_section::check_section_length;
 use bitvec::prelude::*;
 
 use mappers::{projections::LambertConformalConic, Ellipsoid, Projection};
@@ -17,12 +18,13 @@ use super::{
     },
     GridDefinitionTemplate,
 };
+use std::borrow::Cow;
 
-pub struct LambertConformalTemplate {
-    data: Vec<u8>,
+pub struct LambertConformalTemplate<'a> {
+    data: Cow<'a, [u8]>,
 }
 
-impl Template for LambertConformalTemplate {
+impl<'a> Template for LambertConformalTemplate<'a> {
     fn template_type(&self) -> TemplateType {
         TemplateType::Grid
     }
@@ -40,9 +42,16 @@ impl Template for LambertConformalTemplate {
     }
 }
 
-impl LambertConformalTemplate {
-    pub fn new(data: Vec<u8>) -> Self {
-        LambertConformalTemplate { data }
+impl<'a> LambertConformalTemplate<'a> {
+    /// The template read from section data holding at least its 81 octets
+    pub fn new(data: impl Into<Cow<'a, [u8]>>) -> Result<Self, GribberishError> {
+        let data = data.into();
+        check_section_length(3, &data, 81)?;
+        Ok(LambertConformalTemplate { data })
+    }
+
+    pub fn into_owned(self) -> LambertConformalTemplate<'static> {
+        LambertConformalTemplate { data: Cow::Owned(self.data.into_owned()) }
     }
 
     pub fn earth_shape(&self) -> EarthShape {
@@ -100,9 +109,9 @@ impl LambertConformalTemplate {
                 E: 0.0,
                 F: 0.0,
             }),
-            EarthShape::OblateIAU => Err(GribberishError::GridTemplateError("unimplemented: OblateIAU".into())),
-            EarthShape::OblateKM => Err(GribberishError::GridTemplateError("unimplemented: OblateKM".into())),
-            EarthShape::OblateIAGGRS80 => Err(GribberishError::GridTemplateError("unimplemented: OblateIAGGRS80".into())),
+            EarthShape::OblateIAU => Err(GribberishError::Unsupported("OblateIAU earth shape".into())),
+            EarthShape::OblateKM => Err(GribberishError::Unsupported("OblateKM earth shape".into())),
+            EarthShape::OblateIAGGRS80 => Err(GribberishError::Unsupported("OblateIAGGRS80 earth shape".into())),
             EarthShape::WGS84 => Ok(Ellipsoid::WGS84),
             EarthShape::Spherical2 => Ok(Ellipsoid {
                 A: 6_371_229.0,
@@ -110,8 +119,8 @@ impl LambertConformalTemplate {
                 E: 0.0,
                 F: 0.0,
             }),
-            EarthShape::OblateM => Err(GribberishError::GridTemplateError("unimplemented: OblateM".into())),
-            EarthShape::OblateWGS84 => Err(GribberishError::GridTemplateError("unimplemented: OblateWGS84".into())),
+            EarthShape::OblateM => Err(GribberishError::Unsupported("OblateM earth shape".into())),
+            EarthShape::OblateWGS84 => Err(GribberishError::Unsupported("OblateWGS84 earth shape".into())),
             EarthShape::Missing => Err(GribberishError::GridTemplateError("Missing EarthShape".into())),
         }
     }
@@ -124,13 +133,13 @@ impl LambertConformalTemplate {
             EarthShape::Spherical => Ok(" +a=6367470 +b=6367470".to_string()),
             EarthShape::SpecifiedRadiusSpherical => Ok(format!(" +a={major} +b={minor}")),
             EarthShape::OblateIAU => Ok(" +a=6,378,160.0 b=6356775 +rf=297".to_string()),
-            EarthShape::OblateKM => Err(GribberishError::GridTemplateError("unimplemented: OblateKM".into())),
+            EarthShape::OblateKM => Err(GribberishError::Unsupported("OblateKM earth shape".into())),
             EarthShape::OblateIAGGRS80 => Ok(format!(" +a=6378137 +b=6356752.314 +rf=298.257222101")),
             EarthShape::WGS84 => Ok(" +ellps=WGS84".to_string()),
             EarthShape::Spherical2 => Ok(" +a=6371229 +b=6371229".to_string()),
-            EarthShape::OblateM => Err(GribberishError::GridTemplateError("unimplemented: OblateM".into())),
-            EarthShape::OblateWGS84 => Err(GribberishError::GridTemplateError("unimplemented: OblateWGS84".into())),
-            EarthShape::Missing => todo!(),
+            EarthShape::OblateM => Err(GribberishError::Unsupported("OblateM earth shape".into())),
+            EarthShape::OblateWGS84 => Err(GribberishError::Unsupported("OblateWGS84 earth shape".into())),
+            EarthShape::Missing => Err(GribberishError::GridTemplateError("missing earth shape".into())),
         }
     }
 
@@ -304,7 +313,7 @@ impl LambertConformalTemplate {
     }
 }
 
-impl GridDefinitionTemplate for LambertConformalTemplate {
+impl<'a> GridDefinitionTemplate for LambertConformalTemplate<'a> {
     fn proj_name(&self) -> String {
         "lcc".to_string()
     }
@@ -343,7 +352,7 @@ impl GridDefinitionTemplate for LambertConformalTemplate {
     }
 
     fn grid_point_count(&self) -> usize {
-        (self.number_of_points_on_x_axis() * self.number_of_points_on_y_axis()) as usize
+        self.number_of_points_on_x_axis() as usize * self.number_of_points_on_y_axis() as usize
     }
 
     fn is_regular_grid(&self) -> bool {
@@ -358,7 +367,7 @@ impl GridDefinitionTemplate for LambertConformalTemplate {
         self.number_of_points_on_x_axis() as usize
     }
 
-    fn projector(&self) -> LatLngProjection {
+    fn projector(&self) -> Result<LatLngProjection, GribberishError> {
         let mut start_lng = self.longitude_of_first_grid_point();
         start_lng = if start_lng > 180.0 {
             start_lng - 360.0
@@ -366,16 +375,15 @@ impl GridDefinitionTemplate for LambertConformalTemplate {
             start_lng
         };
 
-        let projection = self.projection().expect("Invalid projection");
+        let projection = self.projection()?;
 
         let (start_x, start_y) = projection
             .project(start_lng, self.latitude_of_first_grid_point())
             .map_err(|e| {
-                format!(
+                GribberishError::GridTemplateError(format!(
                     "Failed to project start coordinates to lambert conformal conic coords: {e}"
-                )
-            })
-            .expect("Failed to project");
+                ))
+            })?;
 
         let y_iter = RegularCoordinateIterator::new(
             start_y,
@@ -389,12 +397,12 @@ impl GridDefinitionTemplate for LambertConformalTemplate {
             self.number_of_points_on_x_axis() as usize,
         );
 
-        LatLngProjection::LambertConformal(LambertConformalConicProjection {
+        Ok(LatLngProjection::LambertConformal(LambertConformalConicProjection {
             x: x_iter,
             y: y_iter,
             projection,
             projection_name: self.proj_name(),
             projection_params: self.proj_params(),
-        })
+        }))
     }
 }
@@ -1,20 +1,23 @@
+use crate::sections::grib_section::check_section_length;
 use bitvec::prelude::*;
 
 use super::grid_definition_template::GridDefinitionTemplate;
 use super::tables::{EarthShape, ScanningModeFlags, ScanningMode};
+use crate::error::GribberishError;
 use crate::templates::template::{Template, TemplateType};
 use crate::utils::iter::projection::{RegularCoordinateIterator, LatLngProjection, PlateCareeProjection};
 use crate::utils::read_u32_from_bytes;
 
 use std::iter::Iterator;
 use std::vec::Vec;
+use std::borrow::Cow;
 
 
-pub struct LatLngTemplate {
-    data: Vec<u8>,
+pub struct LatLngTemplate<'a> {
+    data: Cow<'a, [u8]>,
 }
 
-impl Template for LatLngTemplate {
+impl<'a> Template for LatLngTemplate<'a> {
     fn template_type(&self) -> TemplateType {
         TemplateType::Grid
     }
@@ -32,9 +35,16 @@ impl Template for LatLngTemplate {
     }
 }
 
-impl LatLngTemplate {
-    pub fn new(data: Vec<u8>) -> Self {
-        LatLngTemplate { data }
+impl<'a> LatLngTemplate<'a> {
+    /// The template read from section data holding at least its 72 octets
+    pub fn new(data: impl Into<Cow<'a, [u8]>>) -> Result<Self, GribberishError> {
+        let data = data.into();
+        check_section_length(3, &data, 72)?;
+        Ok(LatLngTemplate { data })
+    }
+
+    pub fn into_owned(self) -> LatLngTemplate<'static> {
+        LatLngTemplate { data: Cow::Owned(self.data.into_owned()) }
     }
 
     pub fn earth_shape(&self) -> EarthShape {
@@ -154,7 +164,7 @@ impl LatLngTemplate {
     }
 }
 
-impl GridDefinitionTemplate for LatLngTemplate {
+impl<'a> GridDefinitionTemplate for LatLngTemplate<'a> {
     fn proj_name(&self) -> String {
         "latlon".to_string()
     }
@@ -175,7 +185,7 @@ impl GridDefinitionTemplate for LatLngTemplate {
     }
 
     fn grid_point_count(&self) -> usize {
-        (self.parallel_point_count() * self.meridian_point_count()) as usize
+        self.parallel_point_count() as usize * self.meridian_point_count() as usize
     }
 
     fn is_regular_grid(&self) -> bool {
@@ -190,7 +200,7 @@ impl GridDefinitionTemplate for LatLngTemplate {
         self.parallel_point_count() as usize
     }
 
-    fn projector(&self) -> LatLngProjection {
+    fn projector(&self) -> Result<LatLngProjection, GribberishError> {
         let lat_iter = RegularCoordinateIterator::new(
             self.start_latitude(),
             self.j_direction_increment(),
@@ -203,11 +213,11 @@ impl GridDefinitionTemplate for LatLngTemplate {
             self.x_count()
         );
 
-        LatLngProjection::PlateCaree(PlateCareeProjection {
+        Ok(LatLngProjection::PlateCaree(PlateCareeProjection {
             latitudes: lat_iter, 
             longitudes: lon_iter,
             projection_name: self.proj_name(),
             projection_params: self.proj_params(),
-        })
+        }))
     }
 }
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use crate::utils::read_u16_from_bytes;
+
+/// ECMWF MARS labeling shared by the GRIB2 local definitions written by ECMWF. Only local
+/// definition numbers 1 and 36 are decoded, both start with the two octet class, type and stream
+/// followed by the four character experiment version.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarsLabeling {
+    pub local_definition_number: u16,
+    pub class: u16,
+    pub type_of_data: u16,
+    pub stream: u16,
+    pub experiment_version: String,
+}
+
+impl MarsLabeling {
+    pub fn from_data(data: &[u8]) -> Option<Self> {
+        let local_definition_number = read_u16_from_bytes(data, 0)?;
+        if !matches!(local_definition_number, 1 | 36) {
+            return None;
+        }
+
+        let experiment_version = String::from_utf8_lossy(data.get(8..12)?)
+            .trim_matches(|c: char| c == '\0' || c.is_whitespace())
+            .to_string();
+
+        Some(MarsLabeling {
+            local_definition_number,
+            class: read_u16_from_bytes(data, 2)?,
+            type_of_data: read_u16_from_bytes(data, 4)?,
+            stream: read_u16_from_bytes(data, 6)?,
+            experiment_version,
+        })
+    }
+
+    pub fn class_abbv(&self) -> String {
+        let abbv = match self.class {
+            1 => "od",
+            2 => "rd",
+            3 => "er",
+            4 => "cs",
+            5 => "e4",
+            6 => "dm",
+            7 => "pv",
+            8 => "el",
+            9 => "to",
+            10 => "co",
+            11 => "en",
+            12 => "ti",
+            13 => "me",
+            14 => "ei",
+            _ => return self.class.to_string(),
+        };
+        abbv.into()
+    }
+
+    pub fn type_abbv(&self) -> String {
+        let abbv = match self.type_of_data {
+            1 => "fg",
+            2 => "an",
+            3 => "ia",
+            4 => "oi",
+            5 => "3v",
+            6 => "4v",
+            7 => "3g",
+            8 => "4g",
+            9 => "fc",
+            10 => "cf",
+            11 => "pf",
+            12 => "ef",
+            13 => "ea",
+            14 => "cm",
+            15 => "cs",
+            16 => "fp",
+            17 => "em",
+            18 => "es",
+            19 => "fa",
+            20 => "cl",
+            _ => return self.type_of_data.to_string(),
+        };
+        abbv.into()
+    }
+
+    pub fn stream_abbv(&self) -> String {
+        let abbv = match self.stream {
+            1025 => "oper",
+            1035 => "enfo",
+            _ => return self.stream.to_string(),
+        };
+        abbv.into()
+    }
+
+    /// MARS style request keys, codes without a known abbreviation are given as numbers
+    pub fn keys(&self) -> HashMap<&'static str, String> {
+        HashMap::from([
+            ("class", self.class_abbv()),
+            ("type", self.type_abbv()),
+            ("stream", self.stream_abbv()),
+            ("expver", self.experiment_version.clone()),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MarsLabeling;
+
+    #[test]
+    fn read_mars_labeling() {
+        // Section 2 payload of an ECMWF operational forecast, local definition 1 followed by the
+        // ensemble member fields that are not decoded
+        let raw = [0, 1, 0, 1, 0, 9, 0x04, 0x01, b'0', b'0', b'0', b'1', 0, 0, 0, 0];
+        let labeling = MarsLabeling::from_data(&raw).unwrap();
+
+        assert_eq!(labeling.local_definition_number, 1);
+        assert_eq!(labeling.class_abbv(), "od");
+        assert_eq!(labeling.type_abbv(), "fc");
+        assert_eq!(labeling.stream_abbv(), "oper");
+        assert_eq!(labeling.experiment_version, "0001");
+
+        assert!(MarsLabeling::from_data(&raw[..11]).is_none());
+        assert!(MarsLabeling::from_data(&[0, 2, 0, 1, 0, 9, 0x04, 0x01, b'0', b'0', b'0', b'1']).is_none());
+    }
+}
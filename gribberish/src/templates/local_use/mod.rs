@@ -0,0 +1,44 @@
+pub mod mars_labeling;
+pub mod ncep_ensemble;
+
+pub use mars_labeling::MarsLabeling;
+pub use ncep_ensemble::NCEPEnsembleDefinition;
+
+use crate::sections::identification::OriginatingCentre;
+
+/// Decoded contents of the local use section for the local definitions that are understood
+#[derive(Clone, Debug, PartialEq)]
+pub enum LocalUseDefinition {
+    NCEPEnsemble(NCEPEnsembleDefinition),
+    ECMWFMars(MarsLabeling),
+}
+
+impl LocalUseDefinition {
+    /// Decode the local use payload, the bytes following the five octet section header. The
+    /// layout of the payload is defined by the originating centre.
+    pub fn from_data(centre: OriginatingCentre, data: &[u8]) -> Option<Self> {
+        match centre {
+            OriginatingCentre::NCEP => {
+                NCEPEnsembleDefinition::from_data(data).map(LocalUseDefinition::NCEPEnsemble)
+            }
+            OriginatingCentre::ECMWF => {
+                MarsLabeling::from_data(data).map(LocalUseDefinition::ECMWFMars)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn local_definition_number(&self) -> u16 {
+        match self {
+            LocalUseDefinition::NCEPEnsemble(definition) => definition.local_definition_number.into(),
+            LocalUseDefinition::ECMWFMars(labeling) => labeling.local_definition_number,
+        }
+    }
+
+    pub fn mars_labeling(&self) -> Option<&MarsLabeling> {
+        match self {
+            LocalUseDefinition::ECMWFMars(labeling) => Some(labeling),
+            _ => None,
+        }
+    }
+}
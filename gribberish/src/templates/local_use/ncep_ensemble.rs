@@ -0,0 +1,39 @@
+/// NCEP ensemble extension carried in the local use section. The octets mirror the GRIB1 PDS
+/// ensemble extension (octets 41-45) and are identified by local definition number 1.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NCEPEnsembleDefinition {
+    pub local_definition_number: u8,
+    pub application_identifier: u8,
+    pub ensemble_type: u8,
+    pub identification_number: u8,
+    pub product_identifier: u8,
+    pub smoothing_flag: u8,
+}
+
+impl NCEPEnsembleDefinition {
+    pub fn from_data(data: &[u8]) -> Option<Self> {
+        if data.len() < 6 || data[0] != 1 {
+            return None;
+        }
+
+        Some(NCEPEnsembleDefinition {
+            local_definition_number: data[0],
+            application_identifier: data[1],
+            ensemble_type: data[2],
+            identification_number: data[3],
+            product_identifier: data[4],
+            smoothing_flag: data[5],
+        })
+    }
+
+    pub fn ensemble_type_description(&self) -> &'static str {
+        match self.ensemble_type {
+            1 => "unperturbed high resolution control forecast",
+            2 => "unperturbed low resolution control forecast",
+            3 => "negatively perturbed forecast",
+            4 => "positively perturbed forecast",
+            5 => "multiple forecast product",
+            _ => "unknown",
+        }
+    }
+}
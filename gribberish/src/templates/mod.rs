@@ -1,4 +1,5 @@
 pub mod template;
 pub mod grid_definition;
 pub mod product;
-pub mod data_representation;
\ No newline at end of file
+pub mod data_representation;
+pub mod local_use;
\ No newline at end of file
@@ -0,0 +1,233 @@
+use crate::error::GribberishError;
+use crate::sections::grib_section::check_section_length;
+use crate::templates::template::{Template, TemplateType};
+use crate::utils::{read_u16_from_bytes, read_u32_from_bytes};
+use chrono::{prelude::*, Duration};
+
+use super::product_template::ProductTemplate;
+use super::tables::{
+    FixedSurfaceType, GeneratingProcess, TimeUnit, TypeOfStatisticalProcessing, TypeOfTimeInterval,
+};
+use super::time_range::TimeRangeSpecification;
+use super::HorizontalAnalysisForecastTemplate;
+use std::borrow::Cow;
+
+pub struct AerosolAverageAccumulationExtremeHorizontalForecastTemplate<'a> {
+    data: Cow<'a, [u8]>,
+    discipline: u8,
+}
+
+impl<'a> Template for AerosolAverageAccumulationExtremeHorizontalForecastTemplate<'a> {
+    fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    fn template_number(&self) -> u16 {
+        46
+    }
+
+    fn template_type(&self) -> TemplateType {
+        TemplateType::Product
+    }
+
+    fn template_name(&self) -> &str {
+        "Average, accumulation, and/or extreme values or other statistically processed values
+        at a horizontal level or in a horizontal layer in a continuous or non-continuous
+        time interval for aerosol"
+    }
+}
+
+impl<'a> AerosolAverageAccumulationExtremeHorizontalForecastTemplate<'a> {
+    /// The template read from section data holding at least its 71 octets
+    pub fn new(data: impl Into<Cow<'a, [u8]>>, discipline: u8) -> Result<Self, GribberishError> {
+        let data = data.into();
+        check_section_length(4, &data, 71)?;
+        Ok(Self { data, discipline })
+    }
+
+    pub fn into_owned(self) -> AerosolAverageAccumulationExtremeHorizontalForecastTemplate<'static> {
+        AerosolAverageAccumulationExtremeHorizontalForecastTemplate {
+            data: Cow::Owned(self.data.into_owned()),
+            discipline: self.discipline,
+        }
+    }
+
+    pub fn category_value(&self) -> u8 {
+        self.data[9]
+    }
+
+    pub fn parameter_value(&self) -> u8 {
+        self.data[10]
+    }
+
+    pub fn aerosol_type(&self) -> u16 {
+        read_u16_from_bytes(&self.data, 11).unwrap_or(0)
+    }
+
+    pub fn type_of_size_interval(&self) -> u8 {
+        self.data[13]
+    }
+
+    pub fn first_size(&self) -> Option<f64> {
+        HorizontalAnalysisForecastTemplate::scale_value(
+            as_signed!(self.data[14], 8, i8),
+            as_signed!(read_u32_from_bytes(&self.data, 15).unwrap_or(0), 32, i32),
+        )
+    }
+
+    pub fn second_size(&self) -> Option<f64> {
+        HorizontalAnalysisForecastTemplate::scale_value(
+            as_signed!(self.data[19], 8, i8),
+            as_signed!(read_u32_from_bytes(&self.data, 20).unwrap_or(0), 32, i32),
+        )
+    }
+
+    pub fn generating_process(&self) -> GeneratingProcess {
+        self.data[24].into()
+    }
+
+    pub fn observation_cutoff_hours_after_reference_time(&self) -> u16 {
+        read_u16_from_bytes(&self.data, 27).unwrap_or(0)
+    }
+
+    pub fn observation_cutoff_minutes_after_cutoff_time(&self) -> u8 {
+        self.data[29]
+    }
+
+    pub fn forecast_time(&self) -> u32 {
+        read_u32_from_bytes(&self.data, 31).unwrap_or(0)
+    }
+
+    pub fn first_fixed_surface_scale_factor(&self) -> i8 {
+        as_signed!(self.data[36], 8, i8)
+    }
+
+    pub fn first_fixed_surface_scaled_value(&self) -> i32 {
+        as_signed!(read_u32_from_bytes(&self.data, 37).unwrap_or(0), 32, i32)
+    }
+
+    pub fn second_fixed_surface_scale_factor(&self) -> i8 {
+        as_signed!(self.data[42], 8, i8)
+    }
+
+    pub fn second_fixed_surface_scaled_value(&self) -> i32 {
+        as_signed!(read_u32_from_bytes(&self.data, 43).unwrap_or(0), 32, i32)
+    }
+
+    pub fn valid_end_date(&self) -> DateTime<Utc> {
+        let data = self.data();
+        let year = read_u16_from_bytes(data, 47).unwrap_or(0) as i32;
+        let month = data[49] as u32;
+        let day = data[50] as u32;
+        let hour = data[51] as u32;
+        let minute = data[52] as u32;
+        let second = data[53] as u32;
+
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, second)
+            .single()
+            .unwrap_or_default()
+    }
+
+    pub fn number_of_time_ranges(&self) -> u8 {
+        self.data()[54]
+    }
+
+    pub fn number_of_values_missing_from_stats(&self) -> u32 {
+        read_u32_from_bytes(self.data(), 55).unwrap_or(0)
+    }
+
+    pub fn time_ranges(&self) -> Vec<TimeRangeSpecification> {
+        TimeRangeSpecification::read_all(self.data(), 59, self.number_of_time_ranges() as usize)
+    }
+
+    pub fn type_of_time_interval(&self) -> TypeOfTimeInterval {
+        self.data()[60].into()
+    }
+
+    pub fn statistical_process_time_unit(&self) -> TimeUnit {
+        self.data()[61].into()
+    }
+
+    pub fn statistical_process_time_interval(&self) -> u32 {
+        read_u32_from_bytes(self.data(), 62).unwrap_or(0)
+    }
+}
+
+impl<'a> ProductTemplate for AerosolAverageAccumulationExtremeHorizontalForecastTemplate<'a> {
+    fn discipline(&self) -> u8 {
+        self.discipline
+    }
+
+    fn category_value(&self) -> u8 {
+        self.data[9]
+    }
+
+    fn parameter_value(&self) -> u8 {
+        self.data[10]
+    }
+
+    fn generating_process(&self) -> GeneratingProcess {
+        self.data[24].into()
+    }
+
+    fn time_unit(&self) -> TimeUnit {
+        self.data[30].into()
+    }
+
+    fn time_increment_unit(&self) -> Option<TimeUnit> {
+        Some(self.data()[66].into())
+    }
+
+    fn time_interval(&self) -> u32 {
+        read_u32_from_bytes(&self.data, 31).unwrap_or(0)
+    }
+
+    fn time_increment_interval(&self) -> Option<u32> {
+        Some(read_u32_from_bytes(self.data(), 67).unwrap_or(0))
+    }
+
+    fn forecast_datetime(&self, reference_date: DateTime<Utc>) -> DateTime<Utc> {
+        let offset_duration: Duration = self.time_interval_duration();
+        reference_date
+            .checked_add_signed(offset_duration)
+            .unwrap_or(reference_date)
+    }
+
+    fn forecast_end_datetime(&self, _reference_date: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        Some(self.valid_end_date())
+    }
+
+    fn first_fixed_surface_type(&self) -> FixedSurfaceType {
+        self.data[35].into()
+    }
+
+    fn first_fixed_surface_value(&self) -> Option<f64> {
+        HorizontalAnalysisForecastTemplate::scale_value(
+            self.first_fixed_surface_scale_factor(),
+            self.first_fixed_surface_scaled_value(),
+        )
+    }
+
+    fn second_fixed_surface_type(&self) -> FixedSurfaceType {
+        self.data[41].into()
+    }
+
+    fn second_fixed_surface_value(&self) -> Option<f64> {
+        HorizontalAnalysisForecastTemplate::scale_value(
+            self.second_fixed_surface_scale_factor(),
+            self.second_fixed_surface_scaled_value(),
+        )
+    }
+
+    fn derived_forecast_type(&self) -> Option<super::tables::DerivedForecastType> {
+        None
+    }
+
+    fn statistical_process_type(&self) -> Option<TypeOfStatisticalProcessing> {
+        Some(self.data()[59].into())
+    }
+
+    fn time_ranges(&self) -> Vec<TimeRangeSpecification> {
+        TimeRangeSpecification::read_all(self.data(), 59, self.number_of_time_ranges() as usize)
+    }
+}
@@ -1,19 +1,23 @@
+use crate::error::GribberishError;
+use crate::sections::grib_section::check_section_length;
 use crate::templates::template::{Template, TemplateType};
 use crate::utils::{read_u16_from_bytes, read_u32_from_bytes};
 use chrono::{prelude::*, Duration};
 
 use super::product_template::ProductTemplate;
+use super::time_range::TimeRangeSpecification;
 use super::tables::{
     FixedSurfaceType, GeneratingProcess, TimeUnit, TypeOfStatisticalProcessing, TypeOfTimeInterval,
 };
 use super::HorizontalAnalysisForecastTemplate;
+use std::borrow::Cow;
 
-pub struct AverageAccumulationExtremeHorizontalAnalysisForecastTemplate {
-    data: Vec<u8>,
+pub struct AverageAccumulationExtremeHorizontalAnalysisForecastTemplate<'a> {
+    data: Cow<'a, [u8]>,
     discipline: u8,
 }
 
-impl Template for AverageAccumulationExtremeHorizontalAnalysisForecastTemplate {
+impl<'a> Template for AverageAccumulationExtremeHorizontalAnalysisForecastTemplate<'a> {
     fn data(&self) -> &[u8] {
         &self.data
     }
@@ -33,9 +37,19 @@ impl Template for AverageAccumulationExtremeHorizontalAnalysisForecastTemplate {
     }
 }
 
-impl AverageAccumulationExtremeHorizontalAnalysisForecastTemplate {
-    pub fn new(data: Vec<u8>, discipline: u8) -> Self {
-        Self { data, discipline }
+impl<'a> AverageAccumulationExtremeHorizontalAnalysisForecastTemplate<'a> {
+    /// The template read from section data holding at least its 58 octets
+    pub fn new(data: impl Into<Cow<'a, [u8]>>, discipline: u8) -> Result<Self, GribberishError> {
+        let data = data.into();
+        check_section_length(4, &data, 58)?;
+        Ok(Self { data, discipline })
+    }
+
+    pub fn into_owned(self) -> AverageAccumulationExtremeHorizontalAnalysisForecastTemplate<'static> {
+        AverageAccumulationExtremeHorizontalAnalysisForecastTemplate {
+            data: Cow::Owned(self.data.into_owned()),
+            discipline: self.discipline,
+        }
     }
 
     pub fn category_value(&self) -> u8 {
@@ -88,7 +102,8 @@ impl AverageAccumulationExtremeHorizontalAnalysisForecastTemplate {
         let second = data[40] as u32;
 
         Utc.with_ymd_and_hms(year as i32, month, day, hour, minute, second)
-            .unwrap()
+            .single()
+            .unwrap_or_default()
     }
 
     pub fn number_of_time_ranges(&self) -> u8 {
@@ -110,9 +125,13 @@ impl AverageAccumulationExtremeHorizontalAnalysisForecastTemplate {
     pub fn statistical_process_time_interval(&self) -> u32 {
         read_u32_from_bytes(self.data(), 49).unwrap_or(0)
     }
+
+    pub fn time_ranges(&self) -> Vec<TimeRangeSpecification> {
+        TimeRangeSpecification::read_all(self.data(), 46, self.number_of_time_ranges() as usize)
+    }
 }
 
-impl ProductTemplate for AverageAccumulationExtremeHorizontalAnalysisForecastTemplate {
+impl<'a> ProductTemplate for AverageAccumulationExtremeHorizontalAnalysisForecastTemplate<'a> {
     fn discipline(&self) -> u8 {
         self.discipline
     }
@@ -147,7 +166,9 @@ impl ProductTemplate for AverageAccumulationExtremeHorizontalAnalysisForecastTem
 
     fn forecast_datetime(&self, reference_date: DateTime<Utc>) -> DateTime<Utc> {
         let offset_duration: Duration = self.time_interval_duration();
-        reference_date + offset_duration
+        reference_date
+            .checked_add_signed(offset_duration)
+            .unwrap_or(reference_date)
     }
 
     fn forecast_end_datetime(&self, _reference_date: DateTime<Utc>) -> Option<DateTime<Utc>> {
@@ -183,4 +204,8 @@ impl ProductTemplate for AverageAccumulationExtremeHorizontalAnalysisForecastTem
     fn statistical_process_type(&self) -> Option<TypeOfStatisticalProcessing> {
         Some(self.data()[46].into())
     }
+
+    fn time_ranges(&self) -> Vec<TimeRangeSpecification> {
+        TimeRangeSpecification::read_all(self.data(), 46, self.number_of_time_ranges() as usize)
+    }
 }
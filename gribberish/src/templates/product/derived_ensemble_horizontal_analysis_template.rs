@@ -1,16 +1,19 @@
+use crate::error::GribberishError;
+use crate::sections::grib_section::check_section_length;
 use crate::templates::template::{Template, TemplateType};
 use crate::utils::read_u32_from_bytes;
 use chrono::{Utc, DateTime, Duration};
+use std::borrow::Cow;
 
 use super::product_template::ProductTemplate;
 use super::tables::{TimeUnit, GeneratingProcess, FixedSurfaceType, DerivedForecastType};
 
-pub struct DerivedEnsembleHorizontalAnalysisForecastTemplate {
-	data: Vec<u8>,
+pub struct DerivedEnsembleHorizontalAnalysisForecastTemplate<'a> {
+	data: Cow<'a, [u8]>,
 	discipline: u8,
 }
 
-impl Template for DerivedEnsembleHorizontalAnalysisForecastTemplate {
+impl<'a> Template for DerivedEnsembleHorizontalAnalysisForecastTemplate<'a> {
 	fn data(&self) -> &[u8] {
     	&self.data
  	}
@@ -29,10 +32,20 @@ impl Template for DerivedEnsembleHorizontalAnalysisForecastTemplate {
     }
 }
 
-impl DerivedEnsembleHorizontalAnalysisForecastTemplate {
+impl<'a> DerivedEnsembleHorizontalAnalysisForecastTemplate<'a> {
 
-	pub fn new(	data: Vec<u8>, discipline: u8) -> Self {
-		DerivedEnsembleHorizontalAnalysisForecastTemplate { data, discipline }
+	/// The template read from section data holding at least its 36 octets
+	pub fn new(data: impl Into<Cow<'a, [u8]>>, discipline: u8) -> Result<Self, GribberishError> {
+		let data = data.into();
+		check_section_length(4, &data, 36)?;
+		Ok(DerivedEnsembleHorizontalAnalysisForecastTemplate { data, discipline })
+	}
+
+	pub fn into_owned(self) -> DerivedEnsembleHorizontalAnalysisForecastTemplate<'static> {
+		DerivedEnsembleHorizontalAnalysisForecastTemplate {
+			data: Cow::Owned(self.data.into_owned()),
+			discipline: self.discipline,
+		}
 	}
 
 	pub fn category_value(&self) -> u8 {
@@ -90,7 +103,7 @@ impl DerivedEnsembleHorizontalAnalysisForecastTemplate {
 	}
 }
 
-impl ProductTemplate for DerivedEnsembleHorizontalAnalysisForecastTemplate {
+impl<'a> ProductTemplate for DerivedEnsembleHorizontalAnalysisForecastTemplate<'a> {
 	fn discipline(&self) -> u8 {
 		self.discipline
 	}
@@ -125,7 +138,9 @@ impl ProductTemplate for DerivedEnsembleHorizontalAnalysisForecastTemplate {
 
     fn forecast_datetime(&self, reference_date: DateTime<Utc>) -> DateTime<Utc> {
 		let offset_duration: Duration = self.time_interval_duration();
-		reference_date + offset_duration
+		reference_date
+			.checked_add_signed(offset_duration)
+			.unwrap_or(reference_date)
     }
 
 	fn forecast_end_datetime(&self, _reference_date: DateTime<Utc>) -> Option<DateTime<Utc>> {
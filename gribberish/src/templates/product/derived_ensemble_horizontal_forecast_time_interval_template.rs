@@ -1,17 +1,21 @@
+use crate::error::GribberishError;
+use crate::sections::grib_section::check_section_length;
 use crate::templates::template::{Template, TemplateType};
 use crate::utils::{read_u16_from_bytes, read_u32_from_bytes};
 use chrono::{prelude::*, Duration};
+use std::borrow::Cow;
 
 use super::HorizontalAnalysisForecastTemplate;
 use super::product_template::ProductTemplate;
+use super::time_range::TimeRangeSpecification;
 use super::tables::{TypeOfStatisticalProcessing, TypeOfTimeInterval, TimeUnit, GeneratingProcess, FixedSurfaceType, DerivedForecastType};
 
-pub struct DerivedEnsembleHorizontalForecastTimeIntervalTemplate {
-	data: Vec<u8>,
+pub struct DerivedEnsembleHorizontalForecastTimeIntervalTemplate<'a> {
+	data: Cow<'a, [u8]>,
 	discipline: u8,
 }
 
-impl Template for DerivedEnsembleHorizontalForecastTimeIntervalTemplate {
+impl<'a> Template for DerivedEnsembleHorizontalForecastTimeIntervalTemplate<'a> {
 	fn data(&self) -> &[u8] {
     	&self.data
  	}
@@ -30,12 +34,22 @@ impl Template for DerivedEnsembleHorizontalForecastTimeIntervalTemplate {
     }
 }
 
-impl DerivedEnsembleHorizontalForecastTimeIntervalTemplate {
-	pub fn new(	data: Vec<u8>, discipline: u8) -> Self {
-		Self {
-            data, 
+impl<'a> DerivedEnsembleHorizontalForecastTimeIntervalTemplate<'a> {
+	/// The template read from section data holding at least its 60 octets
+	pub fn new(data: impl Into<Cow<'a, [u8]>>, discipline: u8) -> Result<Self, GribberishError> {
+		let data = data.into();
+		check_section_length(4, &data, 60)?;
+		Ok(Self {
+            data,
             discipline,
-        }
+        })
+	}
+
+	pub fn into_owned(self) -> DerivedEnsembleHorizontalForecastTimeIntervalTemplate<'static> {
+		DerivedEnsembleHorizontalForecastTimeIntervalTemplate {
+			data: Cow::Owned(self.data.into_owned()),
+			discipline: self.discipline,
+		}
 	}
 
 	pub fn category_value(&self) -> u8 {
@@ -87,7 +101,7 @@ impl DerivedEnsembleHorizontalForecastTimeIntervalTemplate {
         let minute = data[41] as u32;
         let second = data[42] as u32;
 
-        Utc.with_ymd_and_hms(year as i32, month, day, hour, minute, second).unwrap()
+        Utc.with_ymd_and_hms(year as i32, month, day, hour, minute, second).single().unwrap_or_default()
     }
 
     pub fn number_of_time_ranges(&self) -> u8 {
@@ -109,9 +123,13 @@ impl DerivedEnsembleHorizontalForecastTimeIntervalTemplate {
     pub fn statistical_process_time_interval(&self) -> u32 {
         read_u32_from_bytes(self.data(), 51).unwrap_or(0)
     }
+
+    pub fn time_ranges(&self) -> Vec<TimeRangeSpecification> {
+        TimeRangeSpecification::read_all(self.data(), 48, self.number_of_time_ranges() as usize)
+    }
 }
 
-impl ProductTemplate for DerivedEnsembleHorizontalForecastTimeIntervalTemplate {
+impl<'a> ProductTemplate for DerivedEnsembleHorizontalForecastTimeIntervalTemplate<'a> {
     fn discipline(&self) -> u8 {
 		self.discipline
 	}
@@ -133,7 +151,7 @@ impl ProductTemplate for DerivedEnsembleHorizontalForecastTimeIntervalTemplate {
     }
 
     fn time_increment_unit(&self) -> Option<TimeUnit> {
-        Some(self.data()[56].into())
+        Some(self.data()[55].into())
     }
 
     fn time_interval(&self) -> u32 {
@@ -146,7 +164,9 @@ impl ProductTemplate for DerivedEnsembleHorizontalForecastTimeIntervalTemplate {
 
     fn forecast_datetime(&self, reference_date: DateTime<Utc>) -> DateTime<Utc> {
 		let offset_duration: Duration = self.time_interval_duration();
-		reference_date + offset_duration
+		reference_date
+			.checked_add_signed(offset_duration)
+			.unwrap_or(reference_date)
     }
 
     fn forecast_end_datetime(&self, _reference_date: DateTime<Utc>) -> Option<DateTime<Utc>> {
@@ -176,4 +196,8 @@ impl ProductTemplate for DerivedEnsembleHorizontalForecastTimeIntervalTemplate {
     fn statistical_process_type(&self) -> Option<TypeOfStatisticalProcessing> {
         Some(self.data()[48].into())
     }
-}
\ No newline at end of file
+
+    fn time_ranges(&self) -> Vec<TimeRangeSpecification> {
+        TimeRangeSpecification::read_all(self.data(), 48, self.number_of_time_ranges() as usize)
+    }
+}
@@ -0,0 +1,190 @@
+use crate::error::GribberishError;
+use crate::sections::grib_section::check_section_length;
+use crate::templates::template::{Template, TemplateType};
+use crate::utils::{read_u16_from_bytes, read_u32_from_bytes};
+use chrono::{prelude::*, Duration};
+
+use super::product_template::ProductTemplate;
+use super::tables::{
+    FixedSurfaceType, GeneratingProcess, TimeUnit, TypeOfEnsembleForecast,
+    TypeOfStatisticalProcessing,
+};
+use super::HorizontalAnalysisForecastTemplate;
+use std::borrow::Cow;
+
+pub struct EnsembleReforecastHorizontalAnalysisForecastTemplate<'a> {
+    data: Cow<'a, [u8]>,
+    discipline: u8,
+}
+
+impl<'a> Template for EnsembleReforecastHorizontalAnalysisForecastTemplate<'a> {
+    fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    fn template_number(&self) -> u16 {
+        60
+    }
+
+    fn template_type(&self) -> TemplateType {
+        TemplateType::Product
+    }
+
+    fn template_name(&self) -> &str {
+        "Individual ensemble reforecast, control and perturbed, at a horizontal level or
+        in a horizontal layer at a point in time"
+    }
+}
+
+impl<'a> EnsembleReforecastHorizontalAnalysisForecastTemplate<'a> {
+    /// The template read from section data holding at least its 44 octets
+    pub fn new(data: impl Into<Cow<'a, [u8]>>, discipline: u8) -> Result<Self, GribberishError> {
+        let data = data.into();
+        check_section_length(4, &data, 44)?;
+        Ok(Self { data, discipline })
+    }
+
+    pub fn into_owned(self) -> EnsembleReforecastHorizontalAnalysisForecastTemplate<'static> {
+        EnsembleReforecastHorizontalAnalysisForecastTemplate {
+            data: Cow::Owned(self.data.into_owned()),
+            discipline: self.discipline,
+        }
+    }
+
+    pub fn category_value(&self) -> u8 {
+        self.data[9]
+    }
+
+    pub fn parameter_value(&self) -> u8 {
+        self.data[10]
+    }
+
+    pub fn generating_process(&self) -> GeneratingProcess {
+        self.data[11].into()
+    }
+
+    pub fn observation_cutoff_hours_after_reference_time(&self) -> u16 {
+        read_u16_from_bytes(&self.data, 14).unwrap_or(0)
+    }
+
+    pub fn observation_cutoff_minutes_after_cutoff_time(&self) -> u8 {
+        self.data[16]
+    }
+
+    pub fn first_fixed_surface_scale_factor(&self) -> i8 {
+        as_signed!(self.data[23], 8, i8)
+    }
+
+    pub fn first_fixed_surface_scaled_value(&self) -> i32 {
+        as_signed!(read_u32_from_bytes(&self.data, 24).unwrap_or(0), 32, i32)
+    }
+
+    pub fn second_fixed_surface_scale_factor(&self) -> i8 {
+        as_signed!(self.data[29], 8, i8)
+    }
+
+    pub fn second_fixed_surface_scaled_value(&self) -> i32 {
+        as_signed!(read_u32_from_bytes(&self.data, 30).unwrap_or(0), 32, i32)
+    }
+
+    pub fn ensemble_forecast_type(&self) -> TypeOfEnsembleForecast {
+        self.data[34].into()
+    }
+
+    pub fn perturbation_number(&self) -> u8 {
+        self.data[35]
+    }
+
+    pub fn number_of_forecasts_in_ensemble(&self) -> u8 {
+        self.data[36]
+    }
+
+    pub fn model_version_date(&self) -> DateTime<Utc> {
+        let data = self.data();
+        let year = read_u16_from_bytes(data, 37).unwrap_or(0) as i32;
+        let month = data[39] as u32;
+        let day = data[40] as u32;
+        let hour = data[41] as u32;
+        let minute = data[42] as u32;
+        let second = data[43] as u32;
+
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, second)
+            .single()
+            .unwrap_or_default()
+    }
+}
+
+impl<'a> ProductTemplate for EnsembleReforecastHorizontalAnalysisForecastTemplate<'a> {
+    fn discipline(&self) -> u8 {
+        self.discipline
+    }
+
+    fn category_value(&self) -> u8 {
+        self.data[9]
+    }
+
+    fn parameter_value(&self) -> u8 {
+        self.data[10]
+    }
+
+    fn generating_process(&self) -> GeneratingProcess {
+        self.data[11].into()
+    }
+
+    fn time_unit(&self) -> TimeUnit {
+        self.data[17].into()
+    }
+
+    fn time_increment_unit(&self) -> Option<TimeUnit> {
+        None
+    }
+
+    fn time_interval(&self) -> u32 {
+        read_u32_from_bytes(&self.data, 18).unwrap_or(0)
+    }
+
+    fn time_increment_interval(&self) -> Option<u32> {
+        None
+    }
+
+    fn forecast_datetime(&self, reference_date: DateTime<Utc>) -> DateTime<Utc> {
+        let offset_duration: Duration = self.time_interval_duration();
+        reference_date
+            .checked_add_signed(offset_duration)
+            .unwrap_or(reference_date)
+    }
+
+    fn forecast_end_datetime(&self, _reference_date: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        None
+    }
+
+    fn first_fixed_surface_type(&self) -> FixedSurfaceType {
+        self.data[22].into()
+    }
+
+    fn first_fixed_surface_value(&self) -> Option<f64> {
+        HorizontalAnalysisForecastTemplate::scale_value(
+            self.first_fixed_surface_scale_factor(),
+            self.first_fixed_surface_scaled_value(),
+        )
+    }
+
+    fn second_fixed_surface_type(&self) -> FixedSurfaceType {
+        self.data[28].into()
+    }
+
+    fn second_fixed_surface_value(&self) -> Option<f64> {
+        HorizontalAnalysisForecastTemplate::scale_value(
+            self.second_fixed_surface_scale_factor(),
+            self.second_fixed_surface_scaled_value(),
+        )
+    }
+
+    fn derived_forecast_type(&self) -> Option<super::tables::DerivedForecastType> {
+        None
+    }
+
+    fn statistical_process_type(&self) -> Option<TypeOfStatisticalProcessing> {
+        None
+    }
+}
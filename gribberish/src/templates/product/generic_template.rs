@@ -0,0 +1,163 @@
+use crate::error::GribberishError;
+use crate::sections::grib_section::check_section_length;
+use crate::templates::template::{Template, TemplateType};
+use crate::utils::read_u32_from_bytes;
+use chrono::{DateTime, Utc};
+
+use super::product_template::ProductTemplate;
+use super::tables::{FixedSurfaceType, GeneratingProcess, TimeUnit};
+use super::HorizontalAnalysisForecastTemplate;
+use std::borrow::Cow;
+
+/// Fallback for product templates that are not explicitly supported. Only the parameter,
+/// generating process, forecast time and fixed surface octets (10-34), which are laid out the
+/// same way as template 4.0 in most product templates, are read.
+pub struct GenericProductTemplate<'a> {
+    data: Cow<'a, [u8]>,
+    discipline: u8,
+    template_number: u16,
+}
+
+impl<'a> Template for GenericProductTemplate<'a> {
+    fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    fn template_number(&self) -> u16 {
+        self.template_number
+    }
+
+    fn template_type(&self) -> TemplateType {
+        TemplateType::Product
+    }
+
+    fn template_name(&self) -> &str {
+        "Unsupported product template, only the fields shared with template 4.0 are available"
+    }
+}
+
+impl<'a> GenericProductTemplate<'a> {
+    /// The template read from section data holding at least its 34 octets
+    pub fn new(data: impl Into<Cow<'a, [u8]>>, discipline: u8, template_number: u16) -> Result<Self, GribberishError> {
+        let data = data.into();
+        check_section_length(4, &data, 34)?;
+        Ok(GenericProductTemplate {
+            data,
+            discipline,
+            template_number,
+        })
+    }
+
+    pub fn into_owned(self) -> GenericProductTemplate<'static> {
+        GenericProductTemplate {
+            data: Cow::Owned(self.data.into_owned()),
+            discipline: self.discipline,
+            template_number: self.template_number,
+        }
+    }
+
+    /// Whether the given template number is expected to share the layout of octets 10-34 with
+    /// template 4.0. Chemical, aerosol, radar, satellite, tile, CCITT and cross section templates
+    /// insert or replace octets ahead of the generating process and cannot be read generically.
+    pub fn has_common_layout(template_number: u16) -> bool {
+        !matches!(
+            template_number,
+            20 | 30..=35 | 40..=50 | 52..=59 | 62..=99 | 254 | 311 | 1000..=1101
+        )
+    }
+
+    pub fn first_fixed_surface_scale_factor(&self) -> i8 {
+        as_signed!(self.data[23], 8, i8)
+    }
+
+    pub fn first_fixed_surface_scaled_value(&self) -> i32 {
+        as_signed!(read_u32_from_bytes(&self.data, 24).unwrap_or(0), 32, i32)
+    }
+
+    pub fn second_fixed_surface_scale_factor(&self) -> i8 {
+        as_signed!(self.data[29], 8, i8)
+    }
+
+    pub fn second_fixed_surface_scaled_value(&self) -> i32 {
+        as_signed!(read_u32_from_bytes(&self.data, 30).unwrap_or(0), 32, i32)
+    }
+}
+
+impl<'a> ProductTemplate for GenericProductTemplate<'a> {
+    fn discipline(&self) -> u8 {
+        self.discipline
+    }
+
+    fn category_value(&self) -> u8 {
+        self.data[9]
+    }
+
+    fn parameter_value(&self) -> u8 {
+        self.data[10]
+    }
+
+    fn generating_process(&self) -> GeneratingProcess {
+        self.data[11].into()
+    }
+
+    fn time_unit(&self) -> TimeUnit {
+        self.data[17].into()
+    }
+
+    fn time_increment_unit(&self) -> Option<TimeUnit> {
+        None
+    }
+
+    fn time_interval(&self) -> u32 {
+        read_u32_from_bytes(&self.data, 18).unwrap_or(0)
+    }
+
+    fn time_increment_interval(&self) -> Option<u32> {
+        None
+    }
+
+    fn forecast_datetime(&self, reference_date: DateTime<Utc>) -> DateTime<Utc> {
+        let offset_duration = self.time_interval_duration();
+        reference_date
+            .checked_add_signed(offset_duration)
+            .unwrap_or(reference_date)
+    }
+
+    fn forecast_end_datetime(&self, _reference_date: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        None
+    }
+
+    fn first_fixed_surface_type(&self) -> FixedSurfaceType {
+        self.data[22].into()
+    }
+
+    fn first_fixed_surface_value(&self) -> Option<f64> {
+        HorizontalAnalysisForecastTemplate::scale_value(
+            self.first_fixed_surface_scale_factor(),
+            self.first_fixed_surface_scaled_value(),
+        )
+    }
+
+    fn second_fixed_surface_type(&self) -> FixedSurfaceType {
+        self.data[28].into()
+    }
+
+    fn second_fixed_surface_value(&self) -> Option<f64> {
+        HorizontalAnalysisForecastTemplate::scale_value(
+            self.second_fixed_surface_scale_factor(),
+            self.second_fixed_surface_scaled_value(),
+        )
+    }
+
+    fn derived_forecast_type(&self) -> Option<super::tables::DerivedForecastType> {
+        None
+    }
+
+    fn statistical_process_type(&self) -> Option<super::tables::TypeOfStatisticalProcessing> {
+        None
+    }
+
+    fn is_partial(&self) -> bool {
+        true
+    }
+}
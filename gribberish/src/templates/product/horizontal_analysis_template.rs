@@ -1,16 +1,19 @@
+use crate::error::GribberishError;
+use crate::sections::grib_section::check_section_length;
 use crate::templates::template::{Template, TemplateType};
 use crate::utils::{read_u16_from_bytes, read_u32_from_bytes};
 use chrono::{DateTime, Utc};
 
 use super::product_template::ProductTemplate;
 use super::tables::{FixedSurfaceType, GeneratingProcess, TimeUnit};
+use std::borrow::Cow;
 
-pub struct HorizontalAnalysisForecastTemplate {
-    data: Vec<u8>,
+pub struct HorizontalAnalysisForecastTemplate<'a> {
+    data: Cow<'a, [u8]>,
     discipline: u8,
 }
 
-impl Template for HorizontalAnalysisForecastTemplate {
+impl<'a> Template for HorizontalAnalysisForecastTemplate<'a> {
     fn data(&self) -> &[u8] {
         &self.data
     }
@@ -28,9 +31,19 @@ impl Template for HorizontalAnalysisForecastTemplate {
     }
 }
 
-impl HorizontalAnalysisForecastTemplate {
-    pub fn new(data: Vec<u8>, discipline: u8) -> Self {
-        HorizontalAnalysisForecastTemplate { data, discipline }
+impl<'a> HorizontalAnalysisForecastTemplate<'a> {
+    /// The template read from section data holding at least its 34 octets
+    pub fn new(data: impl Into<Cow<'a, [u8]>>, discipline: u8) -> Result<Self, GribberishError> {
+        let data = data.into();
+        check_section_length(4, &data, 34)?;
+        Ok(HorizontalAnalysisForecastTemplate { data, discipline })
+    }
+
+    pub fn into_owned(self) -> HorizontalAnalysisForecastTemplate<'static> {
+        HorizontalAnalysisForecastTemplate {
+            data: Cow::Owned(self.data.into_owned()),
+            discipline: self.discipline,
+        }
     }
 
     pub fn category_value(&self) -> u8 {
@@ -94,7 +107,7 @@ impl HorizontalAnalysisForecastTemplate {
     }
 }
 
-impl ProductTemplate for HorizontalAnalysisForecastTemplate {
+impl<'a> ProductTemplate for HorizontalAnalysisForecastTemplate<'a> {
     fn discipline(&self) -> u8 {
         self.discipline
     }
@@ -129,7 +142,9 @@ impl ProductTemplate for HorizontalAnalysisForecastTemplate {
 
     fn forecast_datetime(&self, reference_date: DateTime<Utc>) -> DateTime<Utc> {
         let offset_duration = self.time_interval_duration();
-        reference_date + offset_duration
+        reference_date
+            .checked_add_signed(offset_duration)
+            .unwrap_or(reference_date)
     }
 
     fn forecast_end_datetime(&self, _reference_date: DateTime<Utc>) -> Option<DateTime<Utc>> {
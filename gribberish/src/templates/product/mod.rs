@@ -1,12 +1,24 @@
+pub mod aerosol_average_accumulation_extreme_horizontal_template;
+pub mod aerosol_ensemble_horizontal_forecast_time_interval_template;
 pub mod average_accumulation_extreme_horizontal_analysis_template;
 pub mod derived_ensemble_horizontal_analysis_template;
 pub mod derived_ensemble_horizontal_forecast_time_interval_template;
+pub mod ensemble_reforecast_horizontal_analysis_template;
+pub mod ensemble_reforecast_horizontal_forecast_time_interval_template;
+pub mod generic_template;
 pub mod horizontal_analysis_template;
 pub mod parameters;
 pub mod product_template;
+pub mod spatially_processed_horizontal_analysis_template;
 pub mod tables;
+pub mod time_range;
 
+pub use aerosol_average_accumulation_extreme_horizontal_template::AerosolAverageAccumulationExtremeHorizontalForecastTemplate;
+pub use aerosol_ensemble_horizontal_forecast_time_interval_template::AerosolEnsembleHorizontalForecastTimeIntervalTemplate;
 pub use average_accumulation_extreme_horizontal_analysis_template::AverageAccumulationExtremeHorizontalAnalysisForecastTemplate;
 pub use derived_ensemble_horizontal_analysis_template::DerivedEnsembleHorizontalAnalysisForecastTemplate;
+pub use ensemble_reforecast_horizontal_analysis_template::EnsembleReforecastHorizontalAnalysisForecastTemplate;
+pub use ensemble_reforecast_horizontal_forecast_time_interval_template::EnsembleReforecastHorizontalForecastTimeIntervalTemplate;
+pub use generic_template::GenericProductTemplate;
 pub use horizontal_analysis_template::HorizontalAnalysisForecastTemplate;
-
+pub use spatially_processed_horizontal_analysis_template::SpatiallyProcessedHorizontalAnalysisForecastTemplate;
\ No newline at end of file
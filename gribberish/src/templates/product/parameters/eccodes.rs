@@ -0,0 +1,205 @@
+use crate::templates::product::tables::FixedSurfaceType;
+
+/// ecCodes `shortName` and `paramId` for a parameter
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EccodesParameter {
+    pub short_name: &'static str,
+    pub param_id: u32,
+}
+
+/// Restricts a mapping to a fixed surface, and optionally to a single level of that surface
+struct SurfaceMatch {
+    surface: FixedSurfaceType,
+    value: Option<f64>,
+}
+
+struct EccodesMapping {
+    discipline: u8,
+    category: u8,
+    parameter: u8,
+    surface: Option<SurfaceMatch>,
+    short_name: &'static str,
+    param_id: u32,
+}
+
+const fn mapping(
+    discipline: u8,
+    category: u8,
+    parameter: u8,
+    short_name: &'static str,
+    param_id: u32,
+) -> EccodesMapping {
+    EccodesMapping {
+        discipline,
+        category,
+        parameter,
+        surface: None,
+        short_name,
+        param_id,
+    }
+}
+
+const fn at_surface(
+    discipline: u8,
+    category: u8,
+    parameter: u8,
+    surface: FixedSurfaceType,
+    value: Option<f64>,
+    short_name: &'static str,
+    param_id: u32,
+) -> EccodesMapping {
+    EccodesMapping {
+        discipline,
+        category,
+        parameter,
+        surface: Some(SurfaceMatch { surface, value }),
+        short_name,
+        param_id,
+    }
+}
+
+// Surface specific entries come before the generic entry for the same parameter so they win
+const MAPPINGS: &[EccodesMapping] = &[
+    at_surface(0, 0, 0, FixedSurfaceType::SpecifiedHeightLevelAboveGround, Some(2.0), "2t", 167),
+    mapping(0, 0, 0, "t", 130),
+    at_surface(0, 0, 6, FixedSurfaceType::SpecifiedHeightLevelAboveGround, Some(2.0), "2d", 168),
+    at_surface(0, 0, 17, FixedSurfaceType::GroundOrWater, None, "skt", 235),
+    at_surface(0, 1, 0, FixedSurfaceType::SpecifiedHeightLevelAboveGround, Some(2.0), "2sh", 174096),
+    mapping(0, 1, 0, "q", 133),
+    at_surface(0, 1, 1, FixedSurfaceType::SpecifiedHeightLevelAboveGround, Some(2.0), "2r", 260242),
+    mapping(0, 1, 1, "r", 157),
+    mapping(0, 1, 3, "pwat", 3054),
+    mapping(0, 1, 8, "tp", 228228),
+    mapping(0, 1, 11, "sde", 3066),
+    mapping(0, 1, 60, "sd", 228141),
+    mapping(0, 1, 64, "tcwv", 137),
+    at_surface(0, 2, 0, FixedSurfaceType::SpecifiedHeightLevelAboveGround, Some(10.0), "10wdir", 260260),
+    mapping(0, 2, 0, "wdir", 3031),
+    at_surface(0, 2, 1, FixedSurfaceType::SpecifiedHeightLevelAboveGround, Some(10.0), "10si", 207),
+    mapping(0, 2, 1, "ws", 10),
+    at_surface(0, 2, 2, FixedSurfaceType::SpecifiedHeightLevelAboveGround, Some(10.0), "10u", 165),
+    at_surface(0, 2, 2, FixedSurfaceType::SpecifiedHeightLevelAboveGround, Some(100.0), "100u", 228246),
+    mapping(0, 2, 2, "u", 131),
+    at_surface(0, 2, 3, FixedSurfaceType::SpecifiedHeightLevelAboveGround, Some(10.0), "10v", 166),
+    at_surface(0, 2, 3, FixedSurfaceType::SpecifiedHeightLevelAboveGround, Some(100.0), "100v", 228247),
+    mapping(0, 2, 3, "v", 132),
+    mapping(0, 2, 8, "w", 135),
+    at_surface(0, 3, 0, FixedSurfaceType::GroundOrWater, None, "sp", 134),
+    at_surface(0, 3, 0, FixedSurfaceType::MeanSeaLevel, None, "msl", 151),
+    mapping(0, 3, 0, "pres", 54),
+    mapping(0, 3, 1, "prmsl", 260074),
+    mapping(0, 3, 4, "z", 129),
+    mapping(0, 3, 5, "gh", 156),
+    mapping(0, 6, 1, "tcc", 228164),
+    mapping(0, 7, 6, "cape", 59),
+    mapping(0, 19, 0, "vis", 3020),
+    mapping(2, 0, 0, "lsm", 172),
+    mapping(10, 0, 3, "swh", 140229),
+    mapping(10, 2, 0, "ci", 31),
+    at_surface(10, 3, 0, FixedSurfaceType::GroundOrWater, None, "sst", 34),
+];
+
+/// Looks up the ecCodes `shortName` and `paramId` for a parameter on the given first fixed
+/// surface. ecCodes gives some parameters a dedicated name on specific levels, such as `2t` for
+/// temperature 2 m above ground, so the surface is matched before falling back to the generic
+/// name.
+pub fn eccodes_parameter(
+    discipline: u8,
+    category: u8,
+    parameter: u8,
+    first_fixed_surface_type: &FixedSurfaceType,
+    first_fixed_surface_value: Option<f64>,
+) -> Option<EccodesParameter> {
+    MAPPINGS
+        .iter()
+        .filter(|m| m.discipline == discipline && m.category == category && m.parameter == parameter)
+        .find(|m| match &m.surface {
+            Some(surface_match) => {
+                &surface_match.surface == first_fixed_surface_type
+                    && match surface_match.value {
+                        Some(value) => first_fixed_surface_value
+                            .map(|v| (v - value).abs() < 1e-6)
+                            .unwrap_or(false),
+                        None => true,
+                    }
+            }
+            None => true,
+        })
+        .map(|m| EccodesParameter {
+            short_name: m.short_name,
+            param_id: m.param_id,
+        })
+}
+
+/// Parameter numbers of ECMWF's GRIB1 table 128 that ecCodes maps to a different `paramId` when
+/// they are encoded in GRIB2, paired with that `paramId`
+const TABLE_128_ALIASES: &[(u32, u32)] = &[(141, 228141), (164, 228164), (228, 228228)];
+
+/// Looks up the ecCodes `shortName` for a `paramId`
+pub fn eccodes_parameter_by_id(param_id: u32) -> Option<EccodesParameter> {
+    MAPPINGS
+        .iter()
+        .find(|m| m.param_id == param_id)
+        .map(|m| EccodesParameter {
+            short_name: m.short_name,
+            param_id: m.param_id,
+        })
+}
+
+/// Looks up the ecCodes `shortName` for a parameter number of ECMWF's GRIB1 table 128, which is
+/// also its `paramId` unless ecCodes gives the parameter another `paramId` in GRIB2
+pub fn eccodes_parameter_by_table_128(parameter: u32) -> Option<EccodesParameter> {
+    let param_id = TABLE_128_ALIASES
+        .iter()
+        .find(|(number, _)| *number == parameter)
+        .map(|(_, param_id)| *param_id)
+        .unwrap_or(parameter);
+
+    eccodes_parameter_by_id(param_id).map(|p| EccodesParameter {
+        short_name: p.short_name,
+        param_id: parameter,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn surface_specific_short_names() {
+        let two_metre = eccodes_parameter(
+            0,
+            0,
+            0,
+            &FixedSurfaceType::SpecifiedHeightLevelAboveGround,
+            Some(2.0),
+        )
+        .unwrap();
+        assert_eq!(two_metre.short_name, "2t");
+        assert_eq!(two_metre.param_id, 167);
+
+        let isobaric = eccodes_parameter(0, 0, 0, &FixedSurfaceType::IsobaricSurface, Some(50000.0));
+        assert_eq!(isobaric.unwrap().short_name, "t");
+
+        assert!(eccodes_parameter(0, 0, 6, &FixedSurfaceType::IsobaricSurface, Some(50000.0)).is_none());
+
+        let total_precipitation =
+            eccodes_parameter(0, 1, 8, &FixedSurfaceType::GroundOrWater, None).unwrap();
+        assert_eq!(total_precipitation.short_name, "tp");
+        assert_eq!(total_precipitation.param_id, 228228);
+    }
+
+    #[test]
+    fn table_128_parameters() {
+        let total_precipitation = eccodes_parameter_by_table_128(228).unwrap();
+        assert_eq!(total_precipitation.short_name, "tp");
+        assert_eq!(total_precipitation.param_id, 228);
+        assert_eq!(eccodes_parameter_by_table_128(164).unwrap().short_name, "tcc");
+        assert_eq!(eccodes_parameter_by_table_128(167).unwrap().short_name, "2t");
+        assert!(eccodes_parameter_by_table_128(255).is_none());
+
+        // Table 128 numbers are not GRIB2 parameter ids
+        assert!(eccodes_parameter_by_id(228).is_none());
+        assert_eq!(eccodes_parameter_by_id(228228).unwrap().short_name, "tp");
+    }
+}
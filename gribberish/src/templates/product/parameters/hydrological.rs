@@ -0,0 +1,43 @@
+use gribberish_macros::{
+    wmo_category_table, wmo_parameter_table, DisplayDescription, FromValue, ToParameter,
+};
+use gribberish_types::Parameter;
+
+wmo_parameter_table! {
+    table = "tables/GRIB2_CodeFlag_4_2_CodeTable_en.csv",
+    abbreviations = "tables/parameter_abbreviations.csv",
+    discipline = 1,
+    category = 0,
+    name = "HydrologyBasicProduct"
+}
+
+wmo_parameter_table! {
+    table = "tables/GRIB2_CodeFlag_4_2_CodeTable_en.csv",
+    abbreviations = "tables/parameter_abbreviations.csv",
+    discipline = 1,
+    category = 1,
+    name = "HydrologyProbabilities"
+}
+
+wmo_parameter_table! {
+    table = "tables/GRIB2_CodeFlag_4_2_CodeTable_en.csv",
+    abbreviations = "tables/parameter_abbreviations.csv",
+    discipline = 1,
+    category = 2,
+    name = "InlandWaterSedimentProperties"
+}
+
+pub fn hydrological_parameter(category: u8, parameter: u8) -> Option<Parameter> {
+    match category {
+        0 => Some(Parameter::from(HydrologyBasicProduct::from(parameter))),
+        1 => Some(Parameter::from(HydrologyProbabilities::from(parameter))),
+        2 => Some(Parameter::from(InlandWaterSedimentProperties::from(parameter))),
+        _ => None,
+    }
+}
+
+wmo_category_table! {
+    table = "tables/GRIB2_CodeFlag_4_1_CodeTable_en.csv",
+    discipline = 1,
+    name = "hydrological_category"
+}
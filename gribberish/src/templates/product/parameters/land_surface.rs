@@ -1,30 +1,52 @@
-use gribberish_macros::{DisplayDescription, FromValue, ToParameter};
+use gribberish_macros::{
+    wmo_category_table, wmo_parameter_table, DisplayDescription, FromValue, ToParameter,
+};
 use gribberish_types::Parameter;
 
-#[repr(u8)]
-#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
-pub enum VegetationProduct {
-    #[description = "land cover"]
-    #[abbrev = "lAND"]
-    #[unit = "proportion"]
-    LandCover = 0,
-    #[description = "soil temperature"]
-    #[abbrev = "TSOIL"]
-    #[unit = "K"]
-    SoilTemperature = 2,
-    Missing = 255,
+wmo_parameter_table! {
+    table = "tables/GRIB2_CodeFlag_4_2_CodeTable_en.csv",
+    abbreviations = "tables/parameter_abbreviations.csv",
+    discipline = 2,
+    category = 0,
+    name = "VegetationProduct"
+}
+
+wmo_parameter_table! {
+    table = "tables/GRIB2_CodeFlag_4_2_CodeTable_en.csv",
+    abbreviations = "tables/parameter_abbreviations.csv",
+    discipline = 2,
+    category = 3,
+    name = "SoilProduct"
+}
+
+wmo_parameter_table! {
+    table = "tables/GRIB2_CodeFlag_4_2_CodeTable_en.csv",
+    abbreviations = "tables/parameter_abbreviations.csv",
+    discipline = 2,
+    category = 4,
+    name = "FireWeatherProduct"
+}
+
+wmo_parameter_table! {
+    table = "tables/GRIB2_CodeFlag_4_2_CodeTable_en.csv",
+    abbreviations = "tables/parameter_abbreviations.csv",
+    discipline = 2,
+    category = 5,
+    name = "LandSurfaceProduct"
 }
 
 pub fn land_surface_parameter(category: u8, parameter: u8) -> Option<Parameter> {
     match category {
         0 => Some(Parameter::from(VegetationProduct::from(parameter))),
+        3 => Some(Parameter::from(SoilProduct::from(parameter))),
+        4 => Some(Parameter::from(FireWeatherProduct::from(parameter))),
+        5 => Some(Parameter::from(LandSurfaceProduct::from(parameter))),
         _ => None,
     }
 }
 
-pub fn land_surface_category(category: u8) -> &'static str {
-    match category {
-        0 => "vegetation/biomass",
-        _ => "other",
-    }
+wmo_category_table! {
+    table = "tables/GRIB2_CodeFlag_4_1_CodeTable_en.csv",
+    discipline = 2,
+    name = "land_surface_category"
 }
@@ -1,11 +1,17 @@
 use gribberish_types::Parameter;
 
-use self::{meteorological::{meteorological_category, meteorological_parameter}, land_surface::{land_surface_category, land_surface_parameter}, oceanographic::{oceanographic_category, oceanographic_parameter}, mrms::{multiradar_category, multiradar_parameter}};
+use self::{meteorological::{meteorological_category, meteorological_parameter}, hydrological::{hydrological_category, hydrological_parameter}, land_surface::{land_surface_category, land_surface_parameter}, oceanographic::{oceanographic_category, oceanographic_parameter}, mrms::{multiradar_category, multiradar_parameter}, ncep::{ncep_local_category, ncep_local_parameter}, space::{space_category, space_parameter}, space_weather::{space_weather_category, space_weather_parameter}};
 
+pub mod eccodes;
+pub mod hydrological;
 pub mod land_surface;
 pub mod meteorological;
 pub mod mrms;
+pub mod ncep;
 pub mod oceanographic;
+pub mod registry;
+pub mod space;
+pub mod space_weather;
 
 #[allow(dead_code)]
 pub trait ProductDiscipline {
@@ -17,7 +23,10 @@ pub trait ProductDiscipline {
 pub fn category(discipline: u8, category: u8) -> &'static str {
     match discipline {
         0 => meteorological_category(category),
+        1 => hydrological_category(category),
         2 => land_surface_category(category),
+        3 => space_category(category),
+        4 => space_weather_category(category),
         10 => oceanographic_category(category),
         209 => multiradar_category(category),
         _ => "",
@@ -27,9 +36,108 @@ pub fn category(discipline: u8, category: u8) -> &'static str {
 pub fn parameter(discipline: u8, category: u8, parameter: u8) -> Option<Parameter> {
     match discipline {
         0 => meteorological_parameter(category, parameter),
+        1 => hydrological_parameter(category, parameter),
         2 => land_surface_parameter(category, parameter),
+        3 => space_parameter(category, parameter),
+        4 => space_weather_parameter(category, parameter),
         10 => oceanographic_parameter(category, parameter),
         209 => multiradar_parameter(category, parameter),
         _ => None,
     }
 }
+
+/// Local table entries for the originating centre. These are only consulted when the message
+/// declares a local table version, otherwise the WMO tables are used as is.
+pub fn local_parameter(
+    centre: u16,
+    local_table_version: u8,
+    discipline: u8,
+    category: u8,
+    parameter: u8,
+) -> Option<Parameter> {
+    if local_table_version == 0 {
+        return None;
+    }
+
+    match centre {
+        7 => ncep_local_parameter(discipline, category, parameter),
+        _ => None,
+    }
+}
+
+pub fn local_category(
+    centre: u16,
+    local_table_version: u8,
+    discipline: u8,
+    category: u8,
+) -> Option<&'static str> {
+    if local_table_version == 0 {
+        return None;
+    }
+
+    match centre {
+        7 => ncep_local_category(discipline, category),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{local_parameter, parameter};
+
+    #[test]
+    fn ncep_local_parameters_only_for_ncep() {
+        let mslet = local_parameter(7, 1, 0, 3, 192).unwrap();
+        assert_eq!(mslet.abbrev, "MSLET");
+
+        assert!(local_parameter(7, 0, 0, 3, 192).is_none());
+        assert!(local_parameter(98, 1, 0, 3, 192).is_none());
+        assert!(local_parameter(7, 1, 0, 0, 0).is_none());
+        assert_eq!(parameter(0, 3, 192).unwrap().abbrev, "missing");
+    }
+
+    #[test]
+    fn thermodynamic_stability_indices() {
+        let cin = parameter(0, 7, 7).unwrap();
+        assert_eq!(cin.abbrev, "CIN");
+        assert_eq!(cin.unit, "Jkg-1");
+
+        assert_eq!(parameter(0, 7, 8).unwrap().abbrev, "HLCY");
+        assert_eq!(parameter(0, 7, 10).unwrap().abbrev, "LFTX");
+        assert_eq!(parameter(0, 7, 13).unwrap().abbrev, "SHWINX");
+    }
+
+    #[test]
+    fn generated_hydrological_parameters() {
+        let discharge = parameter(1, 0, 7).unwrap();
+        assert_eq!(discharge.abbrev, "DISRS");
+        assert_eq!(discharge.unit, "m3s-1");
+
+        let land_cover = parameter(1, 2, 8).unwrap();
+        assert_eq!(land_cover.abbrev, "LANDIL");
+        assert_eq!(land_cover.unit, "proportion");
+
+        assert_eq!(parameter(1, 0, 17).unwrap().abbrev, "missing");
+        assert_eq!(super::category(1, 2), "inland water and sediment properties");
+    }
+
+    #[test]
+    fn generated_wmo_parameters() {
+        let temperature = parameter(0, 0, 0).unwrap();
+        assert_eq!(temperature.abbrev, "TMP");
+        assert_eq!(temperature.standard_name.as_deref(), Some("air_temperature"));
+
+        let vapour_pressure = parameter(0, 1, 4).unwrap();
+        assert_eq!(vapour_pressure.abbrev, "VAPP");
+        assert_eq!(vapour_pressure.unit, "Pa");
+
+        assert_eq!(parameter(0, 2, 0).unwrap().unit, "degree true");
+        assert_eq!(parameter(2, 3, 18).unwrap().abbrev, "SOILTMP");
+        assert_eq!(parameter(3, 5, 3).unwrap().abbrev, "FDNSSTMP");
+        assert_eq!(parameter(10, 4, 3).unwrap().abbrev, "SALTY");
+        assert_eq!(parameter(10, 0, 1).unwrap().abbrev, "WVSP2");
+
+        assert_eq!(super::category(0, 17), "electrodynamics");
+        assert_eq!(super::category(10, 4), "sub-surface properties");
+    }
+}
@@ -0,0 +1,1265 @@
+use gribberish_macros::{DisplayDescription, FromValue, ToParameter};
+use gribberish_types::Parameter;
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalTemperatureProduct {
+    #[description = "snow phase change heat flux"]
+    #[abbrev = "SNOHF"]
+    #[unit = "Wm-2"]
+    SnowPhaseChangeHeatFlux = 192,
+    #[description = "temperature tendency by all radiation"]
+    #[abbrev = "TTRAD"]
+    #[unit = "Ks-1"]
+    TemperatureTendencyRadiation = 193,
+    #[description = "relative error variance"]
+    #[abbrev = "REV"]
+    #[unit = "-"]
+    RelativeErrorVariance = 194,
+    #[description = "large scale condensate heating rate"]
+    #[abbrev = "LRGHR"]
+    #[unit = "Ks-1"]
+    LargeScaleCondensateHeatingRate = 195,
+    #[description = "deep convective heating rate"]
+    #[abbrev = "CNVHR"]
+    #[unit = "Ks-1"]
+    DeepConvectiveHeatingRate = 196,
+    #[description = "total downward heat flux at surface"]
+    #[abbrev = "THFLX"]
+    #[unit = "Wm-2"]
+    TotalDownwardHeatFlux = 197,
+    #[description = "temperature tendency by all physics"]
+    #[abbrev = "TTDIA"]
+    #[unit = "Ks-1"]
+    TemperatureTendencyPhysics = 198,
+    #[description = "temperature tendency by non-radiation physics"]
+    #[abbrev = "TTPHY"]
+    #[unit = "Ks-1"]
+    TemperatureTendencyNonRadiationPhysics = 199,
+    #[description = "standard dev. of IR temp. over 1x1 deg. area"]
+    #[abbrev = "TSD1D"]
+    #[unit = "K"]
+    InfraredTemperatureStandardDeviation = 200,
+    #[description = "shallow convective heating rate"]
+    #[abbrev = "SHAHR"]
+    #[unit = "Ks-1"]
+    ShallowConvectiveHeatingRate = 201,
+    #[description = "vertical diffusion heating rate"]
+    #[abbrev = "VDFHR"]
+    #[unit = "Ks-1"]
+    VerticalDiffusionHeatingRate = 202,
+    #[description = "potential temperature at top of viscous sublayer"]
+    #[abbrev = "THZ0"]
+    #[unit = "K"]
+    PotentialTemperatureViscousSublayer = 203,
+    #[description = "tropical cyclone heat potential"]
+    #[abbrev = "TCHP"]
+    #[unit = "Jm-2K"]
+    TropicalCycloneHeatPotential = 204,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalMoistureProduct {
+    #[description = "categorical rain"]
+    #[abbrev = "CRAIN"]
+    #[unit = "BOOL"]
+    CategoricalRain = 192,
+    #[description = "categorical freezing rain"]
+    #[abbrev = "CFRZR"]
+    #[unit = "BOOL"]
+    CategoricalFreezingRain = 193,
+    #[description = "categorical ice pellets"]
+    #[abbrev = "CICEP"]
+    #[unit = "BOOL"]
+    CategoricalIcePellets = 194,
+    #[description = "categorical snow"]
+    #[abbrev = "CSNOW"]
+    #[unit = "BOOL"]
+    CategoricalSnow = 195,
+    #[description = "convective precipitation rate"]
+    #[abbrev = "CPRAT"]
+    #[unit = "kgm-2s-1"]
+    ConvectivePrecipitationRate = 196,
+    #[description = "horizontal moisture divergence"]
+    #[abbrev = "MCONV"]
+    #[unit = "kgkg-1s-1"]
+    HorizontalMoistureDivergence = 197,
+    #[description = "minimum relative humidity"]
+    #[abbrev = "MINRH"]
+    #[unit = "%"]
+    MinimumRelativeHumidity = 198,
+    #[description = "potential evaporation"]
+    #[abbrev = "PEVAP"]
+    #[unit = "kgm-2"]
+    PotentialEvaporation = 199,
+    #[description = "potential evaporation rate"]
+    #[abbrev = "PEVPR"]
+    #[unit = "Wm-2"]
+    PotentialEvaporationRate = 200,
+    #[description = "snow cover"]
+    #[abbrev = "SNOWC"]
+    #[unit = "%"]
+    SnowCover = 201,
+    #[description = "rain fraction of total liquid water"]
+    #[abbrev = "FRAIN"]
+    #[unit = "proportion"]
+    RainFraction = 202,
+    #[description = "rime factor"]
+    #[abbrev = "RIME"]
+    #[unit = "numeric"]
+    RimeFactor = 203,
+    #[description = "total column integrated rain"]
+    #[abbrev = "TCOLR"]
+    #[unit = "kgm-2"]
+    TotalColumnIntegratedRain = 204,
+    #[description = "total column integrated snow"]
+    #[abbrev = "TCOLS"]
+    #[unit = "kgm-2"]
+    TotalColumnIntegratedSnow = 205,
+    #[description = "total icing potential diagnostic"]
+    #[abbrev = "TIPD"]
+    #[unit = "-"]
+    TotalIcingPotentialDiagnostic = 206,
+    #[description = "number concentration for ice particles"]
+    #[abbrev = "NCIP"]
+    #[unit = "-"]
+    IceParticleNumberConcentration = 207,
+    #[description = "snow temperature"]
+    #[abbrev = "SNOT"]
+    #[unit = "K"]
+    SnowTemperature = 208,
+    #[description = "total column-integrated supercooled liquid water"]
+    #[abbrev = "TCLSW"]
+    #[unit = "kgm-2"]
+    TotalColumnSupercooledLiquidWater = 209,
+    #[description = "total column-integrated melting ice"]
+    #[abbrev = "TCOLM"]
+    #[unit = "kgm-2"]
+    TotalColumnMeltingIce = 210,
+    #[description = "evaporation - precipitation"]
+    #[abbrev = "EMNP"]
+    #[unit = "cmday-1"]
+    EvaporationMinusPrecipitation = 211,
+    #[description = "sublimation (evaporation from snow)"]
+    #[abbrev = "SBSNO"]
+    #[unit = "Wm-2"]
+    SublimationFromSnow = 212,
+    #[description = "deep convective moistening rate"]
+    #[abbrev = "CNVMR"]
+    #[unit = "kgkg-1s-1"]
+    DeepConvectiveMoisteningRate = 213,
+    #[description = "shallow convective moistening rate"]
+    #[abbrev = "SHAMR"]
+    #[unit = "kgkg-1s-1"]
+    ShallowConvectiveMoisteningRate = 214,
+    #[description = "vertical diffusion moistening rate"]
+    #[abbrev = "VDFMR"]
+    #[unit = "kgkg-1s-1"]
+    VerticalDiffusionMoisteningRate = 215,
+    #[description = "condensation pressure of parcel lifted from indicated surface"]
+    #[abbrev = "CONDP"]
+    #[unit = "Pa"]
+    CondensationPressure = 216,
+    #[description = "large scale moistening rate"]
+    #[abbrev = "LRGMR"]
+    #[unit = "kgkg-1s-1"]
+    LargeScaleMoisteningRate = 217,
+    #[description = "specific humidity at top of viscous sublayer"]
+    #[abbrev = "QZ0"]
+    #[unit = "kgkg-1"]
+    SpecificHumidityViscousSublayer = 218,
+    #[description = "maximum specific humidity at 2m"]
+    #[abbrev = "QMAX"]
+    #[unit = "kgkg-1"]
+    MaximumSpecificHumidity = 219,
+    #[description = "minimum specific humidity at 2m"]
+    #[abbrev = "QMIN"]
+    #[unit = "kgkg-1"]
+    MinimumSpecificHumidity = 220,
+    #[description = "liquid precipitation (rainfall)"]
+    #[abbrev = "ARAIN"]
+    #[unit = "kgm-2"]
+    LiquidPrecipitation = 221,
+    #[description = "snow temperature, depth-avg"]
+    #[abbrev = "SNOWT"]
+    #[unit = "K"]
+    SnowTemperatureDepthAverage = 222,
+    #[description = "total precipitation (nearest grid point)"]
+    #[abbrev = "APCPN"]
+    #[unit = "kgm-2"]
+    TotalPrecipitationNearestGridPoint = 223,
+    #[description = "convective precipitation (nearest grid point)"]
+    #[abbrev = "ACPCPN"]
+    #[unit = "kgm-2"]
+    ConvectivePrecipitationNearestGridPoint = 224,
+    #[description = "freezing rain"]
+    #[abbrev = "FRZR"]
+    #[unit = "kgm-2"]
+    FreezingRain = 225,
+    #[description = "predominant weather"]
+    #[abbrev = "PWTHER"]
+    #[unit = "numeric"]
+    PredominantWeather = 226,
+    #[description = "frozen rain"]
+    #[abbrev = "FROZR"]
+    #[unit = "kgm-2"]
+    FrozenRain = 227,
+    #[description = "total snow"]
+    #[abbrev = "TSNOW"]
+    #[unit = "kgm-2"]
+    TotalSnow = 241,
+    #[description = "relative humidity with respect to precipitable water"]
+    #[abbrev = "RHPW"]
+    #[unit = "%"]
+    RelativeHumidityPrecipitableWater = 242,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalMomentumProduct {
+    #[description = "vertical speed shear"]
+    #[abbrev = "VWSH"]
+    #[unit = "s-1"]
+    VerticalSpeedShear = 192,
+    #[description = "horizontal momentum flux"]
+    #[abbrev = "MFLX"]
+    #[unit = "Nm-2"]
+    HorizontalMomentumFlux = 193,
+    #[description = "u-component storm motion"]
+    #[abbrev = "USTM"]
+    #[unit = "ms-1"]
+    UComponentStormMotion = 194,
+    #[description = "v-component storm motion"]
+    #[abbrev = "VSTM"]
+    #[unit = "ms-1"]
+    VComponentStormMotion = 195,
+    #[description = "drag coefficient"]
+    #[abbrev = "CD"]
+    #[unit = "numeric"]
+    DragCoefficient = 196,
+    #[description = "frictional velocity"]
+    #[abbrev = "FRICV"]
+    #[unit = "ms-1"]
+    FrictionalVelocity = 197,
+    #[description = "vertical eddy diffusivity heat exchange"]
+    #[abbrev = "VEDH"]
+    #[unit = "m2s-1"]
+    VerticalEddyDiffusivityHeatExchange = 204,
+    #[description = "vertical diffusion zonal acceleration"]
+    #[abbrev = "VDFUA"]
+    #[unit = "ms-2"]
+    VerticalDiffusionZonalAcceleration = 208,
+    #[description = "vertical diffusion meridional acceleration"]
+    #[abbrev = "VDFVA"]
+    #[unit = "ms-2"]
+    VerticalDiffusionMeridionalAcceleration = 209,
+    #[description = "gravity wave drag zonal acceleration"]
+    #[abbrev = "GWDU"]
+    #[unit = "ms-2"]
+    GravityWaveDragZonalAcceleration = 210,
+    #[description = "gravity wave drag meridional acceleration"]
+    #[abbrev = "GWDV"]
+    #[unit = "ms-2"]
+    GravityWaveDragMeridionalAcceleration = 211,
+    #[description = "convective zonal momentum mixing acceleration"]
+    #[abbrev = "CNVU"]
+    #[unit = "ms-2"]
+    ConvectiveZonalMomentumMixing = 212,
+    #[description = "convective meridional momentum mixing acceleration"]
+    #[abbrev = "CNVV"]
+    #[unit = "ms-2"]
+    ConvectiveMeridionalMomentumMixing = 213,
+    #[description = "tendency of vertical velocity"]
+    #[abbrev = "WTEND"]
+    #[unit = "ms-2"]
+    VerticalVelocityTendency = 214,
+    #[description = "hourly maximum of upward vertical velocity"]
+    #[abbrev = "MAXUVV"]
+    #[unit = "ms-1"]
+    HourlyMaximumUpwardVerticalVelocity = 220,
+    #[description = "hourly maximum of downward vertical velocity"]
+    #[abbrev = "MAXDVV"]
+    #[unit = "ms-1"]
+    HourlyMaximumDownwardVerticalVelocity = 221,
+    #[description = "u component of hourly maximum 10m wind speed"]
+    #[abbrev = "MAXUW"]
+    #[unit = "ms-1"]
+    UComponentHourlyMaximumWindSpeed = 222,
+    #[description = "v component of hourly maximum 10m wind speed"]
+    #[abbrev = "MAXVW"]
+    #[unit = "ms-1"]
+    VComponentHourlyMaximumWindSpeed = 223,
+    #[description = "ventilation rate"]
+    #[abbrev = "VRATE"]
+    #[unit = "m2s-1"]
+    VentilationRate = 224,
+    #[description = "transport wind direction"]
+    #[abbrev = "TRWDIR"]
+    #[unit = "degrees"]
+    TransportWindDirection = 225,
+    #[description = "transport wind speed"]
+    #[abbrev = "TRWSPD"]
+    #[unit = "ms-1"]
+    TransportWindSpeed = 226,
+    #[description = "tropical wind direction"]
+    #[abbrev = "TPWDIR"]
+    #[unit = "degrees"]
+    TropicalWindDirection = 231,
+    #[description = "tropical wind speed"]
+    #[abbrev = "TPWSPD"]
+    #[unit = "ms-1"]
+    TropicalWindSpeed = 232,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalMassProduct {
+    #[description = "mslp (eta model reduction)"]
+    #[abbrev = "MSLET"]
+    #[unit = "pa"]
+    MSLP = 192,
+    #[description = "5-wave geopotential height"]
+    #[abbrev = "5WAVH"]
+    #[unit = "gpm"]
+    FiveWaveGeopotentialHeight = 193,
+    #[description = "zonal flux of gravity wave stress"]
+    #[abbrev = "U-GWD"]
+    #[unit = "Nm-2"]
+    ZonalFluxGravityWaveStress = 194,
+    #[description = "meridional flux of gravity wave stress"]
+    #[abbrev = "V-GWD"]
+    #[unit = "Nm-2"]
+    MeridionalFluxGravityWaveStress = 195,
+    #[description = "planetary boundary layer height"]
+    #[abbrev = "HPBL"]
+    #[unit = "m"]
+    PlanetaryBoundaryLayerHeight = 196,
+    #[description = "5-wave geopotential height anomaly"]
+    #[abbrev = "5WAVA"]
+    #[unit = "gpm"]
+    FiveWaveGeopotentialHeightAnomaly = 197,
+    #[description = "mslp (MAPS system reduction)"]
+    #[abbrev = "MSLMA"]
+    #[unit = "pa"]
+    MSLPMapsReduction = 198,
+    #[description = "3-hr pressure tendency (std. atmos. reduction)"]
+    #[abbrev = "TSLSA"]
+    #[unit = "pas-1"]
+    ThreeHourPressureTendency = 199,
+    #[description = "pressure of level from which parcel was lifted"]
+    #[abbrev = "PLPL"]
+    #[unit = "pa"]
+    ParcelLiftedPressure = 200,
+    #[description = "layer thickness"]
+    #[abbrev = "LAYTH"]
+    #[unit = "m"]
+    LayerThickness = 205,
+    #[description = "natural log of surface pressure"]
+    #[abbrev = "NLGSP"]
+    #[unit = "ln(kPa)"]
+    NaturalLogSurfacePressure = 206,
+    #[description = "convective updraft mass flux"]
+    #[abbrev = "CNVUMF"]
+    #[unit = "kgm-2s-1"]
+    ConvectiveUpdraftMassFlux = 207,
+    #[description = "convective downdraft mass flux"]
+    #[abbrev = "CNVDMF"]
+    #[unit = "kgm-2s-1"]
+    ConvectiveDowndraftMassFlux = 208,
+    #[description = "convective detrainment mass flux"]
+    #[abbrev = "CNVDEMF"]
+    #[unit = "kgm-2s-1"]
+    ConvectiveDetrainmentMassFlux = 209,
+    #[description = "geopotential height (nearest grid point)"]
+    #[abbrev = "HGTN"]
+    #[unit = "gpm"]
+    GeopotentialHeightNearestGridPoint = 211,
+    #[description = "pressure (nearest grid point)"]
+    #[abbrev = "PRESN"]
+    #[unit = "pa"]
+    PressureNearestGridPoint = 212,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalShortWaveRadiationProduct {
+    #[description = "downward short-wave radiation flux"]
+    #[abbrev = "DSWRF"]
+    #[unit = "Wm-2"]
+    DownwardShortWaveRadiationFlux = 192,
+    #[description = "upward short-wave radiation flux"]
+    #[abbrev = "USWRF"]
+    #[unit = "Wm-2"]
+    UpwardShortWaveRadiationFlux = 193,
+    #[description = "UV-B downward solar flux"]
+    #[abbrev = "DUVB"]
+    #[unit = "Wm-2"]
+    UVBDownwardSolarFlux = 194,
+    #[description = "clear sky UV-B downward solar flux"]
+    #[abbrev = "CDUVB"]
+    #[unit = "Wm-2"]
+    ClearSkyUVBDownwardSolarFlux = 195,
+    #[description = "clear sky downward solar flux"]
+    #[abbrev = "CSDSF"]
+    #[unit = "Wm-2"]
+    ClearSkyDownwardSolarFlux = 196,
+    #[description = "solar radiative heating rate"]
+    #[abbrev = "SWHR"]
+    #[unit = "Ks-1"]
+    SolarRadiativeHeatingRate = 197,
+    #[description = "clear sky upward solar flux"]
+    #[abbrev = "CSUSF"]
+    #[unit = "Wm-2"]
+    ClearSkyUpwardSolarFlux = 198,
+    #[description = "cloud forcing net solar flux"]
+    #[abbrev = "CFNSF"]
+    #[unit = "Wm-2"]
+    CloudForcingNetSolarFlux = 199,
+    #[description = "visible beam downward solar flux"]
+    #[abbrev = "VBDSF"]
+    #[unit = "Wm-2"]
+    VisibleBeamDownwardSolarFlux = 200,
+    #[description = "visible diffuse downward solar flux"]
+    #[abbrev = "VDDSF"]
+    #[unit = "Wm-2"]
+    VisibleDiffuseDownwardSolarFlux = 201,
+    #[description = "near IR beam downward solar flux"]
+    #[abbrev = "NBDSF"]
+    #[unit = "Wm-2"]
+    NearIRBeamDownwardSolarFlux = 202,
+    #[description = "near IR diffuse downward solar flux"]
+    #[abbrev = "NDDSF"]
+    #[unit = "Wm-2"]
+    NearIRDiffuseDownwardSolarFlux = 203,
+    #[description = "downward total radiation flux"]
+    #[abbrev = "DTRF"]
+    #[unit = "Wm-2"]
+    DownwardTotalRadiationFlux = 204,
+    #[description = "upward total radiation flux"]
+    #[abbrev = "UTRF"]
+    #[unit = "Wm-2"]
+    UpwardTotalRadiationFlux = 205,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalLongWaveRadiationProduct {
+    #[description = "downward long-wave radiation flux"]
+    #[abbrev = "DLWRF"]
+    #[unit = "Wm-2"]
+    DownwardLongWaveRadiationFlux = 192,
+    #[description = "upward long-wave radiation flux"]
+    #[abbrev = "ULWRF"]
+    #[unit = "Wm-2"]
+    UpwardLongWaveRadiationFlux = 193,
+    #[description = "long-wave radiative heating rate"]
+    #[abbrev = "LWHR"]
+    #[unit = "Ks-1"]
+    LongWaveRadiativeHeatingRate = 194,
+    #[description = "clear sky upward long wave flux"]
+    #[abbrev = "CSULF"]
+    #[unit = "Wm-2"]
+    ClearSkyUpwardLongWaveFlux = 195,
+    #[description = "clear sky downward long wave flux"]
+    #[abbrev = "CSDLF"]
+    #[unit = "Wm-2"]
+    ClearSkyDownwardLongWaveFlux = 196,
+    #[description = "cloud forcing net long wave flux"]
+    #[abbrev = "CFNLF"]
+    #[unit = "Wm-2"]
+    CloudForcingNetLongWaveFlux = 197,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalCloudProduct {
+    #[description = "non-convective cloud cover"]
+    #[abbrev = "CDLYR"]
+    #[unit = "%"]
+    NonConvectiveCloudCover = 192,
+    #[description = "cloud work function"]
+    #[abbrev = "CWORK"]
+    #[unit = "Jkg-1"]
+    CloudWorkFunction = 193,
+    #[description = "convective cloud efficiency"]
+    #[abbrev = "CUEFI"]
+    #[unit = "proportion"]
+    ConvectiveCloudEfficiency = 194,
+    #[description = "total condensate"]
+    #[abbrev = "TCOND"]
+    #[unit = "kgkg-1"]
+    TotalCondensate = 195,
+    #[description = "total column-integrated cloud water"]
+    #[abbrev = "TCOLW"]
+    #[unit = "kgm-2"]
+    TotalColumnIntegratedCloudWater = 196,
+    #[description = "total column-integrated cloud ice"]
+    #[abbrev = "TCOLI"]
+    #[unit = "kgm-2"]
+    TotalColumnIntegratedCloudIce = 197,
+    #[description = "total column-integrated condensate"]
+    #[abbrev = "TCOLC"]
+    #[unit = "kgm-2"]
+    TotalColumnIntegratedCondensate = 198,
+    #[description = "ice fraction of total condensate"]
+    #[abbrev = "FICE"]
+    #[unit = "proportion"]
+    IceFractionOfTotalCondensate = 199,
+    #[description = "convective cloud mass flux"]
+    #[abbrev = "MFLUX"]
+    #[unit = "pas-1"]
+    ConvectiveCloudMassFlux = 200,
+    #[description = "sunshine duration"]
+    #[abbrev = "SUNSD"]
+    #[unit = "s"]
+    SunshineDuration = 201,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalThermodynamicStabilityIndices {
+    #[description = "surface lifted index"]
+    #[abbrev = "LFTX"]
+    #[unit = "K"]
+    SurfaceLiftedIndex = 192,
+    #[description = "best (4 layer) lifted index"]
+    #[abbrev = "4LFTX"]
+    #[unit = "K"]
+    BestFourLayerLiftedIndex = 193,
+    #[description = "richardson number"]
+    #[abbrev = "RI"]
+    #[unit = "numeric"]
+    RichardsonNumber = 194,
+    #[description = "convective weather detection index"]
+    #[abbrev = "CWDI"]
+    #[unit = "-"]
+    ConvectiveWeatherDetectionIndex = 195,
+    #[description = "ultra violet index"]
+    #[abbrev = "UVI"]
+    #[unit = "Wm-2"]
+    UltraVioletIndex = 196,
+    #[description = "updraft helicity"]
+    #[abbrev = "UPHL"]
+    #[unit = "m2s-2"]
+    UpdraftHelicity = 197,
+    #[description = "leaf area index"]
+    #[abbrev = "LAI"]
+    #[unit = "numeric"]
+    LeafAreaIndex = 198,
+    #[description = "hourly maximum of updraft helicity"]
+    #[abbrev = "MXUPHL"]
+    #[unit = "m2s-2"]
+    HourlyMaximumUpdraftHelicity = 199,
+    #[description = "hourly minimum of updraft helicity"]
+    #[abbrev = "MNUPHL"]
+    #[unit = "m2s-2"]
+    HourlyMinimumUpdraftHelicity = 200,
+    #[description = "bourgouin negative energy layer"]
+    #[abbrev = "BNEGELAY"]
+    #[unit = "Jkg-1"]
+    BourgouinNegativeEnergyLayer = 201,
+    #[description = "bourgouin positive energy layer"]
+    #[abbrev = "BPOSELAY"]
+    #[unit = "Jkg-1"]
+    BourgouinPositiveEnergyLayer = 202,
+    #[description = "downdraft CAPE"]
+    #[abbrev = "DCAPE"]
+    #[unit = "Jkg-1"]
+    DowndraftCAPE = 203,
+    #[description = "effective storm relative helicity"]
+    #[abbrev = "EFHL"]
+    #[unit = "m2s-2"]
+    EffectiveStormRelativeHelicity = 204,
+    #[description = "enhanced stretching potential"]
+    #[abbrev = "ESP"]
+    #[unit = "numeric"]
+    EnhancedStretchingPotential = 205,
+    #[description = "critical angle"]
+    #[abbrev = "CANGLE"]
+    #[unit = "degrees"]
+    CriticalAngle = 206,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalAerosolProduct {
+    #[description = "particulate matter (coarse)"]
+    #[abbrev = "PMTC"]
+    #[unit = "ugm-3"]
+    ParticulateMatterCoarse = 192,
+    #[description = "particulate matter (fine)"]
+    #[abbrev = "PMTF"]
+    #[unit = "ugm-3"]
+    ParticulateMatterFine = 193,
+    #[description = "particulate matter (fine)"]
+    #[abbrev = "LPMTF"]
+    #[unit = "log10(ugm-3)"]
+    LogParticulateMatterFine = 194,
+    #[description = "integrated column particulate matter (fine)"]
+    #[abbrev = "LIPMF"]
+    #[unit = "log10(ugm-3)"]
+    IntegratedColumnParticulateMatterFine = 195,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalTraceGasProduct {
+    #[description = "ozone concentration"]
+    #[abbrev = "OZCON"]
+    #[unit = "ppb"]
+    OzoneConcentration = 192,
+    #[description = "categorical ozone concentration"]
+    #[abbrev = "OZCAT"]
+    #[unit = "-"]
+    CategoricalOzoneConcentration = 193,
+    #[description = "ozone vertical diffusion"]
+    #[abbrev = "VDFOZ"]
+    #[unit = "kgkg-1s-1"]
+    OzoneVerticalDiffusion = 194,
+    #[description = "ozone production"]
+    #[abbrev = "POZ"]
+    #[unit = "kgkg-1s-1"]
+    OzoneProduction = 195,
+    #[description = "ozone tendency"]
+    #[abbrev = "TOZ"]
+    #[unit = "kgkg-1s-1"]
+    OzoneTendency = 196,
+    #[description = "ozone production from temperature term"]
+    #[abbrev = "POZT"]
+    #[unit = "kgkg-1s-1"]
+    OzoneProductionTemperatureTerm = 197,
+    #[description = "ozone production from col ozone term"]
+    #[abbrev = "POZO"]
+    #[unit = "kgkg-1s-1"]
+    OzoneProductionColumnOzoneTerm = 198,
+    #[description = "ozone daily max from 1-hour average"]
+    #[abbrev = "OZMAX1"]
+    #[unit = "ppbV"]
+    OzoneDailyMaxOneHourAverage = 199,
+    #[description = "ozone daily max from 8-hour average"]
+    #[abbrev = "OZMAX8"]
+    #[unit = "ppbV"]
+    OzoneDailyMaxEightHourAverage = 200,
+    #[description = "PM 2.5 daily max from 1-hour average"]
+    #[abbrev = "PDMAX1"]
+    #[unit = "ugm-3"]
+    PM25DailyMaxOneHourAverage = 201,
+    #[description = "PM 2.5 daily max from 24-hour average"]
+    #[abbrev = "PDMAX24"]
+    #[unit = "ugm-3"]
+    PM25DailyMaxTwentyFourHourAverage = 202,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalForecastRadarImagery {
+    #[description = "equivalent radar reflectivity factor for rain"]
+    #[abbrev = "REFZR"]
+    #[unit = "mm6m-3"]
+    EquivalentReflectivityRain = 192,
+    #[description = "equivalent radar reflectivity factor for snow"]
+    #[abbrev = "REFZI"]
+    #[unit = "mm6m-3"]
+    EquivalentReflectivitySnow = 193,
+    #[description = "equivalent radar reflectivity factor for parameterized convection"]
+    #[abbrev = "REFZC"]
+    #[unit = "mm6m-3"]
+    EquivalentReflectivityConvection = 194,
+    #[description = "reflectivity"]
+    #[abbrev = "REFD"]
+    #[unit = "dB"]
+    Reflectivity = 195,
+    #[description = "composite reflectivity"]
+    #[abbrev = "REFC"]
+    #[unit = "dB"]
+    CompositeReflectivity = 196,
+    #[description = "echo top"]
+    #[abbrev = "RETOP"]
+    #[unit = "m"]
+    EchoTop = 197,
+    #[description = "hourly maximum of simulated reflectivity at 1 km AGL"]
+    #[abbrev = "MAXREF"]
+    #[unit = "dB"]
+    HourlyMaximumReflectivity = 198,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalElectromagnetics {
+    #[description = "lightning"]
+    #[abbrev = "LTNG"]
+    #[unit = "nondim"]
+    Lightning = 192,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalPhysicalAtmosphericProperties {
+    #[description = "maximum snow albedo"]
+    #[abbrev = "MXSALB"]
+    #[unit = "%"]
+    MaximumSnowAlbedo = 192,
+    #[description = "snow-free albedo"]
+    #[abbrev = "SNFALB"]
+    #[unit = "%"]
+    SnowFreeAlbedo = 193,
+    #[description = "slight risk convective outlook"]
+    #[abbrev = "SRCONO"]
+    #[unit = "categorical"]
+    SlightRiskConvectiveOutlook = 194,
+    #[description = "moderate risk convective outlook"]
+    #[abbrev = "MRCONO"]
+    #[unit = "categorical"]
+    ModerateRiskConvectiveOutlook = 195,
+    #[description = "high risk convective outlook"]
+    #[abbrev = "HRCONO"]
+    #[unit = "categorical"]
+    HighRiskConvectiveOutlook = 196,
+    #[description = "tornado probability"]
+    #[abbrev = "TORPROB"]
+    #[unit = "%"]
+    TornadoProbability = 197,
+    #[description = "hail probability"]
+    #[abbrev = "HAILPROB"]
+    #[unit = "%"]
+    HailProbability = 198,
+    #[description = "wind probability"]
+    #[abbrev = "WINDPROB"]
+    #[unit = "%"]
+    WindProbability = 199,
+    #[description = "significant tornado probability"]
+    #[abbrev = "STORPROB"]
+    #[unit = "%"]
+    SignificantTornadoProbability = 200,
+    #[description = "significant hail probability"]
+    #[abbrev = "SHAILPRO"]
+    #[unit = "%"]
+    SignificantHailProbability = 201,
+    #[description = "significant wind probability"]
+    #[abbrev = "SWINDPRO"]
+    #[unit = "%"]
+    SignificantWindProbability = 202,
+    #[description = "categorical thunderstorm"]
+    #[abbrev = "TSTMC"]
+    #[unit = "BOOL"]
+    CategoricalThunderstorm = 203,
+    #[description = "number of mixed layers next to surface"]
+    #[abbrev = "MIXLY"]
+    #[unit = "numeric"]
+    NumberOfMixedLayers = 204,
+    #[description = "flight category"]
+    #[abbrev = "FLGHT"]
+    #[unit = "-"]
+    FlightCategory = 205,
+    #[description = "confidence - ceiling"]
+    #[abbrev = "CICEL"]
+    #[unit = "-"]
+    ConfidenceCeiling = 206,
+    #[description = "confidence - visibility"]
+    #[abbrev = "CIVIS"]
+    #[unit = "-"]
+    ConfidenceVisibility = 207,
+    #[description = "confidence - flight category"]
+    #[abbrev = "CIFLT"]
+    #[unit = "-"]
+    ConfidenceFlightCategory = 208,
+    #[description = "low-level aviation interest"]
+    #[abbrev = "LAVNI"]
+    #[unit = "-"]
+    LowLevelAviationInterest = 209,
+    #[description = "high-level aviation interest"]
+    #[abbrev = "HAVNI"]
+    #[unit = "-"]
+    HighLevelAviationInterest = 210,
+    #[description = "visible, black sky albedo"]
+    #[abbrev = "SBSALB"]
+    #[unit = "%"]
+    VisibleBlackSkyAlbedo = 211,
+    #[description = "visible, white sky albedo"]
+    #[abbrev = "SWSALB"]
+    #[unit = "%"]
+    VisibleWhiteSkyAlbedo = 212,
+    #[description = "near IR, black sky albedo"]
+    #[abbrev = "NBSALB"]
+    #[unit = "%"]
+    NearIRBlackSkyAlbedo = 213,
+    #[description = "near IR, white sky albedo"]
+    #[abbrev = "NWSALB"]
+    #[unit = "%"]
+    NearIRWhiteSkyAlbedo = 214,
+    #[description = "total probability of severe thunderstorms (days 2,3)"]
+    #[abbrev = "PRSVR"]
+    #[unit = "%"]
+    SevereThunderstormProbability = 215,
+    #[description = "total probability of extreme severe thunderstorms (days 2,3)"]
+    #[abbrev = "PRSIGSVR"]
+    #[unit = "%"]
+    ExtremeSevereThunderstormProbability = 216,
+    #[description = "supercooled large droplet (SLD) icing"]
+    #[abbrev = "SIPD"]
+    #[unit = "-"]
+    SupercooledLargeDropletIcing = 217,
+    #[description = "radiative emissivity"]
+    #[abbrev = "EPSR"]
+    #[unit = "-"]
+    RadiativeEmissivity = 218,
+    #[description = "turbulence potential forecast index"]
+    #[abbrev = "TPFI"]
+    #[unit = "-"]
+    TurbulencePotentialForecastIndex = 219,
+    #[description = "categorical severe thunderstorm"]
+    #[abbrev = "SVRTS"]
+    #[unit = "-"]
+    CategoricalSevereThunderstorm = 220,
+    #[description = "probability of convection"]
+    #[abbrev = "PROCON"]
+    #[unit = "%"]
+    ProbabilityOfConvection = 221,
+    #[description = "convection potential"]
+    #[abbrev = "CONVP"]
+    #[unit = "-"]
+    ConvectionPotential = 222,
+    #[description = "volcanic ash forecast transport and dispersion"]
+    #[abbrev = "VAFTD"]
+    #[unit = "log10(kgm-3)"]
+    VolcanicAshForecast = 232,
+    #[description = "icing probability"]
+    #[abbrev = "ICPRB"]
+    #[unit = "-"]
+    IcingProbability = 233,
+    #[description = "icing severity"]
+    #[abbrev = "ICSEV"]
+    #[unit = "-"]
+    IcingSeverity = 234,
+    #[description = "joint fire weather probability"]
+    #[abbrev = "JFWPRB"]
+    #[unit = "%"]
+    JointFireWeatherProbability = 235,
+    #[description = "snow level"]
+    #[abbrev = "SNOWLVL"]
+    #[unit = "m"]
+    SnowLevel = 236,
+    #[description = "dry thunderstorm probability"]
+    #[abbrev = "DRYTPROB"]
+    #[unit = "%"]
+    DryThunderstormProbability = 237,
+    #[description = "ellrod index"]
+    #[abbrev = "ELLINX"]
+    #[unit = "-"]
+    EllrodIndex = 238,
+    #[description = "craven-wiedenfeld aggregate severe parameter"]
+    #[abbrev = "CWASP"]
+    #[unit = "numeric"]
+    CravenWiedenfeldAggregateSevereParameter = 239,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalMiscellaneousProduct {
+    #[description = "latitude (-90 to 90)"]
+    #[abbrev = "NLAT"]
+    #[unit = "deg"]
+    Latitude = 192,
+    #[description = "east longitude (0 to 360)"]
+    #[abbrev = "ELON"]
+    #[unit = "deg"]
+    EastLongitude = 193,
+    #[description = "model layer number (from bottom up)"]
+    #[abbrev = "MLYNO"]
+    #[unit = "numeric"]
+    ModelLayerNumber = 196,
+    #[description = "latitude (nearest neighbor) (-90 to 90)"]
+    #[abbrev = "NLATN"]
+    #[unit = "deg"]
+    LatitudeNearestNeighbor = 197,
+    #[description = "east longitude (nearest neighbor) (0 to 360)"]
+    #[abbrev = "ELONN"]
+    #[unit = "deg"]
+    EastLongitudeNearestNeighbor = 198,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalCovarianceProduct {
+    #[description = "covariance between zonal and meridional components of the wind"]
+    #[abbrev = "COVMZ"]
+    #[unit = "m2s-2"]
+    CovarianceZonalMeridionalWind = 1,
+    #[description = "covariance between zonal component of the wind and temperature"]
+    #[abbrev = "COVTZ"]
+    #[unit = "Kms-1"]
+    CovarianceZonalWindTemperature = 2,
+    #[description = "covariance between meridional component of the wind and temperature"]
+    #[abbrev = "COVTM"]
+    #[unit = "Kms-1"]
+    CovarianceMeridionalWindTemperature = 3,
+    #[description = "covariance between temperature and vertical component of the wind"]
+    #[abbrev = "COVTW"]
+    #[unit = "Kms-1"]
+    CovarianceVerticalVelocityTemperature = 4,
+    #[description = "covariance between zonal and zonal components of the wind"]
+    #[abbrev = "COVZZ"]
+    #[unit = "m2s-2"]
+    CovarianceZonalZonalWind = 5,
+    #[description = "covariance between meridional and meridional components of the wind"]
+    #[abbrev = "COVMM"]
+    #[unit = "m2s-2"]
+    CovarianceMeridionalMeridionalWind = 6,
+    #[description = "covariance between specific humidity and zonal components of the wind"]
+    #[abbrev = "COVQZ"]
+    #[unit = "kgkg-1ms-1"]
+    CovarianceSpecificHumidityZonalWind = 7,
+    #[description = "covariance between specific humidity and meridional components of the wind"]
+    #[abbrev = "COVQM"]
+    #[unit = "kgkg-1ms-1"]
+    CovarianceSpecificHumidityMeridionalWind = 8,
+    #[description = "covariance between temperature and vertical components of the wind"]
+    #[abbrev = "COVTVV"]
+    #[unit = "KPas-1"]
+    CovarianceTemperatureVerticalVelocity = 9,
+    #[description = "covariance between specific humidity and vertical components of the wind"]
+    #[abbrev = "COVQVV"]
+    #[unit = "kgkg-1Pas-1"]
+    CovarianceSpecificHumidityVerticalVelocity = 10,
+    #[description = "covariance between surface pressure and surface pressure"]
+    #[abbrev = "COVPSPS"]
+    #[unit = "Pa2"]
+    CovarianceSurfacePressure = 11,
+    #[description = "covariance between specific humidity and specific humidity"]
+    #[abbrev = "COVQQ"]
+    #[unit = "kg2kg-2"]
+    CovarianceSpecificHumidity = 12,
+    #[description = "covariance between vertical and vertical components of the wind"]
+    #[abbrev = "COVVVVV"]
+    #[unit = "Pa2s-2"]
+    CovarianceVerticalVelocity = 13,
+    #[description = "covariance between temperature and temperature"]
+    #[abbrev = "COVTT"]
+    #[unit = "K2"]
+    CovarianceTemperature = 14,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalVegetationProduct {
+    #[description = "volumetric soil moisture content"]
+    #[abbrev = "SOILW"]
+    #[unit = "fraction"]
+    VolumetricSoilMoistureContent = 192,
+    #[description = "ground heat flux"]
+    #[abbrev = "GFLUX"]
+    #[unit = "Wm-2"]
+    GroundHeatFlux = 193,
+    #[description = "moisture availability"]
+    #[abbrev = "MSTAV"]
+    #[unit = "%"]
+    MoistureAvailability = 194,
+    #[description = "exchange coefficient"]
+    #[abbrev = "SFEXC"]
+    #[unit = "kgm-2s-1"]
+    ExchangeCoefficient = 195,
+    #[description = "plant canopy surface water"]
+    #[abbrev = "CNWAT"]
+    #[unit = "kgm-2"]
+    PlantCanopySurfaceWater = 196,
+    #[description = "blackadar's mixing length scale"]
+    #[abbrev = "BMIXL"]
+    #[unit = "m"]
+    BlackadarMixingLengthScale = 197,
+    #[description = "vegetation type"]
+    #[abbrev = "VGTYP"]
+    #[unit = "integer (0-13)"]
+    VegetationType = 198,
+    #[description = "canopy conductance"]
+    #[abbrev = "CCOND"]
+    #[unit = "ms-1"]
+    CanopyConductance = 199,
+    #[description = "minimal stomatal resistance"]
+    #[abbrev = "RSMIN"]
+    #[unit = "sm-1"]
+    MinimalStomatalResistance = 200,
+    #[description = "wilting point"]
+    #[abbrev = "WILT"]
+    #[unit = "fraction"]
+    WiltingPoint = 201,
+    #[description = "ice-free water surface"]
+    #[abbrev = "ICWAT"]
+    #[unit = "%"]
+    IceFreeWaterSurface = 207,
+    #[description = "surface exchange coefficients for T and Q divided by delta z"]
+    #[abbrev = "AKHS"]
+    #[unit = "ms-1"]
+    SurfaceExchangeCoefficientsTQ = 208,
+    #[description = "surface exchange coefficients for U and V divided by delta z"]
+    #[abbrev = "AKMS"]
+    #[unit = "ms-1"]
+    SurfaceExchangeCoefficientsUV = 209,
+    #[description = "vegetation canopy temperature"]
+    #[abbrev = "VEGT"]
+    #[unit = "K"]
+    VegetationCanopyTemperature = 210,
+    #[description = "surface water storage"]
+    #[abbrev = "SSTOR"]
+    #[unit = "kgm-2"]
+    SurfaceWaterStorage = 211,
+    #[description = "liquid soil moisture content (non-frozen)"]
+    #[abbrev = "LSOIL"]
+    #[unit = "kgm-2"]
+    LiquidSoilMoistureContent = 212,
+    #[description = "open water evaporation (standing water)"]
+    #[abbrev = "EWATR"]
+    #[unit = "Wm-2"]
+    OpenWaterEvaporation = 213,
+    #[description = "groundwater recharge"]
+    #[abbrev = "GWREC"]
+    #[unit = "kgm-2"]
+    GroundwaterRecharge = 214,
+    #[description = "flood plain recharge"]
+    #[abbrev = "QREC"]
+    #[unit = "kgm-2"]
+    FloodPlainRecharge = 215,
+    #[description = "roughness length for heat"]
+    #[abbrev = "SFCRH"]
+    #[unit = "m"]
+    RoughnessLengthForHeat = 216,
+    #[description = "normalized difference vegetation index"]
+    #[abbrev = "NDVI"]
+    #[unit = "-"]
+    NormalizedDifferenceVegetationIndex = 217,
+    #[description = "land-sea coverage (nearest neighbor)"]
+    #[abbrev = "LANDN"]
+    #[unit = "-"]
+    LandSeaCoverageNearestNeighbor = 218,
+    #[description = "asymptotic mixing length scale"]
+    #[abbrev = "AMIXL"]
+    #[unit = "m"]
+    AsymptoticMixingLengthScale = 219,
+    #[description = "aerodynamic conductance"]
+    #[abbrev = "ACOND"]
+    #[unit = "ms-1"]
+    AerodynamicConductance = 228,
+    #[description = "canopy water evaporation"]
+    #[abbrev = "EVCW"]
+    #[unit = "Wm-2"]
+    CanopyWaterEvaporation = 229,
+    #[description = "transpiration"]
+    #[abbrev = "TRANS"]
+    #[unit = "Wm-2"]
+    Transpiration = 230,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalSoilProduct {
+    #[description = "liquid volumetric soil moisture (non frozen)"]
+    #[abbrev = "SOILL"]
+    #[unit = "proportion"]
+    LiquidVolumetricSoilMoisture = 192,
+    #[description = "number of soil layers in root zone"]
+    #[abbrev = "RLYRS"]
+    #[unit = "non-dim"]
+    NumberOfSoilLayersInRootZone = 193,
+    #[description = "surface slope type"]
+    #[abbrev = "SLTYP"]
+    #[unit = "index"]
+    SurfaceSlopeType = 194,
+    #[description = "transpiration stress-onset (soil moisture)"]
+    #[abbrev = "SMREF"]
+    #[unit = "proportion"]
+    TranspirationStressOnset = 195,
+    #[description = "direct evaporation cease (soil moisture)"]
+    #[abbrev = "SMDRY"]
+    #[unit = "proportion"]
+    DirectEvaporationCease = 196,
+    #[description = "soil porosity"]
+    #[abbrev = "POROS"]
+    #[unit = "proportion"]
+    SoilPorosity = 197,
+    #[description = "direct evaporation from bare soil"]
+    #[abbrev = "EVBS"]
+    #[unit = "Wm-2"]
+    DirectEvaporationFromBareSoil = 198,
+    #[description = "land surface precipitation accumulation"]
+    #[abbrev = "LSPA"]
+    #[unit = "kgm-2"]
+    LandSurfacePrecipitationAccumulation = 199,
+    #[description = "bare soil surface skin temperature"]
+    #[abbrev = "BARET"]
+    #[unit = "K"]
+    BareSoilSurfaceSkinTemperature = 200,
+    #[description = "average surface skin temperature"]
+    #[abbrev = "AVSFT"]
+    #[unit = "K"]
+    AverageSurfaceSkinTemperature = 201,
+    #[description = "effective radiative skin temperature"]
+    #[abbrev = "RADT"]
+    #[unit = "K"]
+    EffectiveRadiativeSkinTemperature = 202,
+    #[description = "field capacity"]
+    #[abbrev = "FLDCP"]
+    #[unit = "fraction"]
+    FieldCapacity = 203,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalWavesProduct {
+    #[description = "wave steepness"]
+    #[abbrev = "WSTP"]
+    #[unit = "porportion"]
+    WaveSteepness = 192,
+    #[description = "wave length"]
+    #[abbrev = "WLENG"]
+    #[unit = "-"]
+    WaveLength = 193,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LocalSurfacePropertiesProduct {
+    #[description = "total water level accounting for tide, wind and waves"]
+    #[abbrev = "TWLWAV"]
+    #[unit = "m"]
+    TotalWaterLevelTideWindWaves = 205,
+    #[description = "total water level increase due to waves"]
+    #[abbrev = "RUNUP"]
+    #[unit = "m"]
+    TotalWaterLevelIncreaseWaves = 206,
+    #[description = "mean water level increase due to waves"]
+    #[abbrev = "SETUP"]
+    #[unit = "m"]
+    MeanWaterLevelIncreaseWaves = 207,
+    #[description = "time varying water level increase due to waves"]
+    #[abbrev = "SWASH"]
+    #[unit = "m"]
+    TimeVaryingWaterLevelIncreaseWaves = 208,
+    #[description = "total water level above dune toe"]
+    #[abbrev = "TWLDT"]
+    #[unit = "m"]
+    TotalWaterLevelAboveDuneToe = 209,
+    #[description = "total water level above dune crest"]
+    #[abbrev = "TWLDC"]
+    #[unit = "m"]
+    TotalWaterLevelAboveDuneCrest = 210,
+    #[description = "erosion occurrence probability"]
+    #[abbrev = "EROSNP"]
+    #[unit = "%"]
+    ErosionOccuranceProbability = 252,
+    #[description = "overwash occurrence probability"]
+    #[abbrev = "OWASHP"]
+    #[unit = "%"]
+    OverwashOccuranceProbability = 253,
+    Missing = 255,
+}
+
+/// Only entries that exist in the local table are returned so that lookups fall back to the
+/// WMO tables for everything else
+fn known_entry<T: PartialEq + Into<Parameter>>(value: T, missing: T) -> Option<Parameter> {
+    if value == missing {
+        None
+    } else {
+        Some(value.into())
+    }
+}
+
+pub fn ncep_local_parameter(discipline: u8, category: u8, parameter: u8) -> Option<Parameter> {
+    match (discipline, category) {
+        (0, 0) => known_entry(
+            LocalTemperatureProduct::from(parameter),
+            LocalTemperatureProduct::Missing,
+        ),
+        (0, 1) => known_entry(
+            LocalMoistureProduct::from(parameter),
+            LocalMoistureProduct::Missing,
+        ),
+        (0, 2) => known_entry(
+            LocalMomentumProduct::from(parameter),
+            LocalMomentumProduct::Missing,
+        ),
+        (0, 3) => known_entry(LocalMassProduct::from(parameter), LocalMassProduct::Missing),
+        (0, 4) => known_entry(
+            LocalShortWaveRadiationProduct::from(parameter),
+            LocalShortWaveRadiationProduct::Missing,
+        ),
+        (0, 5) => known_entry(
+            LocalLongWaveRadiationProduct::from(parameter),
+            LocalLongWaveRadiationProduct::Missing,
+        ),
+        (0, 6) => known_entry(
+            LocalCloudProduct::from(parameter),
+            LocalCloudProduct::Missing,
+        ),
+        (0, 7) => known_entry(
+            LocalThermodynamicStabilityIndices::from(parameter),
+            LocalThermodynamicStabilityIndices::Missing,
+        ),
+        (0, 13) => known_entry(
+            LocalAerosolProduct::from(parameter),
+            LocalAerosolProduct::Missing,
+        ),
+        (0, 14) => known_entry(
+            LocalTraceGasProduct::from(parameter),
+            LocalTraceGasProduct::Missing,
+        ),
+        (0, 16) => known_entry(
+            LocalForecastRadarImagery::from(parameter),
+            LocalForecastRadarImagery::Missing,
+        ),
+        (0, 17) => known_entry(
+            LocalElectromagnetics::from(parameter),
+            LocalElectromagnetics::Missing,
+        ),
+        (0, 19) => known_entry(
+            LocalPhysicalAtmosphericProperties::from(parameter),
+            LocalPhysicalAtmosphericProperties::Missing,
+        ),
+        (0, 191) => known_entry(
+            LocalMiscellaneousProduct::from(parameter),
+            LocalMiscellaneousProduct::Missing,
+        ),
+        (0, 192) => known_entry(
+            LocalCovarianceProduct::from(parameter),
+            LocalCovarianceProduct::Missing,
+        ),
+        (2, 0) => known_entry(
+            LocalVegetationProduct::from(parameter),
+            LocalVegetationProduct::Missing,
+        ),
+        (2, 3) => known_entry(LocalSoilProduct::from(parameter), LocalSoilProduct::Missing),
+        (10, 0) => known_entry(
+            LocalWavesProduct::from(parameter),
+            LocalWavesProduct::Missing,
+        ),
+        (10, 3) => known_entry(
+            LocalSurfacePropertiesProduct::from(parameter),
+            LocalSurfacePropertiesProduct::Missing,
+        ),
+        _ => None,
+    }
+}
+
+pub fn ncep_local_category(discipline: u8, category: u8) -> Option<&'static str> {
+    match (discipline, category) {
+        (0, 192) => Some("covariance"),
+        _ => None,
+    }
+}
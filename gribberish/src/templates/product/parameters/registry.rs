@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use gribberish_types::Parameter;
+
+/// Identifies a parameter definition registered at runtime. Leaving the sub-centre or local table
+/// version unset matches any value, while set values take precedence over unset ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParameterKey {
+    pub centre: u16,
+    pub sub_centre: Option<u16>,
+    pub discipline: u8,
+    pub category: u8,
+    pub parameter: u8,
+    pub local_table_version: Option<u8>,
+}
+
+impl ParameterKey {
+    pub fn new(centre: u16, discipline: u8, category: u8, parameter: u8) -> Self {
+        ParameterKey {
+            centre,
+            sub_centre: None,
+            discipline,
+            category,
+            parameter,
+            local_table_version: None,
+        }
+    }
+
+    pub fn with_sub_centre(mut self, sub_centre: u16) -> Self {
+        self.sub_centre = Some(sub_centre);
+        self
+    }
+
+    pub fn with_local_table_version(mut self, local_table_version: u8) -> Self {
+        self.local_table_version = Some(local_table_version);
+        self
+    }
+}
+
+/// Identifies a parameter category registered at runtime, matched the same way as `ParameterKey`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CategoryKey {
+    pub centre: u16,
+    pub sub_centre: Option<u16>,
+    pub discipline: u8,
+    pub category: u8,
+    pub local_table_version: Option<u8>,
+}
+
+impl CategoryKey {
+    pub fn new(centre: u16, discipline: u8, category: u8) -> Self {
+        CategoryKey {
+            centre,
+            sub_centre: None,
+            discipline,
+            category,
+            local_table_version: None,
+        }
+    }
+
+    pub fn with_sub_centre(mut self, sub_centre: u16) -> Self {
+        self.sub_centre = Some(sub_centre);
+        self
+    }
+
+    pub fn with_local_table_version(mut self, local_table_version: u8) -> Self {
+        self.local_table_version = Some(local_table_version);
+        self
+    }
+}
+
+fn registry() -> &'static RwLock<HashMap<ParameterKey, Parameter>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<ParameterKey, Parameter>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+fn category_registry() -> &'static RwLock<HashMap<CategoryKey, String>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<CategoryKey, String>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Registers a custom parameter definition, returning the definition it replaced if any.
+/// Registered parameters are consulted before the built in and centre local tables.
+pub fn register_parameter(key: ParameterKey, parameter: Parameter) -> Option<Parameter> {
+    registry()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(key, parameter)
+}
+
+pub fn unregister_parameter(key: &ParameterKey) -> Option<Parameter> {
+    registry()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(key)
+}
+
+/// Registers a name for a custom parameter category, returning the name it replaced if any.
+/// Registered categories are consulted before the built in and centre local tables.
+pub fn register_category(key: CategoryKey, name: impl Into<String>) -> Option<String> {
+    category_registry()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(key, name.into())
+}
+
+pub fn unregister_category(key: &CategoryKey) -> Option<String> {
+    category_registry()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(key)
+}
+
+/// Removes every registered parameter and category
+pub fn clear_registered_parameters() {
+    registry()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .clear();
+    category_registry()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .clear();
+}
+
+/// Finds the most specific registered definition for a message's parameter
+pub fn registered_parameter(
+    centre: u16,
+    sub_centre: u16,
+    local_table_version: u8,
+    discipline: u8,
+    category: u8,
+    parameter: u8,
+) -> Option<Parameter> {
+    let registry = registry().read().unwrap_or_else(|e| e.into_inner());
+    if registry.is_empty() {
+        return None;
+    }
+
+    let key = ParameterKey::new(centre, discipline, category, parameter);
+    [
+        key.with_sub_centre(sub_centre)
+            .with_local_table_version(local_table_version),
+        key.with_sub_centre(sub_centre),
+        key.with_local_table_version(local_table_version),
+        key,
+    ]
+    .iter()
+    .find_map(|k| registry.get(k).cloned())
+}
+
+/// Finds the most specific registered name for a message's parameter category
+pub fn registered_category(
+    centre: u16,
+    sub_centre: u16,
+    local_table_version: u8,
+    discipline: u8,
+    category: u8,
+) -> Option<String> {
+    let registry = category_registry()
+        .read()
+        .unwrap_or_else(|e| e.into_inner());
+    if registry.is_empty() {
+        return None;
+    }
+
+    let key = CategoryKey::new(centre, discipline, category);
+    [
+        key.with_sub_centre(sub_centre)
+            .with_local_table_version(local_table_version),
+        key.with_sub_centre(sub_centre),
+        key.with_local_table_version(local_table_version),
+        key,
+    ]
+    .iter()
+    .find_map(|k| registry.get(k).cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn most_specific_registration_wins() {
+        let key = ParameterKey::new(250, 0, 1, 200);
+        register_parameter(
+            key,
+            Parameter {
+                name: "in house moisture".into(),
+                unit: "kgkg-1".into(),
+                abbrev: "IHM".into(),
+                standard_name: None,
+            },
+        );
+        let versioned_key = key.with_sub_centre(3).with_local_table_version(2);
+        register_parameter(
+            versioned_key,
+            Parameter {
+                name: "in house moisture v2".into(),
+                unit: "kgkg-1".into(),
+                abbrev: "IHM2".into(),
+                standard_name: None,
+            },
+        );
+
+        assert_eq!(registered_parameter(250, 3, 2, 0, 1, 200).unwrap().abbrev, "IHM2");
+        assert_eq!(registered_parameter(250, 3, 1, 0, 1, 200).unwrap().abbrev, "IHM");
+        assert!(registered_parameter(251, 3, 2, 0, 1, 200).is_none());
+
+        unregister_parameter(&key);
+        unregister_parameter(&versioned_key);
+        assert!(registered_parameter(250, 0, 1, 0, 1, 200).is_none());
+        assert!(registered_parameter(250, 3, 2, 0, 1, 200).is_none());
+    }
+
+    #[test]
+    fn registered_category_by_centre() {
+        let key = CategoryKey::new(250, 0, 201);
+        register_category(key, "in house diagnostics");
+        register_category(key.with_sub_centre(4), "sub centre diagnostics");
+
+        assert_eq!(
+            registered_category(250, 4, 1, 0, 201).as_deref(),
+            Some("sub centre diagnostics")
+        );
+        assert_eq!(
+            registered_category(250, 0, 1, 0, 201).as_deref(),
+            Some("in house diagnostics")
+        );
+        assert!(registered_category(251, 0, 1, 0, 201).is_none());
+
+        unregister_category(&key);
+        unregister_category(&key.with_sub_centre(4));
+        assert!(registered_category(250, 4, 1, 0, 201).is_none());
+    }
+}
@@ -0,0 +1,79 @@
+use gribberish_macros::{
+    wmo_category_table, wmo_parameter_table, DisplayDescription, FromValue, ToParameter,
+};
+use gribberish_types::Parameter;
+
+wmo_parameter_table! {
+    table = "tables/GRIB2_CodeFlag_4_2_CodeTable_en.csv",
+    abbreviations = "tables/parameter_abbreviations.csv",
+    discipline = 3,
+    category = 0,
+    name = "ImageFormatProduct"
+}
+
+wmo_parameter_table! {
+    table = "tables/GRIB2_CodeFlag_4_2_CodeTable_en.csv",
+    abbreviations = "tables/parameter_abbreviations.csv",
+    discipline = 3,
+    category = 1,
+    name = "QuantitativeProduct"
+}
+
+wmo_parameter_table! {
+    table = "tables/GRIB2_CodeFlag_4_2_CodeTable_en.csv",
+    abbreviations = "tables/parameter_abbreviations.csv",
+    discipline = 3,
+    category = 2,
+    name = "CloudPropertiesProduct"
+}
+
+wmo_parameter_table! {
+    table = "tables/GRIB2_CodeFlag_4_2_CodeTable_en.csv",
+    abbreviations = "tables/parameter_abbreviations.csv",
+    discipline = 3,
+    category = 3,
+    name = "FlightRuleConditionsProduct"
+}
+
+wmo_parameter_table! {
+    table = "tables/GRIB2_CodeFlag_4_2_CodeTable_en.csv",
+    abbreviations = "tables/parameter_abbreviations.csv",
+    discipline = 3,
+    category = 4,
+    name = "VolcanicAshProduct"
+}
+
+wmo_parameter_table! {
+    table = "tables/GRIB2_CodeFlag_4_2_CodeTable_en.csv",
+    abbreviations = "tables/parameter_abbreviations.csv",
+    discipline = 3,
+    category = 5,
+    name = "SeaSurfaceTemperatureProduct"
+}
+
+wmo_parameter_table! {
+    table = "tables/GRIB2_CodeFlag_4_2_CodeTable_en.csv",
+    abbreviations = "tables/parameter_abbreviations.csv",
+    discipline = 3,
+    category = 6,
+    name = "SolarRadiationProduct"
+}
+
+pub fn space_parameter(category: u8, parameter: u8) -> Option<Parameter> {
+    match category {
+        0 => Some(Parameter::from(ImageFormatProduct::from(parameter))),
+        1 => Some(Parameter::from(QuantitativeProduct::from(parameter))),
+        2 => Some(Parameter::from(CloudPropertiesProduct::from(parameter))),
+        3 => Some(Parameter::from(FlightRuleConditionsProduct::from(parameter))),
+        4 => Some(Parameter::from(VolcanicAshProduct::from(parameter))),
+        5 => Some(Parameter::from(SeaSurfaceTemperatureProduct::from(parameter))),
+        6 => Some(Parameter::from(SolarRadiationProduct::from(parameter))),
+        _ => None,
+    }
+}
+
+wmo_category_table! {
+    table = "tables/GRIB2_CodeFlag_4_1_CodeTable_en.csv",
+    discipline = 3,
+    name = "space_category"
+}
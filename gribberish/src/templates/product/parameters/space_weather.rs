@@ -0,0 +1,247 @@
+use gribberish_macros::{DisplayDescription, FromValue, ToParameter};
+use gribberish_types::Parameter;
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum SpaceWeatherTemperature {
+    #[description = "temperature"]
+    #[abbrev = "TMPSWP"]
+    #[unit = "K"]
+    Temperature = 0,
+    #[description = "electron temperature"]
+    #[abbrev = "ELECTMP"]
+    #[unit = "K"]
+    ElectronTemperature = 1,
+    #[description = "proton temperature"]
+    #[abbrev = "PROTTMP"]
+    #[unit = "K"]
+    ProtonTemperature = 2,
+    #[description = "ion temperature"]
+    #[abbrev = "IONTMP"]
+    #[unit = "K"]
+    IonTemperature = 3,
+    #[description = "parallel temperature"]
+    #[abbrev = "PRATMP"]
+    #[unit = "K"]
+    ParallelTemperature = 4,
+    #[description = "perpendicular temperature"]
+    #[abbrev = "PRPTMP"]
+    #[unit = "K"]
+    PerpendicularTemperature = 5,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum SpaceWeatherMomentum {
+    #[description = "velocity magnitude"]
+    #[abbrev = "SPEED"]
+    #[unit = "ms-1"]
+    VelocityMagnitude = 0,
+    #[description = "1st vector component of velocity"]
+    #[abbrev = "VEL1"]
+    #[unit = "ms-1"]
+    VelocityVectorComponent1 = 1,
+    #[description = "2nd vector component of velocity"]
+    #[abbrev = "VEL2"]
+    #[unit = "ms-1"]
+    VelocityVectorComponent2 = 2,
+    #[description = "3rd vector component of velocity"]
+    #[abbrev = "VEL3"]
+    #[unit = "ms-1"]
+    VelocityVectorComponent3 = 3,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum ChargedParticleMassAndNumber {
+    #[description = "particle number density"]
+    #[abbrev = "PLSMDEN"]
+    #[unit = "m-3"]
+    ParticleNumberDensity = 0,
+    #[description = "electron density"]
+    #[abbrev = "ELCDEN"]
+    #[unit = "m-3"]
+    ElectronDensity = 1,
+    #[description = "proton density"]
+    #[abbrev = "PROTDEN"]
+    #[unit = "m-3"]
+    ProtonDensity = 2,
+    #[description = "ion density"]
+    #[abbrev = "IONDEN"]
+    #[unit = "m-3"]
+    IonDensity = 3,
+    #[description = "vertical total electron content"]
+    #[abbrev = "VTEC"]
+    #[unit = "TECU"]
+    VerticalTotalElectronContent = 4,
+    #[description = "HF absorption frequency"]
+    #[abbrev = "ABSFRQ"]
+    #[unit = "Hz"]
+    HFAbsorptionFrequency = 5,
+    #[description = "HF absorption"]
+    #[abbrev = "ABSRB"]
+    #[unit = "dB"]
+    HFAbsorption = 6,
+    #[description = "spread F"]
+    #[abbrev = "SPRDF"]
+    #[unit = "m"]
+    SpreadF = 7,
+    #[description = "h'F"]
+    #[abbrev = "HPRIMF"]
+    #[unit = "m"]
+    HPrimeF = 8,
+    #[description = "critical frequency"]
+    #[abbrev = "CRTFRQ"]
+    #[unit = "Hz"]
+    CriticalFrequency = 9,
+    #[description = "maximal usable frequency"]
+    #[abbrev = "MAXUFZ"]
+    #[unit = "Hz"]
+    MaximalUsableFrequency = 10,
+    #[description = "peak height"]
+    #[abbrev = "PEAKH"]
+    #[unit = "m"]
+    PeakHeight = 11,
+    #[description = "peak density"]
+    #[abbrev = "PEAKDEN"]
+    #[unit = "m-3"]
+    PeakDensity = 12,
+    #[description = "equivalent slab thickness"]
+    #[abbrev = "EQSLABT"]
+    #[unit = "km"]
+    EquivalentSlabThickness = 13,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum ElectricAndMagneticFields {
+    #[description = "magnetic field magnitude"]
+    #[abbrev = "BTOT"]
+    #[unit = "T"]
+    MagneticFieldMagnitude = 0,
+    #[description = "1st vector component of magnetic field"]
+    #[abbrev = "BVEC1"]
+    #[unit = "T"]
+    MagneticFieldVectorComponent1 = 1,
+    #[description = "2nd vector component of magnetic field"]
+    #[abbrev = "BVEC2"]
+    #[unit = "T"]
+    MagneticFieldVectorComponent2 = 2,
+    #[description = "3rd vector component of magnetic field"]
+    #[abbrev = "BVEC3"]
+    #[unit = "T"]
+    MagneticFieldVectorComponent3 = 3,
+    #[description = "electric field magnitude"]
+    #[abbrev = "ETOT"]
+    #[unit = "Vm-1"]
+    ElectricFieldMagnitude = 4,
+    #[description = "1st vector component of electric field"]
+    #[abbrev = "EVEC1"]
+    #[unit = "Vm-1"]
+    ElectricFieldVectorComponent1 = 5,
+    #[description = "2nd vector component of electric field"]
+    #[abbrev = "EVEC2"]
+    #[unit = "Vm-1"]
+    ElectricFieldVectorComponent2 = 6,
+    #[description = "3rd vector component of electric field"]
+    #[abbrev = "EVEC3"]
+    #[unit = "Vm-1"]
+    ElectricFieldVectorComponent3 = 7,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum EnergeticParticles {
+    #[description = "proton flux (differential)"]
+    #[abbrev = "DIFPFLUX"]
+    #[unit = "(m2 s sr eV)-1"]
+    DifferentialProtonFlux = 0,
+    #[description = "proton flux (integral)"]
+    #[abbrev = "INTPFLUX"]
+    #[unit = "(m2 s sr)-1"]
+    IntegralProtonFlux = 1,
+    #[description = "electron flux (differential)"]
+    #[abbrev = "DIFEFLUX"]
+    #[unit = "(m2 s sr eV)-1"]
+    DifferentialElectronFlux = 2,
+    #[description = "electron flux (integral)"]
+    #[abbrev = "INTEFLUX"]
+    #[unit = "(m2 s sr)-1"]
+    IntegralElectronFlux = 3,
+    #[description = "heavy ion flux (differential)"]
+    #[abbrev = "DIFIFLUX"]
+    #[unit = "(m2 s sr eV/nuc)-1"]
+    DifferentialHeavyIonFlux = 4,
+    #[description = "heavy ion flux (integral)"]
+    #[abbrev = "INTIFLUX"]
+    #[unit = "(m2 s sr)-1"]
+    IntegralHeavyIonFlux = 5,
+    #[description = "cosmic ray neutron flux"]
+    #[abbrev = "NTRNFLUX"]
+    #[unit = "h-1"]
+    CosmicRayNeutronFlux = 6,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum SolarElectromagneticEmissions {
+    #[description = "integrated solar irradiance"]
+    #[abbrev = "TSI"]
+    #[unit = "Wm-2"]
+    IntegratedSolarIrradiance = 0,
+    #[description = "solar x-ray flux"]
+    #[abbrev = "XLONG"]
+    #[unit = "Wm-2"]
+    SolarXRayFlux = 1,
+    #[description = "solar EUV irradiance"]
+    #[abbrev = "EUVIRR"]
+    #[unit = "Wm-2"]
+    SolarEUVIrradiance = 2,
+    #[description = "solar spectral irradiance"]
+    #[abbrev = "SPECIRR"]
+    #[unit = "Wm-2nm-1"]
+    SolarSpectralIrradiance = 3,
+    #[description = "F10.7"]
+    #[abbrev = "F107"]
+    #[unit = "Wm-2Hz-1"]
+    F107 = 4,
+    #[description = "solar radio emissions"]
+    #[abbrev = "SOLRF"]
+    #[unit = "Wm-2Hz-1"]
+    SolarRadioEmissions = 5,
+    Missing = 255,
+}
+
+pub fn space_weather_parameter(category: u8, parameter: u8) -> Option<Parameter> {
+    match category {
+        0 => Some(Parameter::from(SpaceWeatherTemperature::from(parameter))),
+        1 => Some(Parameter::from(SpaceWeatherMomentum::from(parameter))),
+        2 => Some(Parameter::from(ChargedParticleMassAndNumber::from(parameter))),
+        3 => Some(Parameter::from(ElectricAndMagneticFields::from(parameter))),
+        4 => Some(Parameter::from(EnergeticParticles::from(parameter))),
+        6 => Some(Parameter::from(SolarElectromagneticEmissions::from(parameter))),
+        _ => None,
+    }
+}
+
+pub fn space_weather_category(category: u8) -> &'static str {
+    match category {
+        0 => "temperature",
+        1 => "momentum",
+        2 => "charged particle mass and number",
+        3 => "electric and magnetic fields",
+        4 => "energetic particles",
+        5 => "waves",
+        6 => "solar electromagnetic emissions",
+        7 => "terrestrial electromagnetic emissions",
+        8 => "imagery",
+        9 => "ion-neutral coupling",
+        10 => "space weather indices",
+        _ => "other",
+    }
+}
@@ -4,12 +4,13 @@ use gribberish_types::Parameter;
 use super::{
     parameters::{category, parameter},
     tables::{
-        DerivedForecastType, FixedSurfaceType, GeneratingProcess, TimeUnit,
+        DerivedForecastType, FixedSurfaceType, GeneratingProcess, StatisticalProcessing, TimeUnit,
         TypeOfStatisticalProcessing,
     },
+    time_range::TimeRangeSpecification,
 };
 
-pub trait ProductTemplate {
+pub trait ProductTemplate: Send + Sync {
     fn discipline(&self) -> u8;
     fn category_value(&self) -> u8;
     fn parameter_value(&self) -> u8;
@@ -27,6 +28,31 @@ pub trait ProductTemplate {
     fn derived_forecast_type(&self) -> Option<DerivedForecastType>;
     fn statistical_process_type(&self) -> Option<TypeOfStatisticalProcessing>;
 
+    fn time_ranges(&self) -> Vec<TimeRangeSpecification> {
+        Vec::new()
+    }
+
+    /// Whether only the fields common to all horizontal product templates could be read
+    fn is_partial(&self) -> bool {
+        false
+    }
+
+    fn statistical_processing(&self) -> Option<StatisticalProcessing> {
+        let time_ranges = self.time_ranges();
+        if time_ranges.is_empty() {
+            return self
+                .statistical_process_type()
+                .map(StatisticalProcessing::from);
+        }
+
+        Some(StatisticalProcessing {
+            processes: time_ranges
+                .into_iter()
+                .map(|r| r.statistical_process)
+                .collect(),
+        })
+    }
+
     fn category(&self) -> &'static str {
         category(self.discipline(), self.category_value())
     }
@@ -33,11 +33,11 @@ pub enum FixedSurfaceType {
     MaximumWindLevel = 6,
     #[description = "tropopause"]
     Tropopause = 7,
-    #[description = "sea bottom"]
-    SeaBottom = 9,
     #[name = "top of atmosphere"]
     #[description = "nominal top of the atmosphere"]
     NominalTopOfAtmosphere = 8,
+    #[description = "sea bottom"]
+    SeaBottom = 9,
     #[name = "entire atmosphere"]
     #[description = "entire atmosphere"]
     EntireAtmosphere = 10,
@@ -62,8 +62,16 @@ pub enum FixedSurfaceType {
     HybridLevel = 105,
     #[description = "depth below land surface"]
     DepthBelowLandSurface = 106,
+    #[name = "isentropic level"]
+    #[unit = "K"]
+    #[description = "isentropic (theta) level"]
+    IsentropicLevel = 107,
     #[description = "level at specified pressure difference from ground to level"]
     LevelAtSpecifiedPressureDifferenceFromGroundToLevel = 108,
+    #[name = "potential vorticity surface"]
+    #[unit = "Km2kg-1s-1"]
+    #[description = "potential vorticity surface"]
+    PotentialVorticitySurface = 109,
     #[description = "eta level"]
     EtaLevel = 111,
     #[description = "snow level"]
@@ -132,6 +140,8 @@ impl FixedSurfaceType {
             FixedSurfaceType::MixingLayer => true,
             FixedSurfaceType::IsobaricSurface => true,
             FixedSurfaceType::LevelAtSpecifiedPressureDifferenceFromGroundToLevel => true,
+            FixedSurfaceType::IsentropicLevel => true,
+            FixedSurfaceType::PotentialVorticitySurface => true,
             _ => false,
         }
     }
@@ -177,6 +187,8 @@ impl FixedSurfaceType {
             FixedSurfaceType::IsobaricSurface => "isobar",
             FixedSurfaceType::LevelAtSpecifiedPressureDifferenceFromGroundToLevel => "pres_diff",
             FixedSurfaceType::PlanetaryBoundaryLayer => "pbl",
+            FixedSurfaceType::IsentropicLevel => "theta",
+            FixedSurfaceType::PotentialVorticitySurface => "pv",
         }
     }
 }
@@ -275,24 +287,35 @@ pub enum TimeUnit {
     SixHours = 11,
     #[description = "12 hours"]
     TwelveHours = 12,
+    /// Only defined by GRIB1 code table 4, so it takes a code from the range GRIB2 reserves for local use
+    #[description = "15 minutes"]
+    FifteenMinutes = 192,
+    /// Only defined by GRIB1 code table 4, so it takes a code from the range GRIB2 reserves for local use
+    #[description = "30 minutes"]
+    ThirtyMinutes = 193,
     Seconds = 13,
 }
 
 impl TimeUnit {
+    /// The duration of `value` units, saturating at the largest representable duration
     pub fn duration(&self, value: i64) -> Duration {
+        let minutes = |factor: i64| Duration::try_minutes(value.saturating_mul(factor)).unwrap_or_else(Duration::max_value);
+        let hours = |factor: i64| Duration::try_hours(value.saturating_mul(factor)).unwrap_or_else(Duration::max_value);
         match self {
-            TimeUnit::Minute => Duration::minutes(value),
-            TimeUnit::Hour => Duration::hours(value),
-            TimeUnit::ThreeHours => Duration::hours(value * 3),
-            TimeUnit::SixHours => Duration::hours(value * 6),
-            TimeUnit::TwelveHours => Duration::hours(value * 12),
-            TimeUnit::Day => Duration::hours(value * 24),
-            TimeUnit::Month => Duration::hours(value * 730),
-            TimeUnit::Year => Duration::hours(value * 8760),
-            TimeUnit::Decade => Duration::hours(value * 87600),
-            TimeUnit::Normal => Duration::hours(value * 262800),
-            TimeUnit::Century => Duration::hours(value * 876000),
-            TimeUnit::Seconds => Duration::seconds(value),
+            TimeUnit::Minute => minutes(1),
+            TimeUnit::FifteenMinutes => minutes(15),
+            TimeUnit::ThirtyMinutes => minutes(30),
+            TimeUnit::Hour => hours(1),
+            TimeUnit::ThreeHours => hours(3),
+            TimeUnit::SixHours => hours(6),
+            TimeUnit::TwelveHours => hours(12),
+            TimeUnit::Day => hours(24),
+            TimeUnit::Month => hours(730),
+            TimeUnit::Year => hours(8760),
+            TimeUnit::Decade => hours(87600),
+            TimeUnit::Normal => hours(262800),
+            TimeUnit::Century => hours(876000),
+            TimeUnit::Seconds => Duration::try_seconds(value).unwrap_or_else(Duration::max_value),
         }
     }
 }
@@ -312,6 +335,8 @@ impl TryFrom<&str> for TimeUnit {
             "3 hours" => Ok(TimeUnit::ThreeHours),
             "6 hours" => Ok(TimeUnit::SixHours),
             "12 hours" => Ok(TimeUnit::TwelveHours),
+            "15 minutes" => Ok(TimeUnit::FifteenMinutes),
+            "30 minutes" => Ok(TimeUnit::ThirtyMinutes),
             "seconds" => Ok(TimeUnit::Seconds),
             _ => Err(GribberishError::TimeUnitError(value.to_string())),
         }
@@ -361,8 +386,53 @@ impl TypeOfStatisticalProcessing {
     }
 }
 
+/// Statistical processing applied to a field, ordered from the outermost time range to the
+/// innermost one, e.g. `[Average, Maximum]` for an average of daily maximums
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct StatisticalProcessing {
+    pub processes: Vec<TypeOfStatisticalProcessing>,
+}
+
+impl StatisticalProcessing {
+    pub fn outermost(&self) -> Option<&TypeOfStatisticalProcessing> {
+        self.processes.first()
+    }
+
+    pub fn is_nested(&self) -> bool {
+        self.processes.len() > 1
+    }
+
+    pub fn abbv(&self) -> String {
+        self.processes
+            .iter()
+            .map(|p| p.abbv())
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+}
+
+impl From<TypeOfStatisticalProcessing> for StatisticalProcessing {
+    fn from(value: TypeOfStatisticalProcessing) -> Self {
+        StatisticalProcessing {
+            processes: vec![value],
+        }
+    }
+}
+
+impl std::fmt::Display for StatisticalProcessing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = self
+            .processes
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>()
+            .join(" of ");
+        write!(f, "{description}")
+    }
+}
+
 #[repr(u8)]
-#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[derive(Clone, Eq, PartialEq, Debug, DisplayDescription, FromValue)]
 pub enum TypeOfTimeInterval {
     Reserved = 0,
     #[description = "successive times processed have same forecast time, start time of forecast is incremented."]
@@ -405,3 +475,41 @@ pub enum DerivedForecastType {
     NinetyFifthPercentile = 204,
     Missing = 255,
 }
+
+#[repr(u8)]
+#[derive(Clone, Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+pub enum TypeOfEnsembleForecast {
+    #[description = "unperturbed high-resolution control forecast"]
+    UnperturbedHighResolutionControl = 0,
+    #[description = "unperturbed low-resolution control forecast"]
+    UnperturbedLowResolutionControl = 1,
+    #[description = "negatively perturbed forecast"]
+    NegativelyPerturbed = 2,
+    #[description = "positively perturbed forecast"]
+    PositivelyPerturbed = 3,
+    #[description = "multi-model forecast"]
+    MultiModel = 4,
+    #[description = "perturbed ensemble member"]
+    PerturbedEnsembleMember = 192,
+    Missing = 255,
+}
+
+#[repr(u8)]
+#[derive(Clone, Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+pub enum TypeOfSpatialProcessing {
+    #[description = "data is calculated directly from the source grid with no interpolation"]
+    NoInterpolation = 0,
+    #[description = "bilinear interpolation using the 4 source grid grid-point values surrounding the nominal grid-point"]
+    Bilinear = 1,
+    #[description = "bicubic interpolation using the 4 source grid grid-point values surrounding the nominal grid-point"]
+    Bicubic = 2,
+    #[description = "using the value from the source grid grid-point which is nearest to the nominal grid-point"]
+    NearestNeighbor = 3,
+    #[description = "budget interpolation using the 4 source grid grid-point values surrounding the nominal grid-point"]
+    Budget = 4,
+    #[description = "spectral interpolation using the 4 source grid grid-point values surrounding the nominal grid-point"]
+    Spectral = 5,
+    #[description = "neighbor-budget interpolation using the 4 source grid grid-point values surrounding the nominal grid-point"]
+    NeighborBudget = 6,
+    Missing = 255,
+}
@@ -0,0 +1,66 @@
+use crate::utils::read_u32_from_bytes;
+
+use super::tables::{TimeUnit, TypeOfStatisticalProcessing, TypeOfTimeInterval};
+
+/// Size in bytes of a single time range specification in the statistical product templates
+pub const TIME_RANGE_SPECIFICATION_LEN: usize = 12;
+
+#[derive(Clone, Debug)]
+pub struct TimeRangeSpecification {
+    pub statistical_process: TypeOfStatisticalProcessing,
+    pub time_interval_type: TypeOfTimeInterval,
+    pub time_range_unit: TimeUnit,
+    pub time_range_length: u32,
+    pub time_increment_unit: TimeUnit,
+    pub time_increment: u32,
+}
+
+impl TimeRangeSpecification {
+    pub fn from_data(data: &[u8], offset: usize) -> Option<Self> {
+        if data.len() < offset + TIME_RANGE_SPECIFICATION_LEN {
+            return None;
+        }
+
+        Some(TimeRangeSpecification {
+            statistical_process: data[offset].into(),
+            time_interval_type: data[offset + 1].into(),
+            time_range_unit: data[offset + 2].into(),
+            time_range_length: read_u32_from_bytes(data, offset + 3).unwrap_or(0),
+            time_increment_unit: data[offset + 7].into(),
+            time_increment: read_u32_from_bytes(data, offset + 8).unwrap_or(0),
+        })
+    }
+
+    /// Reads `count` successive time range specifications starting at `offset`. The first
+    /// entry is the outermost time range, every following entry is nested within the previous one.
+    pub fn read_all(data: &[u8], offset: usize, count: usize) -> Vec<Self> {
+        (0..count)
+            .map_while(|i| {
+                TimeRangeSpecification::from_data(data, offset + i * TIME_RANGE_SPECIFICATION_LEN)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TimeRangeSpecification;
+    use crate::templates::product::tables::{TimeUnit, TypeOfStatisticalProcessing, TypeOfTimeInterval};
+
+    #[test]
+    fn read_nested_time_ranges() {
+        let raw = [
+            0x00u8, 0x02, 0x01, 0x00, 0x00, 0x00, 0x18, 0x01, 0x00, 0x00, 0x00, 0x00,
+            0x02, 0x01, 0x01, 0x00, 0x00, 0x00, 0x01, 0xff, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let ranges = TimeRangeSpecification::read_all(&raw, 0, 3);
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(ranges[0].statistical_process, TypeOfStatisticalProcessing::Average);
+        assert_eq!(ranges[0].time_interval_type, TypeOfTimeInterval::SameStartTimeIncrementedForecastTime);
+        assert_eq!(ranges[0].time_range_unit, TimeUnit::Hour);
+        assert_eq!(ranges[0].time_range_length, 24);
+        assert_eq!(ranges[1].statistical_process, TypeOfStatisticalProcessing::Maximum);
+        assert_eq!(ranges[1].time_range_length, 1);
+    }
+}
@@ -9,6 +9,8 @@ pub enum TemplateType {
     Data = 6,
 }
 
+/// Templates borrow the octets of the section they are read from, so decoding one does not copy
+/// the section. Each template's `into_owned` copies the octets when it has to outlive the data.
 pub trait Template {
     fn template_type(&self) -> TemplateType;
 
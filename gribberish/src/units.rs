@@ -0,0 +1,430 @@
+use crate::error::GribberishError;
+
+/// Exponents of the base dimensions kg, m, s, K, mol and A. Exponents can be fractional, as in
+/// the m2/3 s-1 of eddy dissipation rates.
+type Dimensions = [f64; 6];
+
+const DIMENSIONLESS: Dimensions = [0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+const MASS: Dimensions = [1.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+const LENGTH: Dimensions = [0.0, 1.0, 0.0, 0.0, 0.0, 0.0];
+const TIME: Dimensions = [0.0, 0.0, 1.0, 0.0, 0.0, 0.0];
+const TEMPERATURE: Dimensions = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+const AMOUNT: Dimensions = [0.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+const CURRENT: Dimensions = [0.0, 0.0, 0.0, 0.0, 0.0, 1.0];
+const PER_AREA: Dimensions = [0.0, -2.0, 0.0, 0.0, 0.0, 0.0];
+const AMOUNT_PER_AREA: Dimensions = [0.0, -2.0, 0.0, 0.0, 1.0, 0.0];
+const FREQUENCY: Dimensions = [0.0, 0.0, -1.0, 0.0, 0.0, 0.0];
+const SPEED: Dimensions = [0.0, 1.0, -1.0, 0.0, 0.0, 0.0];
+const PRESSURE: Dimensions = [1.0, -1.0, -2.0, 0.0, 0.0, 0.0];
+const FORCE: Dimensions = [1.0, 1.0, -2.0, 0.0, 0.0, 0.0];
+const ENERGY: Dimensions = [1.0, 2.0, -2.0, 0.0, 0.0, 0.0];
+const POWER: Dimensions = [1.0, 2.0, -3.0, 0.0, 0.0, 0.0];
+const VOLTAGE: Dimensions = [1.0, 2.0, -3.0, 0.0, 0.0, -1.0];
+const MAGNETIC_FLUX_DENSITY: Dimensions = [1.0, 0.0, -2.0, 0.0, 0.0, -1.0];
+
+/// Density of liquid water, used to convert between mass per area and depth of water so that
+/// 1 kg m-2 is 1 mm of precipitation
+const WATER_DENSITY: f64 = 1000.0;
+
+/// Known unit symbols with their scale and offset to SI. Prefixes are not parsed generically, so
+/// compact strings like "ms-1" from the parameter tables read as metres per second.
+const SYMBOLS: &[(&str, f64, f64, Dimensions)] = &[
+    ("kg", 1.0, 0.0, MASS),
+    ("g", 1e-3, 0.0, MASS),
+    ("mg", 1e-6, 0.0, MASS),
+    ("ug", 1e-9, 0.0, MASS),
+    ("m", 1.0, 0.0, LENGTH),
+    ("gpm", 1.0, 0.0, LENGTH),
+    ("dam", 10.0, 0.0, LENGTH),
+    ("km", 1e3, 0.0, LENGTH),
+    ("cm", 1e-2, 0.0, LENGTH),
+    ("mm", 1e-3, 0.0, LENGTH),
+    ("nm", 1e-9, 0.0, LENGTH),
+    ("ft", 0.3048, 0.0, LENGTH),
+    ("in", 0.0254, 0.0, LENGTH),
+    ("mi", 1609.344, 0.0, LENGTH),
+    ("nmi", 1852.0, 0.0, LENGTH),
+    ("s", 1.0, 0.0, TIME),
+    ("min", 60.0, 0.0, TIME),
+    ("h", 3600.0, 0.0, TIME),
+    ("hr", 3600.0, 0.0, TIME),
+    ("d", 86400.0, 0.0, TIME),
+    ("day", 86400.0, 0.0, TIME),
+    ("K", 1.0, 0.0, TEMPERATURE),
+    ("degC", 1.0, 273.15, TEMPERATURE),
+    ("°C", 1.0, 273.15, TEMPERATURE),
+    ("degF", 5.0 / 9.0, 459.67 * 5.0 / 9.0, TEMPERATURE),
+    ("°F", 5.0 / 9.0, 459.67 * 5.0 / 9.0, TEMPERATURE),
+    ("mol", 1.0, 0.0, AMOUNT),
+    ("A", 1.0, 0.0, CURRENT),
+    ("Hz", 1.0, 0.0, FREQUENCY),
+    ("Bq", 1.0, 0.0, FREQUENCY),
+    ("kt", 1852.0 / 3600.0, 0.0, SPEED),
+    ("knot", 1852.0 / 3600.0, 0.0, SPEED),
+    ("knots", 1852.0 / 3600.0, 0.0, SPEED),
+    ("mph", 1609.344 / 3600.0, 0.0, SPEED),
+    ("Pa", 1.0, 0.0, PRESSURE),
+    ("pa", 1.0, 0.0, PRESSURE),
+    ("hPa", 100.0, 0.0, PRESSURE),
+    ("kPa", 1000.0, 0.0, PRESSURE),
+    ("mb", 100.0, 0.0, PRESSURE),
+    ("mbar", 100.0, 0.0, PRESSURE),
+    ("bar", 1e5, 0.0, PRESSURE),
+    ("N", 1.0, 0.0, FORCE),
+    ("J", 1.0, 0.0, ENERGY),
+    ("eV", 1.602176634e-19, 0.0, ENERGY),
+    ("W", 1.0, 0.0, POWER),
+    ("V", 1.0, 0.0, VOLTAGE),
+    ("T", 1.0, 0.0, MAGNETIC_FLUX_DENSITY),
+    // Dobson units of ozone and TEC units of electrons, both counted over an area
+    ("DU", 4.4615e-4, 0.0, AMOUNT_PER_AREA),
+    ("TECU", 1e16, 0.0, PER_AREA),
+    ("%", 0.01, 0.0, DIMENSIONLESS),
+    ("ppm", 1e-6, 0.0, DIMENSIONLESS),
+    ("ppb", 1e-9, 0.0, DIMENSIONLESS),
+    ("ppbV", 1e-9, 0.0, DIMENSIONLESS),
+    // Angles, counts and the datum of heights above mean sea level carry no dimension
+    ("rad", 1.0, 0.0, DIMENSIONLESS),
+    ("sr", 1.0, 0.0, DIMENSIONLESS),
+    ("nuc", 1.0, 0.0, DIMENSIONLESS),
+    ("flashes", 1.0, 0.0, DIMENSIONLESS),
+    ("MSL", 1.0, 0.0, DIMENSIONLESS),
+];
+
+const DIMENSIONLESS_WORDS: &[&str] = &[
+    "", "-", "1", "numeric", "proportion", "porportion", "fraction", "nondim", "non-dim",
+    "dimensionless", "index", "deg", "degree", "degrees", "degree true", "degrees true", "deg e",
+    "deg n", "flag", "categorical", "bool", "ccittia5", "ph",
+];
+
+/// Units of codes and counts from a code table rather than measured quantities
+const DIMENSIONLESS_PREFIXES: &[&str] = &["code table", "integer"];
+
+/// Logarithmic units, which only convert to themselves
+const LOGARITHMIC_WORDS: &[&str] = &["db", "dbz"];
+const LOGARITHMIC_PREFIXES: &[&str] = &["ln(", "log10("];
+
+/// A parsed unit, relating a value to SI as `si = value * scale + offset`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unit {
+    scale: f64,
+    offset: f64,
+    dimensions: Dimensions,
+    /// The spelling of a logarithmic unit, as these have no linear relation to SI
+    logarithm: Option<String>,
+}
+
+impl Unit {
+    /// Parses unit strings as they appear in `Parameter::unit` ("kgm-2s-1", "ms-1", "K",
+    /// "(m2 s sr)-1", "code table 4.201") as well as common spellings such as "kg m-2 s-1",
+    /// "m/s", "mm/h", "hPa", "degC" and "kt"
+    pub fn parse(unit: &str) -> Result<Unit, GribberishError> {
+        let trimmed = unit.trim();
+        let lowercase = trimmed.to_lowercase();
+        if DIMENSIONLESS_WORDS.contains(&lowercase.as_str())
+            || DIMENSIONLESS_PREFIXES.iter().any(|prefix| lowercase.starts_with(prefix))
+        {
+            return Ok(Unit::dimensionless(None));
+        }
+        if LOGARITHMIC_WORDS.contains(&lowercase.as_str())
+            || LOGARITHMIC_PREFIXES.iter().any(|prefix| lowercase.starts_with(prefix))
+        {
+            return Ok(Unit::dimensionless(Some(trimmed.replace(' ', ""))));
+        }
+
+        match Unit::parse_product(trimmed, unit)? {
+            (parsed, "") => Ok(parsed),
+            _ => Err(GribberishError::UnitError(format!("Unmatched `)` in `{unit}`"))),
+        }
+    }
+
+    fn dimensionless(logarithm: Option<String>) -> Unit {
+        Unit {
+            scale: 1.0,
+            offset: 0.0,
+            dimensions: DIMENSIONLESS,
+            logarithm,
+        }
+    }
+
+    /// Parses the product of the components in `text` up to its end or a closing parenthesis,
+    /// returning the text left from that parenthesis
+    fn parse_product<'a>(text: &'a str, unit: &str) -> Result<(Unit, &'a str), GribberishError> {
+        let mut parsed = Unit::dimensionless(None);
+        let mut components = 0;
+        let mut divide_next = false;
+        let mut rest = text;
+
+        while let Some(c) = rest.chars().next() {
+            if c.is_whitespace() || c == '.' || c == '*' || c == '·' {
+                rest = &rest[c.len_utf8()..];
+                continue;
+            }
+            if c == '/' {
+                divide_next = true;
+                rest = &rest[1..];
+                continue;
+            }
+            if c == ')' {
+                break;
+            }
+
+            let component = if c == '(' {
+                let (group, group_rest) = Unit::parse_product(&rest[1..], unit)?;
+                rest = group_rest.strip_prefix(')').ok_or_else(|| {
+                    GribberishError::UnitError(format!("Unmatched `(` in `{unit}`"))
+                })?;
+                Unit { offset: 0.0, ..group }
+            } else if c.is_ascii_digit() {
+                // A numeric factor, such as the 0.001 of "0.001/s"
+                let length = rest
+                    .find(|c: char| !c.is_ascii_digit() && c != '.')
+                    .unwrap_or(rest.len());
+                let factor = rest[..length].parse::<f64>().map_err(|_| {
+                    GribberishError::UnitError(format!("Invalid factor `{}` in `{unit}`", &rest[..length]))
+                })?;
+                rest = &rest[length..];
+                Unit {
+                    scale: factor,
+                    ..Unit::dimensionless(None)
+                }
+            } else {
+                let (symbol, scale, offset, dimensions) = symbol_at(rest).ok_or_else(|| {
+                    GribberishError::UnitError(format!("Unknown unit `{rest}` in `{unit}`"))
+                })?;
+                rest = &rest[symbol.len()..];
+                Unit {
+                    scale: *scale,
+                    offset: *offset,
+                    dimensions: *dimensions,
+                    logarithm: None,
+                }
+            };
+
+            rest = rest.strip_prefix('^').unwrap_or(rest);
+            let (mut exponent, exponent_rest) = parse_exponent(rest, unit)?;
+            rest = exponent_rest;
+
+            if divide_next {
+                exponent = -exponent;
+                divide_next = false;
+            }
+
+            parsed.scale *= component.scale.powf(exponent);
+            parsed.offset = component.offset;
+            for (d, s) in parsed.dimensions.iter_mut().zip(component.dimensions.iter()) {
+                *d += s * exponent;
+            }
+            components += 1;
+
+            // Offsets only apply to a lone absolute temperature, anything else is an interval
+            if components > 1 || exponent != 1.0 {
+                parsed.offset = 0.0;
+            }
+        }
+
+        Ok((parsed, rest))
+    }
+
+    pub fn is_compatible(&self, other: &Unit) -> bool {
+        self.logarithm == other.logarithm
+            && (same_dimensions(&self.dimensions, &other.dimensions)
+                || self.water_equivalent_factor(other).is_some())
+    }
+
+    /// Factor from this unit's SI value to the other's when they only differ by a water density,
+    /// such as kg m-2 s-1 and mm/h
+    fn water_equivalent_factor(&self, other: &Unit) -> Option<f64> {
+        let difference: Vec<f64> = self
+            .dimensions
+            .iter()
+            .zip(other.dimensions.iter())
+            .map(|(a, b)| a - b)
+            .collect();
+
+        if same_dimensions(&difference, &[1.0, -3.0, 0.0, 0.0, 0.0, 0.0]) {
+            Some(1.0 / WATER_DENSITY)
+        } else if same_dimensions(&difference, &[-1.0, 3.0, 0.0, 0.0, 0.0, 0.0]) {
+            Some(WATER_DENSITY)
+        } else {
+            None
+        }
+    }
+}
+
+/// The longest symbol at the start of `text` that leaves text another component can follow, so
+/// "m2srad-1" reads as square metre seconds per radian rather than stopping at "sr" and "ad"
+fn symbol_at(text: &str) -> Option<&'static (&'static str, f64, f64, Dimensions)> {
+    let mut candidates: Vec<_> = SYMBOLS
+        .iter()
+        .filter(|(symbol, ..)| text.starts_with(symbol))
+        .collect();
+    candidates.sort_by_key(|(symbol, ..)| std::cmp::Reverse(symbol.len()));
+
+    candidates
+        .iter()
+        .find(|(symbol, ..)| can_follow_symbol(&text[symbol.len()..]))
+        .or(candidates.first())
+        .copied()
+}
+
+fn can_follow_symbol(text: &str) -> bool {
+    match text.chars().next() {
+        None => true,
+        Some(c) if c.is_ascii_digit() || c.is_whitespace() || "-+^/().*·".contains(c) => true,
+        Some(_) => SYMBOLS.iter().any(|(symbol, ..)| text.starts_with(symbol)),
+    }
+}
+
+/// Reads the exponent at the start of `text`, such as the "-2" of "m-2" or the "2/3" of "m2/3",
+/// defaulting to 1 when there is none
+fn parse_exponent<'a>(text: &'a str, unit: &str) -> Result<(f64, &'a str), GribberishError> {
+    let length = text
+        .char_indices()
+        .take_while(|(i, c)| c.is_ascii_digit() || (*i == 0 && (*c == '-' || *c == '+')))
+        .count();
+    let numerator = match &text[..length] {
+        "" => return Ok((1.0, text)),
+        e => e.parse::<i32>().map_err(|_| {
+            GribberishError::UnitError(format!("Invalid exponent `{e}` in `{unit}`"))
+        })?,
+    };
+    let rest = &text[length..];
+
+    // Digits right after a slash divide the exponent rather than the unit
+    let denominator_length = rest
+        .strip_prefix('/')
+        .map(|r| r.chars().take_while(char::is_ascii_digit).count())
+        .unwrap_or(0);
+    if denominator_length == 0 {
+        return Ok((numerator as f64, rest));
+    }
+
+    match rest[1..=denominator_length].parse::<i32>() {
+        Ok(denominator) if denominator != 0 => Ok((
+            numerator as f64 / denominator as f64,
+            &rest[denominator_length + 1..],
+        )),
+        _ => Err(GribberishError::UnitError(format!(
+            "Invalid exponent `{}` in `{unit}`",
+            &text[..length + denominator_length + 1]
+        ))),
+    }
+}
+
+fn same_dimensions(a: &[f64], b: &[f64]) -> bool {
+    a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 1e-9)
+}
+
+/// A linear conversion between two units
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnitConversion {
+    scale: f64,
+    offset: f64,
+}
+
+impl UnitConversion {
+    pub fn new(from: &str, to: &str) -> Result<UnitConversion, GribberishError> {
+        let from_unit = Unit::parse(from)?;
+        let to_unit = Unit::parse(to)?;
+
+        if from_unit.logarithm != to_unit.logarithm {
+            return Err(GribberishError::UnitError(format!(
+                "Cannot convert from `{from}` to `{to}`, logarithmic units only convert to themselves"
+            )));
+        }
+
+        let factor = if same_dimensions(&from_unit.dimensions, &to_unit.dimensions) {
+            1.0
+        } else {
+            from_unit.water_equivalent_factor(&to_unit).ok_or_else(|| {
+                GribberishError::UnitError(format!(
+                    "Cannot convert from `{from}` to `{to}`, the units have different dimensions"
+                ))
+            })?
+        };
+
+        Ok(UnitConversion {
+            scale: from_unit.scale * factor / to_unit.scale,
+            offset: (from_unit.offset * factor - to_unit.offset) / to_unit.scale,
+        })
+    }
+
+    pub fn apply(&self, value: f64) -> f64 {
+        value * self.scale + self.offset
+    }
+}
+
+pub fn convert_values(values: &[f64], from: &str, to: &str) -> Result<Vec<f64>, GribberishError> {
+    let conversion = UnitConversion::new(from, to)?;
+    Ok(values.iter().map(|v| conversion.apply(*v)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(value: f64, from: &str, to: &str) -> f64 {
+        UnitConversion::new(from, to).unwrap().apply(value)
+    }
+
+    #[test]
+    fn convert_common_units() {
+        assert!((convert(273.15, "K", "degC") - 0.0).abs() < 1e-9);
+        assert!((convert(300.0, "K", "degF") - 80.33).abs() < 1e-2);
+        assert!((convert(101325.0, "Pa", "hPa") - 1013.25).abs() < 1e-9);
+        assert!((convert(10.0, "ms-1", "kt") - 19.438).abs() < 1e-3);
+        assert!((convert(1.0, "kgm-2s-1", "mm/h") - 3600.0).abs() < 1e-9);
+        assert!((convert(2.0, "Ks-1", "degC/h") - 7200.0).abs() < 1e-9);
+        assert!((convert(50.0, "%", "proportion") - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn parse_parameter_table_units() {
+        use crate::grib1::parameters::grib1_parameter;
+        use crate::templates::product::parameters::ncep::ncep_local_parameter;
+        use crate::templates::product::parameters::parameter;
+        use std::collections::BTreeSet;
+
+        let mut units = BTreeSet::new();
+        for discipline in [0, 1, 2, 3, 4, 10, 209] {
+            for category in 0..=255 {
+                for number in 0..=255 {
+                    let parameters = [
+                        parameter(discipline, category, number),
+                        ncep_local_parameter(discipline, category, number),
+                    ];
+                    units.extend(parameters.into_iter().flatten().map(|p| p.unit));
+                }
+            }
+        }
+        for (centre, table_version) in [(7, 2), (98, 128)] {
+            let parameters = (0..=255).filter_map(|number| grib1_parameter(centre, table_version, number));
+            units.extend(parameters.map(|p| p.unit));
+        }
+
+        let unparsed: Vec<_> = units.iter().filter(|unit| Unit::parse(unit).is_err()).collect();
+        assert!(unparsed.is_empty(), "{unparsed:?}");
+    }
+
+    #[test]
+    fn reject_incompatible_units() {
+        assert!(UnitConversion::new("K", "m").is_err());
+        assert!(UnitConversion::new("ms-1", "Pa").is_err());
+        assert!(UnitConversion::new("dBZ", "1").is_err());
+        assert!(Unit::parse("furlong").is_err());
+        assert!(Unit::parse("(m2 s").is_err());
+    }
+
+    #[test]
+    fn convert_table_units() {
+        assert!((convert(270.0, "degree true", "deg") - 270.0).abs() < 1e-9);
+        assert!((convert(3.0, "code table 4.201", "1") - 3.0).abs() < 1e-9);
+        assert!((convert(20.0, "dBZ", "dBZ") - 20.0).abs() < 1e-9);
+        assert!((convert(1.0, "(m2 s sr)-1", "(cm2 s sr)-1") - 1e-4).abs() < 1e-12);
+        assert!((convert(1.0, "m2/3s-1", "cm2/3 s-1") - 100f64.powf(2.0 / 3.0)).abs() < 1e-9);
+        assert!((convert(1.0, "0.001/s", "Hz") - 1e-3).abs() < 1e-12);
+        assert!((convert(1.0, "m2srad-1", "m2 s") - 1.0).abs() < 1e-9);
+        assert!((convert(1.0, "flashes/km^2/min", "m-2 s-1") - 1.0 / 6e7).abs() < 1e-15);
+    }
+}
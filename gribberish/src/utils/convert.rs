@@ -1,28 +1,29 @@
 
 pub fn read_u16_from_bytes(data: &[u8], offset: usize) -> Option<u16> {
-    match data[offset..offset + 2].try_into() {
-        Ok(b) => Some(u16::from_be_bytes(b)),
-        Err(_) => None,
-    }
+    data.get(offset..offset + 2)
+        .and_then(|b| b.try_into().ok())
+        .map(u16::from_be_bytes)
+}
+
+pub fn read_u24_from_bytes(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 3)
+        .map(|b| u32::from_be_bytes([0, b[0], b[1], b[2]]))
 }
 
 pub fn read_u32_from_bytes(data: &[u8], offset: usize) -> Option<u32> {
-    match data[offset..offset + 4].try_into() {
-        Ok(b) => Some(u32::from_be_bytes(b)),
-        Err(_) => None,
-    }
+    data.get(offset..offset + 4)
+        .and_then(|b| b.try_into().ok())
+        .map(u32::from_be_bytes)
 }
 
 pub fn read_u64_from_bytes(data: &[u8], offset: usize) -> Option<u64> {
-    match data[offset..offset + 8].try_into() {
-        Ok(b) => Some(u64::from_be_bytes(b)),
-        Err(_) => None,
-    }
+    data.get(offset..offset + 8)
+        .and_then(|b| b.try_into().ok())
+        .map(u64::from_be_bytes)
 }
 
 pub fn read_f32_from_bytes(data: &[u8], offset: usize) -> Option<f32> {
-    match data[offset..offset + 4].try_into() {
-        Ok(b) => Some(f32::from_be_bytes(b)),
-        Err(_) => None,
-    }
+    data.get(offset..offset + 4)
+        .and_then(|b| b.try_into().ok())
+        .map(f32::from_be_bytes)
 }
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::f64::consts::FRAC_PI_4;
 
 use itertools::Itertools;
 use mappers::{projections::LambertConformalConic, Projection};
@@ -20,10 +21,85 @@ pub struct LambertConformalConicProjection {
     pub projection_params: HashMap<String, f64>,
 }
 
+/// A latitude longitude grid with regularly spaced longitudes and gaussian latitudes
+#[derive(Clone, Debug)]
+pub struct GaussianProjection {
+    pub latitudes: Vec<f64>,
+    pub longitudes: RegularCoordinateIterator,
+    pub projection_name: String,
+    pub projection_params: HashMap<String, f64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PolarStereographicProjection {
+    pub x: RegularCoordinateIterator,
+    pub y: RegularCoordinateIterator,
+    pub projection: PolarStereographic,
+    pub projection_name: String,
+    pub projection_params: HashMap<String, f64>,
+}
+
+/// Spherical polar stereographic projection, true to scale at `lat_ts`
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct PolarStereographic {
+    lon_0: f64,
+    lat_ts: f64,
+    radius: f64,
+    south_pole: bool,
+}
+
+impl PolarStereographic {
+    pub fn new(lon_0: f64, lat_ts: f64, radius: f64, south_pole: bool) -> Self {
+        PolarStereographic {
+            lon_0,
+            lat_ts,
+            radius,
+            south_pole,
+        }
+    }
+
+    fn scaled_radius(&self) -> f64 {
+        self.radius * (1.0 + self.lat_ts.abs().to_radians().sin())
+    }
+}
+
+impl Projection for PolarStereographic {
+    fn project_unchecked(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = (lon - self.lon_0).to_radians();
+        if self.south_pole {
+            let rho = self.scaled_radius() * (FRAC_PI_4 + lat.to_radians() / 2.0).tan();
+            (rho * lambda.sin(), rho * lambda.cos())
+        } else {
+            let rho = self.scaled_radius() * (FRAC_PI_4 - lat.to_radians() / 2.0).tan();
+            (rho * lambda.sin(), -rho * lambda.cos())
+        }
+    }
+
+    fn inverse_project_unchecked(&self, x: f64, y: f64) -> (f64, f64) {
+        let rho = (x * x + y * y).sqrt();
+        let c = 2.0 * (rho / self.scaled_radius()).atan();
+        let (lat, lambda) = if self.south_pole {
+            (c - std::f64::consts::FRAC_PI_2, x.atan2(y))
+        } else {
+            (std::f64::consts::FRAC_PI_2 - c, x.atan2(-y))
+        };
+
+        let mut lon = self.lon_0 + lambda.to_degrees();
+        if lon > 180.0 {
+            lon -= 360.0;
+        } else if lon < -180.0 {
+            lon += 360.0;
+        }
+        (lon, lat.to_degrees())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum LatLngProjection {
     PlateCaree(PlateCareeProjection),
     LambertConformal(LambertConformalConicProjection),
+    Gaussian(GaussianProjection),
+    PolarStereographic(PolarStereographicProjection),
 }
 
 impl LatLngProjection {
@@ -31,6 +107,8 @@ impl LatLngProjection {
         match self {
             LatLngProjection::PlateCaree(_) => true,
             LatLngProjection::LambertConformal(_) => false,
+            LatLngProjection::Gaussian(_) => true,
+            LatLngProjection::PolarStereographic(_) => false,
         }
     }
 
@@ -51,7 +129,28 @@ impl LatLngProjection {
                             let projected = projection
                                 .projection
                                 .inverse_project(x_coord, y_coord)
-                                .expect("Failed to inverse project from xy to lnglat");
+                                .unwrap_or((f64::NAN, f64::NAN));
+                            (projected.1, projected.0)
+                        })
+                        .collect::<Vec<(f64, f64)>>()
+                })
+                .unzip(),
+            LatLngProjection::Gaussian(projection) => (
+                projection.latitudes.clone(),
+                projection.longitudes.clone().collect(),
+            ),
+            LatLngProjection::PolarStereographic(projection) => projection
+                .y
+                .clone()
+                .flat_map(|y_coord| {
+                    projection
+                        .x
+                        .clone()
+                        .map(|x_coord| {
+                            let projected = projection
+                                .projection
+                                .inverse_project(x_coord, y_coord)
+                                .unwrap_or((f64::NAN, f64::NAN));
                             (projected.1, projected.0)
                         })
                         .collect::<Vec<(f64, f64)>>()
@@ -64,6 +163,8 @@ impl LatLngProjection {
         match self {
             LatLngProjection::PlateCaree(projection) => projection.longitudes.clone().collect(),
             LatLngProjection::LambertConformal(projection) => projection.x.clone().collect(),
+            LatLngProjection::Gaussian(projection) => projection.longitudes.clone().collect(),
+            LatLngProjection::PolarStereographic(projection) => projection.x.clone().collect(),
         }
     }
 
@@ -71,6 +172,8 @@ impl LatLngProjection {
         match self {
             LatLngProjection::PlateCaree(projection) => projection.latitudes.clone().collect(),
             LatLngProjection::LambertConformal(projection) => projection.y.clone().collect(),
+            LatLngProjection::Gaussian(projection) => projection.latitudes.clone(),
+            LatLngProjection::PolarStereographic(projection) => projection.y.clone().collect(),
         }
     }
 
@@ -78,7 +181,12 @@ impl LatLngProjection {
         match self {
             LatLngProjection::PlateCaree(_) => (x, y),
             LatLngProjection::LambertConformal(projection) => {
-                let projected = projection.projection.project(x, y).unwrap();
+                let projected = projection.projection.project(x, y).unwrap_or((f64::NAN, f64::NAN));
+                (projected.1, projected.0)
+            }
+            LatLngProjection::Gaussian(_) => (x, y),
+            LatLngProjection::PolarStereographic(projection) => {
+                let projected = projection.projection.inverse_project(x, y).unwrap_or((f64::NAN, f64::NAN));
                 (projected.1, projected.0)
             }
         }
@@ -88,9 +196,13 @@ impl LatLngProjection {
         match self {
             LatLngProjection::PlateCaree(_) => (lng, lat),
             LatLngProjection::LambertConformal(projection) => {
-                let projected = projection.projection.inverse_project(lng, lat).unwrap();
+                let projected = projection.projection.inverse_project(lng, lat).unwrap_or((f64::NAN, f64::NAN));
                 (projected.1, projected.0)
             }
+            LatLngProjection::Gaussian(_) => (lng, lat),
+            LatLngProjection::PolarStereographic(projection) => {
+                projection.projection.project(lng, lat).unwrap_or((f64::NAN, f64::NAN))
+            }
         }
     }
 
@@ -98,15 +210,17 @@ impl LatLngProjection {
         match self {
             LatLngProjection::PlateCaree(projection) => {
                 let minmax_lat = projection.latitudes.clone().minmax();
-                let (min_lat, max_lat) = minmax_lat.into_option().unwrap();
+                let (min_lat, max_lat) = minmax_lat.into_option().unwrap_or((f64::NAN, f64::NAN));
                 let minmax_lng = projection.longitudes.clone().minmax();
-                let (min_lng, max_lng) = minmax_lng.into_option().unwrap();
+                let (min_lng, max_lng) = minmax_lng.into_option().unwrap_or((f64::NAN, f64::NAN));
                 (min_lng, min_lat, max_lng, max_lat)
             }
-            LatLngProjection::LambertConformal(_) => {
+            LatLngProjection::LambertConformal(_)
+            | LatLngProjection::Gaussian(_)
+            | LatLngProjection::PolarStereographic(_) => {
                 let (lat, lng) = self.lat_lng();
-                let (min_lat, max_lat) = lat.into_iter().minmax().into_option().unwrap();
-                let (min_lng, max_lng) = lng.into_iter().minmax().into_option().unwrap();
+                let (min_lat, max_lat) = lat.into_iter().minmax().into_option().unwrap_or((f64::NAN, f64::NAN));
+                let (min_lng, max_lng) = lng.into_iter().minmax().into_option().unwrap_or((f64::NAN, f64::NAN));
                 (min_lng, min_lat, max_lng, max_lat)
             }
         }
@@ -118,6 +232,13 @@ impl LatLngProjection {
             LatLngProjection::LambertConformal(projection) => {
                 self.project_xy(projection.x.start, projection.y.start)
             },
+            LatLngProjection::Gaussian(projection) => (
+                projection.latitudes.first().copied().unwrap_or(f64::NAN),
+                projection.longitudes.start,
+            ),
+            LatLngProjection::PolarStereographic(projection) => {
+                self.project_xy(projection.x.start, projection.y.start)
+            },
         }
     }
 
@@ -127,6 +248,13 @@ impl LatLngProjection {
             LatLngProjection::LambertConformal(projection) => {
                 self.project_xy(projection.x.end, projection.y.end)
             },
+            LatLngProjection::Gaussian(projection) => (
+                projection.latitudes.last().copied().unwrap_or(f64::NAN),
+                projection.longitudes.end,
+            ),
+            LatLngProjection::PolarStereographic(projection) => {
+                self.project_xy(projection.x.end, projection.y.end)
+            },
         }
     }
 
@@ -134,6 +262,8 @@ impl LatLngProjection {
         match self {
             LatLngProjection::PlateCaree(projection) => projection.projection_name.clone(),
             LatLngProjection::LambertConformal(projection) => projection.projection_name.clone(),
+            LatLngProjection::Gaussian(projection) => projection.projection_name.clone(),
+            LatLngProjection::PolarStereographic(projection) => projection.projection_name.clone(),
         }
     }
 
@@ -141,6 +271,8 @@ impl LatLngProjection {
         match self {
             LatLngProjection::PlateCaree(projection) => projection.projection_params.clone(),
             LatLngProjection::LambertConformal(projection) => projection.projection_params.clone(),
+            LatLngProjection::Gaussian(projection) => projection.projection_params.clone(),
+            LatLngProjection::PolarStereographic(projection) => projection.projection_params.clone(),
         }
     }
 }
@@ -159,7 +291,7 @@ impl RegularCoordinateIterator {
         Self {
             start,
             step,
-            end: start + (step * (count - 1) as f64),
+            end: start + (step * count.saturating_sub(1) as f64),
             current_index: 0,
             count,
         }
@@ -223,4 +355,18 @@ mod tests {
         let iter = super::RegularCoordinateIterator::new(start, step, count);
         assert!((iter.end - end).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn test_polar_stereographic_round_trip() {
+        use mappers::Projection;
+
+        let projection = super::PolarStereographic::new(-105.0, 60.0, 6367470.0, false);
+        let (x, y) = projection.project(-120.0, 45.0).unwrap();
+        let (lon, lat) = projection.inverse_project(x, y).unwrap();
+        assert!((lon + 120.0).abs() < 1e-9);
+        assert!((lat - 45.0).abs() < 1e-9);
+
+        let (x, y) = projection.project(-105.0, 90.0).unwrap();
+        assert!(x.abs() < 1e-6 && y.abs() < 1e-6);
+    }
 }
@@ -30,7 +30,7 @@ impl <I> Iterator for FirstOrderSpatialDifferencing<I> where I: Iterator<Item =
             Some(self.d1)
         } else {
             let next_value = self.iter.next()?;
-            let next_value = next_value + self.prev + self.dmin;
+            let next_value = next_value.wrapping_add(self.prev).wrapping_add(self.dmin);
             self.prev = next_value;
             Some(next_value)
         }
@@ -79,7 +79,10 @@ impl <I> Iterator for SecondOrderSpatialDifferencing<I> where I: Iterator<Item =
             },
             _ => {
                 let next_value = self.iter.next()?;
-                let next_value = next_value + 2 * self.prev - self.prev2 + self.dmin;
+                let next_value = next_value
+                    .wrapping_add(self.prev.wrapping_mul(2))
+                    .wrapping_sub(self.prev2)
+                    .wrapping_add(self.dmin);
                 self.prev2 = self.prev;
                 self.prev = next_value;
                 Some(next_value)
@@ -153,13 +153,13 @@ pub fn extract_jpeg_data(raw_data: &Vec<u8>) -> Result<Vec<i32>, GribberishError
         if openjpeg_sys::opj_read_header(stream, dinfo, &mut image) != 1 {
             openjpeg_sys::opj_destroy_codec(dinfo);
             openjpeg_sys::opj_image_destroy(image);
-            return Err(GribberishError::JpegError("Failed to decode JPEG byte stream header".into()));
+            return Err(GribberishError::DecodeError { codec: "JPEG2000", reason: "Failed to decode the byte stream header".into() });
         }
 
         if openjpeg_sys::opj_decode(dinfo, stream, image) != 1 {
             openjpeg_sys::opj_destroy_codec(dinfo);
             openjpeg_sys::opj_image_destroy(image);
-            return Err(GribberishError::JpegError("Failed to decode JPEG byte stream".into()));
+            return Err(GribberishError::DecodeError { codec: "JPEG2000", reason: "Failed to decode the byte stream".into() });
         }
 
         // Do things to the data
@@ -181,7 +181,7 @@ pub fn extract_jpeg_data(raw_data: &Vec<u8>) -> Result<Vec<i32>, GribberishError
     }
 
     if output_data.len() == 0  {
-        Err(GribberishError::JpegError("Unknown failure extracting JPEG data".into()))
+        Err(GribberishError::DecodeError { codec: "JPEG2000", reason: "Unknown failure extracting the image data".into() })
     } else {
         Ok(output_data)
     }
@@ -1,6 +1,7 @@
 extern crate gribberish;
 
 use gribberish::message::{Message, read_messages};
+use gribberish::sections::section::field_section_offsets;
 use std::time::Instant;
 use std::vec::Vec;
 
@@ -128,4 +129,589 @@ fn read_complex_zerod() {
     assert!(data.is_ok());
     let data = data.unwrap();
     println!("spatial complex zero unpacking data() took {:?} for {} data points", end.duration_since(start), data.len());
-}
\ No newline at end of file
+}
+#[test]
+fn read_multi_field_message() {
+    let read_data = read_grib_messages("tests/data/hrrr.t06z.wrfsfcf01-UGRD.grib2");
+    let message = read_messages(read_data.as_slice()).next().unwrap();
+    let message_data = &read_data[message.byte_offset()..message.byte_offset() + message.len()];
+
+    // Repeat sections 4 to 7 as a second field holding the v component of the wind
+    let field_offsets = field_section_offsets(message_data, 0);
+    let product_offset = field_offsets[0][field_offsets[0].len() - 4];
+    let end_offset = message_data.len() - 4;
+
+    let mut second_field = message_data[product_offset..end_offset].to_vec();
+    second_field[10] = 3;
+
+    let mut multi_field = message_data[..end_offset].to_vec();
+    multi_field.extend_from_slice(&second_field);
+    multi_field.extend_from_slice(b"7777");
+    let total_length = multi_field.len() as u64;
+    multi_field[8..16].copy_from_slice(&total_length.to_be_bytes());
+
+    let messages = read_messages(multi_field.as_slice()).collect::<Vec<Message>>();
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0].field_count(), 2);
+    assert_eq!(messages[0].variable_abbrev().unwrap(), "UGRD");
+    assert_eq!(messages[1].variable_abbrev().unwrap(), "VGRD");
+    assert_eq!(messages[1].field_index(), 1);
+    assert_eq!(messages[1].grid_dimensions().unwrap(), messages[0].grid_dimensions().unwrap());
+    assert_eq!(messages[1].data_point_count().unwrap(), message.data_point_count().unwrap());
+
+    // The second field inherits every section before its product definition
+    let (first_sections, second_sections) = (messages[0].section_table(), messages[1].section_table());
+    assert_eq!(first_sections[..4], second_sections[..4]);
+    assert_ne!(first_sections[4], second_sections[4]);
+
+    // Templates are decoded once and reused by later accessors
+    assert!(std::ptr::addr_eq(
+        messages[1].product_template().unwrap(),
+        messages[1].product_template().unwrap()
+    ));
+}
+
+#[test]
+fn messages_are_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Message>();
+    assert_send_sync::<gribberish::message::MessageIterator>();
+}
+
+/// Grid description section of a 3 x 2 regular lat/lng grid from 10N 0E to 9N 2E, with the
+/// given scanning mode
+fn grib1_latlng_grid(scanning_mode: u8) -> Vec<u8> {
+    let mut grid = vec![0, 0, 32, 0, 255, 0, 0, 3, 0, 2];
+    grid.extend_from_slice(&[0x00, 0x27, 0x10, 0, 0, 0, 0x80, 0x00, 0x23, 0x28, 0x00, 0x07, 0xd0]);
+    grid.extend_from_slice(&[0x03, 0xe8, 0x03, 0xe8, scanning_mode, 0, 0, 0, 0]);
+    grid
+}
+
+/// Builds a GRIB1 message of temperatures on the 500 hPa isobaric surface over the given grid,
+/// packed with 8 bits per value from a reference value of 250.0
+fn grib1_message(grid: &[u8], bitmap: Option<&[u8]>, values: &[u8]) -> Vec<u8> {
+    // Product definition section, flagging the grid description and bitmap sections
+    let flags = if bitmap.is_some() { 0xc0 } else { 0x80 };
+    let mut sections = vec![0, 0, 28, 2, 7, 96, 255, flags, 11, 100, 0x01, 0xf4];
+    sections.extend_from_slice(&[24, 1, 2, 0, 0, 1, 6, 0, 0, 0, 0, 0, 21, 0, 0, 0]);
+
+    sections.extend_from_slice(grid);
+
+    if let Some(bitmap) = bitmap {
+        sections.extend_from_slice(&[0, 0, 6 + bitmap.len() as u8, 0, 0, 0]);
+        sections.extend_from_slice(bitmap);
+    }
+
+    // Binary data section, padded to an even length
+    let padding = (values.len() + 1) % 2;
+    let length = 11 + values.len() + padding;
+    sections.extend_from_slice(&[0, 0, length as u8, 8 * padding as u8, 0, 0, 0x42, 0xfa, 0, 0, 8]);
+    sections.extend_from_slice(values);
+    sections.extend(std::iter::repeat_n(0, padding));
+
+    let length = 8 + sections.len() + 4;
+    let mut message = b"GRIB".to_vec();
+    message.extend_from_slice(&[(length >> 16) as u8, (length >> 8) as u8, length as u8, 1]);
+    message.extend(sections);
+    message.extend_from_slice(b"7777");
+    message
+}
+
+/// Builds a GRIB1 message holding a 3 x 2 regular lat/lng grid of temperatures on the 500 hPa
+/// isobaric surface, packed with 8 bits per value
+fn grib1_temperature_message() -> Vec<u8> {
+    grib1_message(&grib1_latlng_grid(0), None, &[0, 1, 2, 3, 4, 5])
+}
+
+/// Rewrites a GRIB1 message with the ECMWF convention for messages longer than the 24 bit total
+/// length can hold, counting the length in 120 byte blocks and giving how far the blocks overshoot
+/// the message in the binary data section length
+fn as_large_grib1_message(mut message: Vec<u8>, binary_data_start: usize) -> Vec<u8> {
+    let blocks = message.len().div_ceil(120);
+    let overshoot = blocks * 120 - message.len() + 4;
+    message[4..7].copy_from_slice(&[0x80, (blocks >> 8) as u8, blocks as u8]);
+    message[binary_data_start..binary_data_start + 3]
+        .copy_from_slice(&[(overshoot >> 16) as u8, (overshoot >> 8) as u8, overshoot as u8]);
+    message
+}
+
+#[test]
+fn read_grib1() {
+    use gribberish::data_message::read_data_message;
+    use gribberish::message_metadata::scan_message_metadata;
+    use gribberish::templates::product::tables::TypeOfStatisticalProcessing;
+
+    let grib_data = grib1_temperature_message();
+    assert_eq!(read_messages(grib_data.as_slice()).count(), 0);
+
+    let metadata = scan_message_metadata(grib_data.as_slice());
+    assert_eq!(metadata.len(), 1);
+
+    let (_, (_, offset, metadata)) = metadata.into_iter().next().unwrap();
+    assert_eq!(offset, 0);
+    assert_eq!(metadata.var, "TMP");
+    assert_eq!(metadata.units, "K");
+    assert_eq!(metadata.first_fixed_surface_value, Some(50000.0));
+    assert_eq!(metadata.time_interval, 6);
+    assert_eq!(metadata.reference_date.to_rfc3339(), "2024-01-02T00:00:00+00:00");
+
+    let data_message = read_data_message(grib_data.as_slice(), offset, 0).unwrap();
+    assert_eq!(data_message.data, vec![250.0, 251.0, 252.0, 253.0, 254.0, 255.0]);
+    assert_eq!(data_message.metadata.statistical_process, None);
+
+    // Accumulated from the reference time to the forecast time
+    let mut grib_data = grib_data;
+    grib_data[28] = 4;
+    let data_message = read_data_message(grib_data.as_slice(), offset, 0).unwrap();
+    assert_eq!(
+        data_message.metadata.statistical_process,
+        Some(TypeOfStatisticalProcessing::Accumulation)
+    );
+    assert_eq!(
+        data_message.metadata.statistical_processing,
+        Some(TypeOfStatisticalProcessing::Accumulation.into())
+    );
+    assert_eq!(data_message.metadata.cell_methods().as_deref(), Some("time: sum"));
+}
+
+#[test]
+fn read_grib1_time_units() {
+    use gribberish::message_metadata::scan_message_metadata;
+    use gribberish::templates::product::tables::TimeUnit;
+
+    // GRIB1 code table 4 parts from GRIB2 code table 4.4 after code 7
+    let cases = [
+        (1, TimeUnit::Hour, "2024-01-02T06:00:00+00:00"),
+        (10, TimeUnit::ThreeHours, "2024-01-02T18:00:00+00:00"),
+        (11, TimeUnit::SixHours, "2024-01-03T12:00:00+00:00"),
+        (12, TimeUnit::TwelveHours, "2024-01-05T00:00:00+00:00"),
+        (13, TimeUnit::FifteenMinutes, "2024-01-02T01:30:00+00:00"),
+        (14, TimeUnit::ThirtyMinutes, "2024-01-02T03:00:00+00:00"),
+        (254, TimeUnit::Seconds, "2024-01-02T00:00:06+00:00"),
+    ];
+
+    for (code, unit, forecast_date) in cases {
+        let mut grib_data = grib1_temperature_message();
+        grib_data[25] = code;
+
+        let (_, (_, _, metadata)) = scan_message_metadata(grib_data.as_slice()).into_iter().next().unwrap();
+        assert_eq!(metadata.time_unit, unit);
+        assert_eq!(metadata.forecast_date.to_rfc3339(), forecast_date);
+    }
+}
+
+#[test]
+fn read_grib1_grids() {
+    use gribberish::grib1::Grib1Message;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-3, "{actual} != {expected}");
+    }
+
+    // Gaussian grid with one parallel between each pole and the equator, without increments
+    let mut grid = vec![0, 0, 32, 0, 255, 4, 0, 4, 0, 2];
+    grid.extend_from_slice(&[0x00, 0x89, 0xc0, 0, 0, 0, 0x00, 0x80, 0x89, 0xc0, 0x04, 0x1e, 0xb0]);
+    grid.extend_from_slice(&[0xff, 0xff, 0x00, 0x01, 0, 0, 0, 0, 0]);
+    let grib_data = grib1_message(&grid, None, &[0, 1, 2, 3, 4, 5, 6, 7]);
+    let message = Grib1Message::from_data(&grib_data, 0).unwrap();
+    assert!(message.is_regular_grid().unwrap());
+    assert_eq!(message.grid_dimensions().unwrap(), (2, 4));
+    let (latitudes, longitudes) = message.latlng_projector().unwrap().lat_lng();
+    assert_close(latitudes[0], 35.264);
+    assert_close(latitudes[1], -35.264);
+    assert_eq!(longitudes, vec![0.0, 90.0, 180.0, 270.0]);
+    assert_eq!(message.data().unwrap().len(), 8);
+
+    // Lambert conformal grid of 2 x 2 points 3 km apart, scanning north from 20N 100W
+    let mut grid = vec![0, 0, 42, 0, 255, 3, 0, 2, 0, 2];
+    grid.extend_from_slice(&[0x00, 0x4e, 0x20, 0x81, 0x86, 0xa0, 0x08, 0x81, 0x73, 0x18]);
+    grid.extend_from_slice(&[0x00, 0x0b, 0xb8, 0x00, 0x0b, 0xb8, 0, 0x40]);
+    grid.extend_from_slice(&[0x00, 0x61, 0xa8, 0x00, 0x61, 0xa8, 0, 0, 0, 0, 0, 0, 0, 0]);
+    let grib_data = grib1_message(&grid, None, &[0, 1, 2, 3]);
+    let message = Grib1Message::from_data(&grib_data, 0).unwrap();
+    assert!(!message.is_regular_grid().unwrap());
+    assert!(message.proj_string().unwrap().starts_with("+proj=lcc +lon_0=-95 +lat_0=25"));
+    let (latitudes, longitudes) = message.latlng_projector().unwrap().lat_lng();
+    assert_eq!(latitudes.len(), 4);
+    assert_close(latitudes[0], 20.0);
+    assert_close(longitudes[0], -100.0);
+    assert!(latitudes[2] > latitudes[0]);
+    assert_eq!(message.data().unwrap(), vec![250.0, 251.0, 252.0, 253.0]);
+
+    // Polar stereographic grid of 2 x 2 points 5 km apart, scanning north from 60N 105W
+    let mut grid = vec![0, 0, 32, 0, 255, 5, 0, 2, 0, 2];
+    grid.extend_from_slice(&[0x00, 0xea, 0x60, 0x81, 0x9a, 0x28, 0x08, 0x81, 0x9a, 0x28]);
+    grid.extend_from_slice(&[0x00, 0x13, 0x88, 0x00, 0x13, 0x88, 0, 0x40, 0, 0, 0, 0]);
+    let grib_data = grib1_message(&grid, None, &[0, 1, 2, 3]);
+    let message = Grib1Message::from_data(&grib_data, 0).unwrap();
+    assert!(message.proj_string().unwrap().starts_with("+proj=stere +lat_0=90 +lat_ts=60 +lon_0=-105"));
+    let (latitudes, longitudes) = message.latlng_projector().unwrap().lat_lng();
+    assert_close(latitudes[0], 60.0);
+    assert_close(longitudes[0], -105.0);
+    assert_eq!(message.data().unwrap(), vec![250.0, 251.0, 252.0, 253.0]);
+}
+
+#[test]
+fn read_grib1_bitmap() {
+    use gribberish::grib1::Grib1Message;
+
+    let grib_data = grib1_message(&grib1_latlng_grid(0), Some(&[0b1011_0100, 0]), &[0, 1, 2, 3]);
+    let message = Grib1Message::from_data(&grib_data, 0).unwrap();
+    assert!(message.has_bitmap());
+
+    let data = message.data().unwrap();
+    assert_eq!(data.len(), 6);
+    let present = data
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| !v.is_nan())
+        .collect::<Vec<_>>();
+    assert_eq!(present, vec![(0, &250.0), (2, &251.0), (3, &252.0), (5, &253.0)]);
+}
+
+#[test]
+fn read_grib1_consecutive_j() {
+    use gribberish::grib1::Grib1Message;
+
+    // Points are stored down each column, and read back along the rows
+    let grib_data = grib1_message(&grib1_latlng_grid(0x20), None, &[0, 1, 2, 3, 4, 5]);
+    let message = Grib1Message::from_data(&grib_data, 0).unwrap();
+    assert_eq!(message.grid_dimensions().unwrap(), (2, 3));
+    assert_eq!(message.data().unwrap(), vec![250.0, 252.0, 254.0, 251.0, 253.0, 255.0]);
+}
+
+#[test]
+fn read_large_grib1() {
+    use gribberish::grib1::{read_grib1_messages, Grib1Message};
+    use gribberish::reader::GribReader;
+    use std::io::Cursor;
+
+    let message = grib1_temperature_message();
+    let large_message = as_large_grib1_message(message.clone(), 68);
+    assert_eq!(&large_message[4..7], &[0x80, 0, 1]);
+
+    let grib1 = Grib1Message::from_data(&large_message, 0).unwrap();
+    assert_eq!(grib1.len(), message.len());
+    assert!(!grib1.is_empty());
+    assert_eq!(grib1.data().unwrap(), vec![250.0, 251.0, 252.0, 253.0, 254.0, 255.0]);
+
+    let mut grib_data = large_message.clone();
+    grib_data.extend(large_message);
+    grib_data.extend(message.clone());
+    let lengths = read_grib1_messages(&grib_data).map(|m| m.len()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![message.len(); 3]);
+
+    let reader = GribReader::new(Cursor::new(grib_data.as_slice()));
+    let messages = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(messages.len(), 3);
+    for (i, message) in messages.iter().enumerate() {
+        assert_eq!(message.byte_offset(), i * 90);
+        assert_eq!(message.data(0).unwrap().len(), 6);
+    }
+
+    let mut reader = GribReader::new(Cursor::new(grib_data.as_slice()));
+    let metadata = reader.metadata().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(metadata.iter().map(|m| m.message_size).collect::<Vec<_>>(), vec![90; 3]);
+    assert_eq!(reader.current_offset(), grib_data.len());
+
+    let mut reader = GribReader::new(Cursor::new(grib_data.as_slice()));
+    let headers = reader.read_message_headers().unwrap().unwrap();
+    assert_eq!(headers.message_size(), 90);
+    assert_eq!(headers.metadata(0).unwrap().data_compression, "grib1: Simple Packing");
+    assert!(headers.data(0).is_err());
+}
+
+#[test]
+fn unknown_originating_centre_keeps_id() {
+    use gribberish::message_metadata::read_message_metadata;
+    use gribberish::sections::identification::OriginatingCentre;
+
+    let mut grib_data = grib1_temperature_message();
+    let metadata = read_message_metadata(grib_data.as_slice(), 0, 0).unwrap();
+    assert_eq!(metadata.originating_centre_id, 7);
+
+    grib_data[12] = 200;
+    let metadata = read_message_metadata(grib_data.as_slice(), 0, 0).unwrap();
+    assert_eq!(metadata.originating_centre, OriginatingCentre::Missing);
+    assert_eq!(metadata.originating_centre_id, 200);
+}
+
+#[test]
+fn read_grib_stream() {
+    use gribberish::message_metadata::read_message_metadata;
+    use gribberish::reader::GribReader;
+    use std::io::{BufReader, Cursor};
+
+    let path = "tests/data/hrrr.t06z.wrfsfcf01-TMP.grib2";
+    let grib_data = read_grib_messages(path);
+    let expected = read_messages(grib_data.as_slice())
+        .map(|m| (m.byte_offset(), m.key().unwrap(), m.data().unwrap()))
+        .collect::<Vec<_>>();
+
+    let reader = GribReader::new(BufReader::new(File::open(path).unwrap()));
+    let mut read_count = 0;
+    for message in reader {
+        let message = message.unwrap();
+        for field in 0..message.field_count() {
+            let (offset, key, data) = &expected[read_count];
+            let data_message = message.data_message(field).unwrap();
+            assert_eq!(data_message.metadata.byte_offset, *offset);
+            assert_eq!(&data_message.metadata.key, key);
+            assert_eq!(data_message.data.len(), data.len());
+            read_count += 1;
+        }
+    }
+    assert_eq!(read_count, expected.len());
+
+    // Mixing in a GRIB1 message, the seeking reader reads the same metadata without the data
+    let mut grib_data = grib_data;
+    let grib1_offset = grib_data.len();
+    grib_data.extend(grib1_temperature_message());
+
+    let mut reader = GribReader::new(Cursor::new(grib_data.as_slice()));
+    let metadata = reader.metadata().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(metadata.len(), expected.len() + 1);
+    assert_eq!(reader.current_offset(), grib_data.len());
+
+    for metadata in metadata {
+        let field = metadata.field_index;
+        let in_memory = read_message_metadata(grib_data.as_slice(), metadata.byte_offset, field).unwrap();
+        assert_eq!(metadata.key, in_memory.key);
+        assert_eq!(metadata.message_size, in_memory.message_size);
+        assert_eq!(metadata.data_compression, in_memory.data_compression);
+        assert_eq!(metadata.grid_shape, in_memory.grid_shape);
+    }
+
+    let mut reader = GribReader::new(Cursor::new(&grib_data[grib1_offset..]));
+    let grib1 = reader.read_message_headers().unwrap().unwrap();
+    assert!(grib1.data(0).is_err());
+    assert!(reader.read_message().unwrap().is_none());
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn read_grib_async_stream() {
+    use futures::{executor::block_on, io::Cursor, StreamExt};
+    use gribberish::async_reader::read_grib_async_stream;
+
+    let mut grib_data = read_grib_messages("tests/data/hrrr.t06z.wrfsfcf01-UGRD.grib2");
+    let expected = read_messages(grib_data.as_slice())
+        .map(|m| (m.byte_offset(), m.key().unwrap()))
+        .collect::<Vec<_>>();
+    grib_data.extend(grib1_temperature_message());
+
+    let messages = block_on(read_grib_async_stream(Cursor::new(grib_data.as_slice())).collect::<Vec<_>>());
+    assert_eq!(messages.len(), expected.len() + 1);
+
+    for (message, (offset, key)) in messages.iter().zip(expected.iter()) {
+        let metadata = message.as_ref().unwrap().metadata(0).unwrap();
+        assert_eq!(metadata.byte_offset, *offset);
+        assert_eq!(&metadata.key, key);
+    }
+
+    let grib1 = messages.last().unwrap().as_ref().unwrap();
+    assert_eq!(grib1.edition(), Some(1));
+    assert_eq!(grib1.data(0).unwrap().len(), 6);
+
+    // A truncated stream yields the messages before the break, then an error
+    let truncated = &grib_data[..grib_data.len() - 10];
+    let messages = block_on(read_grib_async_stream(Cursor::new(truncated)).collect::<Vec<_>>());
+    assert_eq!(messages.len(), expected.len() + 1);
+    assert!(messages.last().unwrap().is_err());
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn read_grib_file() {
+    use gribberish::file::GribFile;
+
+    let path = "tests/data/hrrr.t06z.wrfsfcf01-UGRD.grib2";
+    let grib_data = read_grib_messages(path);
+    let file = GribFile::open(path).unwrap();
+    assert_eq!(file.byte_data(), grib_data.as_slice());
+
+    let expected = read_messages(grib_data.as_slice())
+        .map(|m| (m.byte_offset(), m.key().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(file.messages().count(), expected.len());
+
+    let metadata = file.metadata().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(metadata.len(), expected.len());
+
+    for (metadata, (offset, key)) in metadata.iter().zip(expected.iter()) {
+        assert_eq!(metadata.byte_offset, *offset);
+        assert_eq!(&metadata.key, key);
+    }
+
+    // Messages are read directly at their byte offset
+    let (offset, key) = expected.last().unwrap();
+    assert_eq!(&file.message(*offset).unwrap().key().unwrap(), key);
+    assert_eq!(&file.message_metadata(*offset, 0).unwrap().key, key);
+    assert_eq!(file.message_data(*offset, 0).unwrap().len(), metadata.last().unwrap().data_point_count());
+    assert!(file.message(*offset + 1).is_none());
+    assert_eq!(file.grib1_messages().count(), 0);
+
+    assert!(GribFile::open("tests/data/missing.grib2").is_err());
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn read_grib_file_editions() {
+    use gribberish::file::GribFile;
+
+    let grib2_data = read_grib_messages("tests/data/hrrr.t06z.wrfsfcf01-UGRD.grib2");
+    let grib2_fields = read_messages(grib2_data.as_slice()).count();
+    let mut grib_data = grib1_temperature_message();
+    grib_data.extend_from_slice(&grib2_data);
+
+    let path = std::env::temp_dir().join(format!("gribberish-editions-{}.grib", std::process::id()));
+    std::fs::write(&path, &grib_data).unwrap();
+    let file = GribFile::open(&path).unwrap();
+
+    // Only GRIB2 messages are read as `Message`, GRIB1 messages have their own iterator
+    assert_eq!(file.messages().count(), grib2_fields);
+    let grib1_messages = file.grib1_messages().collect::<Vec<_>>();
+    assert_eq!(grib1_messages.len(), 1);
+    assert_eq!(grib1_messages[0].variable_abbrev().unwrap(), "TMP");
+    assert_eq!(file.metadata().count(), grib2_fields + 1);
+
+    drop(file);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn read_resilient() {
+    use gribberish::message::read_messages_resilient;
+
+    let grib_data = read_grib_messages("tests/data/hrrr.t06z.wrfsfcf01-TMP.grib2");
+    let message = read_messages(grib_data.as_slice()).next().unwrap();
+    let message_data = &grib_data[..message.len()];
+
+    // A WMO bulletin header, then a complete message, padding with a corrupt marker, a GRIB1
+    // message, another complete message and finally a truncated one
+    let mut data = b"\x01\r\r\n123\r\r\nYTPA98 KWBC 010600\r\r\n".to_vec();
+    let header = 0..data.len();
+    data.extend_from_slice(message_data);
+    let padding_start = data.len();
+    data.extend_from_slice(b"\0\0\0\0GRIB\0\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\x10");
+    let padding = padding_start..data.len();
+    data.extend(grib1_temperature_message());
+    let second_offset = data.len();
+    data.extend_from_slice(message_data);
+    let truncated = data.len()..data.len() + message_data.len() / 2;
+    data.extend_from_slice(&message_data[..message_data.len() / 2]);
+
+    assert_eq!(read_messages(data.as_slice()).count(), 0);
+
+    let mut messages = read_messages_resilient(data.as_slice());
+    let offsets = messages.by_ref().map(|m| m.byte_offset()).collect::<Vec<_>>();
+    assert_eq!(offsets, vec![header.end, second_offset]);
+    assert_eq!(messages.skipped_ranges(), &[header, padding, truncated]);
+}
+
+#[test]
+fn read_corrupt() {
+    use gribberish::grib1::read_grib1_messages;
+    use gribberish::message::read_messages_resilient;
+    use gribberish::message_metadata::{scan_message_metadata, MessageMetadata};
+
+    fn is_small_grid(dimensions: Option<(usize, usize)>) -> bool {
+        dimensions
+            .and_then(|(y, x)| y.checked_mul(x))
+            .is_some_and(|count| count <= 10_000)
+    }
+
+    fn read_all(data: &[u8]) {
+        for message in read_messages(data) {
+            let _ = message.key();
+            let _ = MessageMetadata::try_from(&message);
+            if is_small_grid(message.grid_dimensions().ok()) {
+                let _ = message.data();
+                let _ = message.latlng_projector().map(|projector| projector.lat_lng());
+            }
+        }
+        for _ in read_messages_resilient(data) {}
+        let _ = scan_message_metadata(data);
+        for message in read_grib1_messages(data) {
+            let _ = MessageMetadata::try_from(&message);
+            if is_small_grid(message.grid_dimensions().ok()) {
+                let _ = message.data();
+            }
+        }
+    }
+
+    let messages = vec![
+        read_grib_messages("tests/data/gfs.t18z.pgrb2.0p25.f186-RH.grib2"),
+        read_grib_messages("tests/data/hrrr.t06z.wrfsfcf01-CFRZR.grib2"),
+        grib1_temperature_message(),
+    ];
+
+    // Every truncation and every single octet overwritten with extreme values must be read
+    // without panicking
+    for message in messages {
+        for length in 0..message.len() {
+            read_all(&message[..length]);
+        }
+
+        for offset in 0..message.len() {
+            for value in [0x00, 0x01, 0x7f, 0x80, 0xff] {
+                let mut corrupt = message.clone();
+                corrupt[offset] = value;
+                read_all(&corrupt);
+            }
+        }
+    }
+}
+
+#[test]
+fn read_errors() {
+    use gribberish::error::{ErrorKind, GribberishError};
+    use gribberish::message_metadata::MessageMetadata;
+
+    let message_data = read_grib_messages("tests/data/hrrr.t06z.wrfsfcf01-CFRZR.grib2");
+    let field_offsets = field_section_offsets(message_data.as_slice(), 0);
+    let product_offset = field_offsets[0][field_offsets[0].len() - 4];
+    let data_representation_offset = field_offsets[0][field_offsets[0].len() - 3];
+
+    // The message, then a copy using an unsupported product template, a copy packing more
+    // bits than its data section holds and a copy whose product template overruns its section
+    let mut data = message_data.clone();
+    let mut unsupported = message_data.clone();
+    unsupported[product_offset + 7..product_offset + 9].copy_from_slice(&30u16.to_be_bytes());
+    data.extend_from_slice(&unsupported);
+    let mut corrupt = message_data.clone();
+    corrupt[data_representation_offset + 19] = 32;
+    data.extend_from_slice(&corrupt);
+    let mut truncated = message_data.clone();
+    truncated[product_offset + 7..product_offset + 9].copy_from_slice(&61u16.to_be_bytes());
+    data.extend_from_slice(&truncated);
+
+    let messages = read_messages(data.as_slice()).collect::<Vec<_>>();
+    assert_eq!(messages.len(), 4);
+    assert!(MessageMetadata::try_from(&messages[0]).is_ok());
+
+    let error = MessageMetadata::try_from(&messages[1]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Unsupported);
+    assert_eq!(error.message_index(), Some(1));
+    assert_eq!(error.byte_offset(), Some(message_data.len()));
+    assert!(matches!(
+        error.root(),
+        GribberishError::UnsupportedTemplate { section: 4, template: 30 }
+    ));
+
+    let error = messages[2].data().unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Corrupt);
+    assert_eq!(error.message_index(), Some(2));
+    assert_eq!(error.byte_offset(), Some(message_data.len() * 2));
+    assert!(matches!(error.root(), GribberishError::TruncatedData { .. }));
+
+    let error = messages[3].product_template().err().unwrap();
+    assert_eq!(error.kind(), ErrorKind::Corrupt);
+    match error.root() {
+        GribberishError::TruncatedSection { section, offset, expected, .. } => {
+            assert_eq!((*section, *offset, *expected), (4, product_offset, 68));
+        }
+        error => panic!("Unexpected error {error}"),
+    }
+}
@@ -0,0 +1,60 @@
+use std::{fs::File, io::Read};
+
+use gribberish::message::Message;
+
+extern crate gribberish;
+
+// Registrations are global to the process, so these tests run in their own test binary where
+// they cannot change the parameters read by tests running in parallel
+
+pub fn read_grib_messages(path: &str) -> Vec<u8> {
+    let mut grib_file = File::open(path).expect("file not found");
+
+    let mut raw_grib_data = Vec::new();
+    grib_file.read_to_end(&mut raw_grib_data).expect("failed to read raw grib2 data");
+
+    raw_grib_data
+}
+
+#[test]
+fn registered_parameter_in_custom_category() {
+    use gribberish::templates::product::parameters::registry::{
+        register_category, register_parameter, unregister_category, unregister_parameter,
+        CategoryKey, ParameterKey,
+    };
+    use gribberish_types::Parameter;
+
+    let mut grib_data = read_grib_messages("tests/data/hrrr.t06z.wrfsfcf01-UGRD.grib2");
+
+    // Move the field into an unassigned category, octets 10 and 11 of section 4
+    let mut offset = 16;
+    while grib_data[offset + 4] != 4 {
+        offset += u32::from_be_bytes(grib_data[offset..offset + 4].try_into().unwrap()) as usize;
+    }
+    grib_data[offset + 9] = 201;
+    grib_data[offset + 10] = 7;
+
+    let parameter_key = ParameterKey::new(7, 0, 201, 7);
+    let category_key = CategoryKey::new(7, 0, 201);
+    register_parameter(
+        parameter_key,
+        Parameter {
+            name: "in house wind diagnostic".into(),
+            unit: "ms-1".into(),
+            abbrev: "IHWD".into(),
+            standard_name: None,
+        },
+    );
+    register_category(category_key, "in house diagnostics");
+
+    let message = Message::from_data(&grib_data, 0).unwrap();
+    let parameter = message.parameter().unwrap();
+    let category = message.category().unwrap();
+
+    unregister_parameter(&parameter_key);
+    unregister_category(&category_key);
+
+    assert_eq!(parameter.abbrev, "IHWD");
+    assert_eq!(category, "in house diagnostics");
+    assert_ne!(message.category().unwrap(), "in house diagnostics");
+}
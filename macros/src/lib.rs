@@ -1,5 +1,7 @@
 extern crate proc_macro;
 
+mod wmo;
+
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{parse_macro_input, Item, ItemEnum, DeriveInput};
@@ -96,7 +98,7 @@ fn generate_from_value_impl(enum_data: &ItemEnum) -> TokenStream {
     }).into()
 }
 
-#[proc_macro_derive(ToParameter, attributes(name, abbrev, unit))]
+#[proc_macro_derive(ToParameter, attributes(name, abbrev, unit, standard_name))]
 pub fn parameter_attributes(input: TokenStream) -> TokenStream {
     // Parse the input tokens into a syntax tree
     let input = parse_macro_input!(input as DeriveInput);
@@ -119,6 +121,7 @@ fn generate_parameter_attributes(enum_data: &ItemEnum) -> TokenStream {
     let variant_names_first = variants.into_iter().map(|v| v.ident.clone());
     let variant_names_second = variants.into_iter().map(|v| v.ident.clone());
     let variant_names_third = variants.into_iter().map(|v| v.ident.clone());
+    let variant_names_fourth = variants.into_iter().map(|v| v.ident.clone());
     let variant_names = variants
         .into_iter()
         .map(|v| {
@@ -146,6 +149,18 @@ fn generate_parameter_attributes(enum_data: &ItemEnum) -> TokenStream {
                 _ => "".to_string(),
             }
         });
+    let variant_standard_names = variants
+        .into_iter()
+        .map(|v| {
+            let standard_name_attribute = v.attrs.iter().find(|a| a.path.is_ident("standard_name"));
+            match standard_name_attribute {
+                Some(a) => {
+                    let standard_name = a.tokens.to_string().replace("=", "").replace("\"", "").trim().to_string();
+                    quote! { Some(#standard_name) }
+                }
+                _ => quote! { None },
+            }
+        });
 
     (quote! {
         impl #name {
@@ -172,6 +187,14 @@ fn generate_parameter_attributes(enum_data: &ItemEnum) -> TokenStream {
                     )*
                 }
             }
+
+            pub fn standard_name(&self) -> Option<&str> {
+                match self {
+                    #(
+                        #name::#variant_names_fourth => #variant_standard_names,
+                    )*
+                }
+            }
         }
 
         impl std::convert::From<#name> for Parameter {
@@ -180,8 +203,51 @@ fn generate_parameter_attributes(enum_data: &ItemEnum) -> TokenStream {
                     name: value.name().to_string(),
                     unit: value.unit().to_string(),
                     abbrev: value.abbrev().to_string(),
+                    standard_name: value.standard_name().map(|s| s.to_string()),
                 }
             }
         }
     }).into()
 }
+
+/// Generates a parameter enum for a single WMO code table 4.2 category from a vendored csv in the
+/// WMO GRIB2 code table format. Paths are relative to the invoking crate's manifest directory and
+/// the optional abbreviations csv has `discipline,category,parameter,abbrev,standard_name` columns,
+/// where the CF standard name may be left empty.
+///
+/// ```ignore
+/// wmo_parameter_table! {
+///     table = "tables/GRIB2_CodeFlag_4_2_CodeTable_en.csv",
+///     abbreviations = "tables/parameter_abbreviations.csv",
+///     discipline = 1,
+///     category = 0,
+///     name = "HydrologyBasicProduct"
+/// }
+/// ```
+#[proc_macro]
+pub fn wmo_parameter_table(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input as wmo::TableArgs);
+    match wmo::parameter_table(args) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Generates a function naming the categories of a discipline from a vendored WMO code table 4.1
+/// csv, returning "other" for unknown categories.
+///
+/// ```ignore
+/// wmo_category_table! {
+///     table = "tables/GRIB2_CodeFlag_4_1_CodeTable_en.csv",
+///     discipline = 1,
+///     name = "hydrological_category"
+/// }
+/// ```
+#[proc_macro]
+pub fn wmo_category_table(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input as wmo::TableArgs);
+    match wmo::category_table(args) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
@@ -0,0 +1,452 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Ident, Lit, LitStr, MetaNameValue, Token};
+
+/// Arguments to the table macros, given as `key = value` pairs separated by commas
+pub struct TableArgs {
+    values: HashMap<String, Lit>,
+}
+
+impl Parse for TableArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pairs = Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?;
+        let values = pairs
+            .into_iter()
+            .filter_map(|p| p.path.get_ident().map(|i| (i.to_string(), p.lit)))
+            .collect();
+        Ok(TableArgs { values })
+    }
+}
+
+impl TableArgs {
+    fn string(&self, key: &str) -> Option<String> {
+        match self.values.get(key) {
+            Some(Lit::Str(s)) => Some(s.value()),
+            _ => None,
+        }
+    }
+
+    fn number(&self, key: &str) -> Option<u8> {
+        match self.values.get(key) {
+            Some(Lit::Int(i)) => i.base10_parse().ok(),
+            _ => None,
+        }
+    }
+
+    fn require_string(&self, key: &str) -> Result<String, syn::Error> {
+        self.string(key).ok_or_else(|| missing_argument(key))
+    }
+
+    fn require_number(&self, key: &str) -> Result<u8, syn::Error> {
+        self.number(key).ok_or_else(|| missing_argument(key))
+    }
+}
+
+fn missing_argument(key: &str) -> syn::Error {
+    syn::Error::new(Span::call_site(), format!("Missing table argument `{key}`"))
+}
+
+/// A row of a WMO GRIB2 code table csv, as published in the WMO-IM/GRIB2 repository
+struct CodeTableRow {
+    sub_title: String,
+    value: String,
+    meaning: String,
+    unit: String,
+}
+
+/// Resolves a table path relative to the manifest directory of the crate invoking the macro
+fn table_path(relative: &str) -> PathBuf {
+    let root = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+    PathBuf::from(root).join(relative)
+}
+
+fn read_table(relative: &str) -> Result<(PathBuf, Vec<Vec<String>>), syn::Error> {
+    let path = table_path(relative);
+    let contents = fs::read_to_string(&path).map_err(|e| {
+        syn::Error::new(
+            Span::call_site(),
+            format!("Failed to read table {}: {e}", path.display()),
+        )
+    })?;
+    Ok((path, parse_csv(&contents)))
+}
+
+/// Minimal csv reader supporting quoted fields with embedded commas and escaped quotes
+fn parse_csv(contents: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = contents.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => row.push(std::mem::take(&mut field)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    rows.into_iter()
+        .filter(|r| r.iter().any(|f| !f.trim().is_empty()))
+        .collect()
+}
+
+fn code_table_rows(rows: Vec<Vec<String>>) -> Result<Vec<CodeTableRow>, syn::Error> {
+    let mut rows = rows.into_iter();
+    let header = rows
+        .next()
+        .ok_or_else(|| syn::Error::new(Span::call_site(), "Empty code table"))?;
+    let column = |name: &str| header.iter().position(|h| h.trim() == name);
+    let sub_title = column("SubTitle_en");
+    let value = column("CodeFlag")
+        .ok_or_else(|| syn::Error::new(Span::call_site(), "Code table has no CodeFlag column"))?;
+    let meaning = column("MeaningParameterDescription_en").ok_or_else(|| {
+        syn::Error::new(
+            Span::call_site(),
+            "Code table has no MeaningParameterDescription_en column",
+        )
+    })?;
+    let unit = column("UnitComments_en");
+
+    let field = |row: &[String], index: Option<usize>| {
+        index
+            .and_then(|i| row.get(i))
+            .map(|f| f.trim().to_string())
+            .unwrap_or_default()
+    };
+
+    Ok(rows
+        .map(|row| CodeTableRow {
+            sub_title: field(&row, sub_title),
+            value: field(&row, Some(value)),
+            meaning: field(&row, Some(meaning)),
+            unit: field(&row, unit),
+        })
+        .collect())
+}
+
+/// Reads the number following `key` in a WMO sub title such as
+/// "Product discipline 0 - Meteorological products, parameter category 1: moisture"
+fn sub_title_number(sub_title: &str, key: &str) -> Option<u8> {
+    let lower = sub_title.to_lowercase();
+    let start = lower.find(key)? + key.len();
+    let digits: String = lower[start..]
+        .trim_start()
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok()
+}
+
+/// Lowercases a WMO meaning and drops a trailing parenthetical when it is only a note, such as
+/// "(see Note 1)", "(0 = sea, 1 = land)" or an acronym like "(VIL)". Qualifiers like "(surface)"
+/// are kept since they tell entries with otherwise identical names apart
+fn description(meaning: &str) -> String {
+    let trimmed = match trailing_parenthetical(meaning) {
+        Some((head, note)) if is_note(note) => head,
+        _ => meaning,
+    };
+    trimmed.trim().to_lowercase()
+}
+
+/// Splits "Total column water (vertically integrated (vapour + cloud))" into the text before the
+/// outermost trailing parenthetical and its contents
+fn trailing_parenthetical(meaning: &str) -> Option<(&str, &str)> {
+    let body = meaning.trim_end().strip_suffix(')')?;
+    let mut depth = 0;
+    for (i, c) in body.char_indices().rev() {
+        match c {
+            ')' => depth += 1,
+            '(' if depth == 0 => return Some((&body[..i], &body[i + 1..])),
+            '(' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn is_note(text: &str) -> bool {
+    let lower = text.trim().to_lowercase();
+    let acronym = text.chars().any(|c| c.is_ascii_uppercase())
+        && text
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+
+    acronym
+        || lower.contains('=')
+        || ["see ", "encoded ", "defined ", "as defined "]
+            .iter()
+            .any(|prefix| lower.starts_with(prefix))
+}
+
+/// Compacts WMO units to the style used by the rest of the parameter tables, so "kg m-2"
+/// becomes "kgm-2" while descriptive units such as "Code table 4.215", "Proportion" or
+/// "degree true" keep their spacing and only lose the leading capital
+fn unit(unit: &str) -> String {
+    let descriptive = unit.to_lowercase().contains("table")
+        || (unit.split_whitespace().next().map_or(0, str::len) > 2
+            && unit.chars().all(|c| c.is_alphabetic() || c == ' '));
+
+    if descriptive {
+        let mut chars = unit.chars();
+        match chars.next() {
+            Some(first) => first.to_lowercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    } else {
+        unit.split_whitespace().collect()
+    }
+}
+
+fn variant_ident(description: &str, value: u8, used: &mut Vec<String>) -> Ident {
+    let mut name: String = description
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect();
+
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name = format!("Parameter{name}");
+    }
+    if name == "Missing" || used.contains(&name) {
+        name = format!("{name}{value}");
+    }
+    used.push(name.clone());
+
+    Ident::new(&name, Span::call_site())
+}
+
+/// Abbreviation and optional CF standard name for a parameter
+struct Abbreviation {
+    abbrev: String,
+    standard_name: Option<String>,
+}
+
+fn read_abbreviations(
+    relative: &str,
+    discipline: u8,
+    category: u8,
+) -> Result<(PathBuf, HashMap<u8, Abbreviation>), syn::Error> {
+    let (path, rows) = read_table(relative)?;
+    let abbreviations = rows
+        .into_iter()
+        .skip(1)
+        .filter_map(|row| {
+            let number = |i: usize| row.get(i).and_then(|f| f.trim().parse::<u8>().ok());
+            match (number(0), number(1), number(2), row.get(3)) {
+                (Some(d), Some(c), Some(p), Some(abbrev)) if d == discipline && c == category => {
+                    let standard_name = row
+                        .get(4)
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty());
+                    Some((
+                        p,
+                        Abbreviation {
+                            abbrev: abbrev.trim().to_string(),
+                            standard_name,
+                        },
+                    ))
+                }
+                _ => None,
+            }
+        })
+        .collect();
+    Ok((path, abbreviations))
+}
+
+/// Makes the generated code depend on the table file so it is rebuilt when the table changes
+fn track(path: &Path) -> TokenStream {
+    let path = LitStr::new(&path.to_string_lossy(), Span::call_site());
+    quote! {
+        const _: &[u8] = include_bytes!(#path);
+    }
+}
+
+pub fn parameter_table(args: TableArgs) -> Result<TokenStream, syn::Error> {
+    let table = args.require_string("table")?;
+    let discipline = args.require_number("discipline")?;
+    let category = args.require_number("category")?;
+    let name = Ident::new(&args.require_string("name")?, Span::call_site());
+
+    let (table_path, rows) = read_table(&table)?;
+    let rows = code_table_rows(rows)?;
+
+    let (abbreviations_path, abbreviations) = match args.string("abbreviations") {
+        Some(relative) => {
+            let (path, abbreviations) = read_abbreviations(&relative, discipline, category)?;
+            (Some(path), abbreviations)
+        }
+        None => (None, HashMap::new()),
+    };
+
+    let mut used = Vec::new();
+    let variants = rows
+        .iter()
+        .filter(|r| {
+            sub_title_number(&r.sub_title, "discipline") == Some(discipline)
+                && sub_title_number(&r.sub_title, "category") == Some(category)
+        })
+        .filter_map(|r| {
+            let value: u8 = r.value.parse().ok()?;
+            let lower = r.meaning.to_lowercase();
+            if value == 255 || lower.starts_with("reserved") || lower == "missing" {
+                return None;
+            }
+
+            let description = description(&r.meaning);
+            let ident = variant_ident(&description, value, &mut used);
+            let unit = unit(&r.unit);
+            let entry = abbreviations.get(&value);
+            let abbrev = entry
+                .map(|e| e.abbrev.as_str())
+                .filter(|a| !a.is_empty())
+                .map(|abbrev| quote! { #[abbrev = #abbrev] });
+            let standard_name = entry
+                .and_then(|e| e.standard_name.as_deref())
+                .map(|standard_name| quote! { #[standard_name = #standard_name] });
+
+            Some(quote! {
+                #[description = #description]
+                #abbrev
+                #standard_name
+                #[unit = #unit]
+                #ident = #value,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if variants.is_empty() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            format!("No entries for discipline {discipline} category {category} in {table}"),
+        ));
+    }
+
+    let table_dependency = track(&table_path);
+    let abbreviations_dependency = abbreviations_path.as_deref().map(track);
+
+    Ok(quote! {
+        #table_dependency
+        #abbreviations_dependency
+
+        #[repr(u8)]
+        #[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+        pub enum #name {
+            #(#variants)*
+            Missing = 255,
+        }
+    })
+}
+
+pub fn category_table(args: TableArgs) -> Result<TokenStream, syn::Error> {
+    let table = args.require_string("table")?;
+    let discipline = args.require_number("discipline")?;
+    let name = Ident::new(&args.require_string("name")?, Span::call_site());
+
+    let (table_path, rows) = read_table(&table)?;
+    let rows = code_table_rows(rows)?;
+
+    let (values, descriptions): (Vec<u8>, Vec<String>) = rows
+        .iter()
+        .filter(|r| sub_title_number(&r.sub_title, "discipline") == Some(discipline))
+        .filter_map(|r| {
+            let value: u8 = r.value.parse().ok()?;
+            let lower = r.meaning.to_lowercase();
+            if value == 255 || lower.starts_with("reserved") || lower == "missing" {
+                return None;
+            }
+            Some((value, description(&r.meaning)))
+        })
+        .unzip();
+
+    let table_dependency = track(&table_path);
+
+    Ok(quote! {
+        #table_dependency
+
+        pub fn #name(category: u8) -> &'static str {
+            match category {
+                #(
+                    #values => #descriptions,
+                )*
+                _ => "other",
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_quoted_csv_fields() {
+        let rows = parse_csv("a,b,c\r\n\"x, y\",\"say \"\"hi\"\"\",z\n");
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1], vec!["x, y", "say \"hi\"", "z"]);
+    }
+
+    #[test]
+    fn normalize_wmo_entries() {
+        assert_eq!(
+            sub_title_number(
+                "Product discipline 1 - Hydrological products, parameter category 2: inland water",
+                "category"
+            ),
+            Some(2)
+        );
+        assert_eq!(
+            description("Land cover (0 = water, 1 = land)"),
+            "land cover"
+        );
+        assert_eq!(description("Echo top (see Note 1)"), "echo top");
+        assert_eq!(
+            description("Vertically-integrated liquid water (VIL)"),
+            "vertically-integrated liquid water"
+        );
+        assert_eq!(
+            description("Net short-wave radiation flux (surface)"),
+            "net short-wave radiation flux (surface)"
+        );
+        assert_eq!(
+            description(
+                "Total column water (Vertically integrated total water (vapour + cloud water/ice))"
+            ),
+            "total column water (vertically integrated total water (vapour + cloud water/ice))"
+        );
+        assert_eq!(unit("kg m-2 s-1"), "kgm-2s-1");
+        assert_eq!(unit("Code table 4.215"), "code table 4.215");
+        assert_eq!(unit("Proportion"), "proportion");
+        assert_eq!(unit("K"), "K");
+        assert_eq!(unit("degree true"), "degree true");
+        assert_eq!(unit("deg N"), "deg N");
+        assert_eq!(unit("Pa m"), "Pam");
+    }
+}
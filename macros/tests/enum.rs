@@ -6,6 +6,7 @@ enum Shape {
     #[description = "rectangle"]
     #[abbrev = "rect"]
     #[unit = "sqft"]
+    #[standard_name = "rectangle_area"]
     Rectangle = 0,
     #[description = "triangle"]
     #[abbrev = "tri"]
@@ -44,6 +45,7 @@ fn shape_from_value() {
 fn shape_parameter_attributes() {
     let rectangle: Shape = 0.into();
     assert_eq!(rectangle.abbrev(), "rect");
+    assert_eq!(rectangle.standard_name(), Some("rectangle_area"));
 
     let triangle: Shape = 1u8.into();
     assert_eq!(triangle.abbrev(), "tri");
@@ -52,4 +54,5 @@ fn shape_parameter_attributes() {
     let circle: Shape = 2u8.into();
     assert_eq!(circle.unit(), "radius");
     assert_eq!(circle.name(), "round one");
+    assert_eq!(circle.standard_name(), None);
 }
\ No newline at end of file
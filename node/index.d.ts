@@ -14,6 +14,11 @@ export interface LatLng {
 export function parseMessagesFromBuffer(buffer: Buffer): unknown[]
 export class GribMessage {
   static parseFromBuffer(buffer: Buffer, offset: number): GribMessage
+  get originatingCentre(): string
+  get originatingCentreId(): number
+  get originatingSubCentre(): number
+  get masterTableVersion(): number
+  get localTableVersion(): number
   get varName(): string
   get varAbbrev(): string
   get units(): string
@@ -3,8 +3,8 @@
 use std::collections::HashMap;
 
 use gribberish::{
-  data_message::DataMessage,
-  message::{read_message, read_messages, scan_messages},
+  data_message::{read_data_message, DataMessage},
+  message_metadata::{iter_message_metadata, scan_message_metadata},
 };
 use napi::{
   bindgen_prelude::{Array, Buffer, Float64Array},
@@ -45,19 +45,42 @@ impl GribMessage {
   #[napi(factory)]
   pub fn parse_from_buffer(buffer: Buffer, offset: u32) -> Self {
     let buf: Vec<u8> = buffer.into();
-    let message = read_message(&buf, offset as usize).unwrap();
-    let message = DataMessage::try_from(&message).unwrap();
+    let message = read_data_message(&buf, offset as usize, 0).unwrap();
 
     GribMessage { inner: message }
   }
 
-  pub fn parse_from_bytes(data: &[u8], offset: usize) -> Self {
-    let message = read_message(&data, offset).unwrap();
-    let message = DataMessage::try_from(&message).unwrap();
+  pub fn parse_from_bytes(data: &[u8], offset: usize, field: usize) -> Self {
+    let message = read_data_message(data, offset, field).unwrap();
 
     GribMessage { inner: message }
   }
 
+  #[napi(getter)]
+  pub fn originating_centre(&self) -> String {
+    self.inner.metadata.originating_centre.to_string()
+  }
+
+  #[napi(getter)]
+  pub fn originating_centre_id(&self) -> u32 {
+    self.inner.metadata.originating_centre_id as u32
+  }
+
+  #[napi(getter)]
+  pub fn originating_sub_centre(&self) -> u32 {
+    self.inner.metadata.originating_sub_centre as u32
+  }
+
+  #[napi(getter)]
+  pub fn master_table_version(&self) -> u32 {
+    self.inner.metadata.master_table_version as u32
+  }
+
+  #[napi(getter)]
+  pub fn local_table_version(&self) -> u32 {
+    self.inner.metadata.local_table_version as u32
+  }
+
   #[napi(getter)]
   pub fn var_name(&self) -> &str {
     self.inner.metadata.name.as_str()
@@ -127,12 +150,12 @@ impl GribMessage {
 #[napi]
 pub fn parse_messages_from_buffer(buffer: Buffer, env: Env) -> Array {
   let buf: Vec<u8> = buffer.into();
-  let messages = read_messages(&buf).collect::<Vec<_>>();
 
   let mut arr = env.create_array(0).unwrap();
-  messages.into_iter().for_each(|gm| {
+  iter_message_metadata(&buf).for_each(|metadata| {
+    let metadata = metadata.unwrap();
     let grib_message = GribMessage {
-      inner: DataMessage::try_from(&gm).unwrap(),
+      inner: read_data_message(&buf, metadata.byte_offset, metadata.field_index).unwrap(),
     };
 
     arr.insert(grib_message).unwrap();
@@ -144,6 +167,7 @@ pub fn parse_messages_from_buffer(buffer: Buffer, env: Env) -> Array {
 #[napi]
 pub struct GribMessageFactory {
   data: Vec<u8>,
+  /// Byte offset and field index of each message key
   mapping: HashMap<String, (usize, usize)>,
 }
 
@@ -152,7 +176,10 @@ impl GribMessageFactory {
   #[napi(factory)]
   pub fn from_buffer(buffer: Buffer) -> Self {
     let data: Vec<u8> = buffer.into();
-    let mapping = scan_messages(&data);
+    let mapping = scan_message_metadata(&data)
+      .into_iter()
+      .map(|(key, (_, offset, metadata))| (key, (offset, metadata.field_index)))
+      .collect();
 
     GribMessageFactory { data, mapping }
   }
@@ -164,6 +191,7 @@ impl GribMessageFactory {
 
   #[napi]
   pub fn get_message(&self, key: String) -> GribMessage {
-    GribMessage::parse_from_bytes(&self.data, self.mapping[&key].1)
+    let (offset, field) = self.mapping[&key];
+    GribMessage::parse_from_bytes(&self.data, offset, field)
   }
 }
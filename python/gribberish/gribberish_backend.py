@@ -110,14 +110,14 @@ class GribberishBackendArray(BackendArray):
         arrs = []
         with self.lock:
             with fsspec.open(self.filename_or_obj, 'rb', **self.storage_options) as f:
-                for offset, size in self.offsets:
+                for offset, size, field in self.offsets:
                     f.seek(offset, 0)
                     raw_data = f.read(size)
 
                     # Current offset is the beginning of the raw data chunk
                     # The shape is the shape of the spatial portion of the 
-                    # data chunk
-                    chunk_data = parse_grib_array(raw_data, 0)
+                    # data chunk, and field selects the field within the message
+                    chunk_data = parse_grib_array(raw_data, 0, field)
                     arrs.append(chunk_data)
     
         # Concatentate the flattened arrays, the reshape to the target shape
@@ -12,9 +12,10 @@ class GribberishCodec(numcodecs.abc.Codec):
 
     codec_id = "gribberish"
 
-    def __init__(self, var, dtype=None):
+    def __init__(self, var, dtype=None, field=0):
         self.var = var
         self.dtype = dtype
+        self.field = field
 
     def encode(self, buf):
         # on encode, pass through
@@ -22,11 +23,11 @@ class GribberishCodec(numcodecs.abc.Codec):
 
     def decode(self, buf, out=None):
         if self.var == 'latitude' or self.var == 'longitude':
-            message = parse_grib_message_metadata(buf, 0)
+            message = parse_grib_message_metadata(buf, 0, self.field)
             lat, lng = message.latlng()
             data = lat if self.var == 'latitude' else lng
         else:
-            data = parse_grib_array(buf, 0)
+            data = parse_grib_array(buf, 0, self.field)
 
         if out is not None:
             return numcodecs.compat.ndarray_copy(data, out)
@@ -60,7 +60,8 @@ def _store_array_ref(
     offset,
     size,
     attr, 
-    use_cfgrib_codec=False
+    use_cfgrib_codec=False,
+    field=0,
 ):
     shape = tuple(data_shape or ())
     data_type = np.dtype('float64')
@@ -68,7 +69,7 @@ def _store_array_ref(
     if use_cfgrib_codec:
         filters = [GRIBCodec(var=var, dtype=str(data_type))]
     else:
-        filters = [GribberishCodec(var=var, dtype=str(data_type))]
+        filters = [GribberishCodec(var=var, dtype=str(data_type), field=field)]
 
     d = z.create_dataset(
         name=var,
@@ -134,33 +135,39 @@ def scan_gribberish(
         for offset, size, data in _split_file(f):
             try:
                 dataset = parse_grib_dataset(data, perserve_dims=perserve_dims, encode_coords=True, filter_by_attrs=filter_by_attrs, filter_by_variable_attrs=filter_by_variable_attrs)
-                var_name, var_data = next(iter(dataset['data_vars'].items()))
             except Exception as e:
                 # Skip messages that gribberish cannot handle yet or that are filtered out
                 continue
 
-            # Only reading one variable from each data chunk (1 message)
-            if only_variables and var_name not in only_variables:
+            # A message holds one variable per field
+            data_vars = {
+                var_name: var_data
+                for var_name, var_data in dataset['data_vars'].items()
+                if not only_variables or var_name in only_variables
+            }
+            if not data_vars:
                 continue
 
             store = {}
             z = zarr.open_group(store)
             z.attrs.update(dataset['attrs'])
 
-            _store_array_ref(
-                store,
-                z,
-                var_data['values']['shape'],
-                var_name,
-                offset,
-                size,
-                var_data['attrs'], 
-                use_cfgrib_codec,
-            )
-
-            # Coords
-            dims = var_data['dims']
-            z[var_name].attrs["_ARRAY_DIMENSIONS"] = dims
+            for var_name, var_data in data_vars.items():
+                _store_array_ref(
+                    store,
+                    z,
+                    var_data['values']['shape'],
+                    var_name,
+                    offset,
+                    size,
+                    var_data['attrs'], 
+                    use_cfgrib_codec,
+                    field=var_data['values']['offsets'][0][2],
+                )
+
+                # Coords
+                dims = var_data['dims']
+                z[var_name].attrs["_ARRAY_DIMENSIONS"] = dims
 
             for coord_name, coord_data in dataset['coords'].items():
                 coord_values = coord_data["values"]
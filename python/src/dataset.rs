@@ -1,7 +1,8 @@
 use std::collections::{HashMap, HashSet};
 
 use gribberish::{
-    message::Message, message_metadata::scan_message_metadata,
+    cf::{self, AttributeValue, Attributes},
+    message::read_message_data, message_metadata::scan_message_metadata,
     templates::product::tables::FixedSurfaceType,
 };
 use numpy::{
@@ -16,17 +17,20 @@ use pyo3::{
 };
 
 #[pyfunction]
+#[pyo3(signature = (data, shape, offsets, fields=None))]
 pub fn build_grib_array<'py>(
     py: Python<'py>,
     data: &[u8],
     shape: Vec<usize>,
     offsets: Vec<usize>,
+    fields: Option<Vec<usize>>,
 ) -> pyo3::Bound<'py, PyArray<f64, Dim<IxDynImpl>>> {
     let v = offsets
         .iter()
-        .flat_map(|offset| {
-            let message = Message::from_data(data, *offset).unwrap();
-            message.data().unwrap()
+        .enumerate()
+        .flat_map(|(i, offset)| {
+            let field = fields.as_ref().and_then(|f| f.get(i).copied()).unwrap_or(0);
+            read_message_data(data, *offset, field).unwrap()
         })
         .collect::<Vec<_>>();
 
@@ -117,7 +121,7 @@ pub fn parse_grib_dataset<'py>(
             "{var}{surf}_{stat}{gen}",
             surf = v.2.first_fixed_surface_type.coordinate_name(),
             stat =
-                v.2.statistical_process
+                v.2.statistical_processing
                     .clone()
                     .map(|s| s.abbv())
                     .unwrap_or("".to_string())
@@ -350,6 +354,9 @@ pub fn parse_grib_dataset<'py>(
             if surface_type.is_vertical_level() {
                 vertical_metadata.set_item("axis", "Z").unwrap();
             }
+            if let Some(attributes) = cf::vertical_coordinate_attributes(surface_type) {
+                set_cf_attributes(&vertical_metadata, attributes);
+            }
             vertical.set_item("attrs", vertical_metadata).unwrap();
             vertical.set_item("values", verticals).unwrap();
             vertical.set_item("dims", vec![dim]).unwrap();
@@ -386,6 +393,9 @@ pub fn parse_grib_dataset<'py>(
                 if surface_type.is_vertical_level() {
                     vertical_metadata.set_item("axis", "Z").unwrap();
                 }
+                if let Some(attributes) = cf::vertical_coordinate_attributes(surface_type) {
+                    set_cf_attributes(&vertical_metadata, attributes);
+                }
                 vertical.set_item("attrs", vertical_metadata).unwrap();
                 vertical.set_item("values", verticals).unwrap();
                 vertical.set_item("dims", vec![name.clone()]).unwrap();
@@ -520,8 +530,18 @@ pub fn parse_grib_dataset<'py>(
         let var_metadata = PyDict::new_bound(py);
         let first = mapping.get(v.first().unwrap()).unwrap();
         var_metadata
-            .set_item("standard_name", first.2.name.clone())
+            .set_item(
+                "standard_name",
+                first
+                    .2
+                    .standard_name
+                    .clone()
+                    .unwrap_or_else(|| first.2.name.clone()),
+            )
             .unwrap();
+        if let Some(cell_methods) = first.2.cell_methods() {
+            var_metadata.set_item("cell_methods", cell_methods).unwrap();
+        }
         var_metadata
             .set_item("long_name", first.2.name.clone())
             .unwrap();
@@ -569,7 +589,7 @@ pub fn parse_grib_dataset<'py>(
                 "statistical_process",
                 first
                     .2
-                    .statistical_process
+                    .statistical_processing
                     .as_ref()
                     .map(|s| s.to_string())
                     .unwrap_or("".to_string()),
@@ -646,6 +666,7 @@ pub fn parse_grib_dataset<'py>(
                 (
                     mapping.get(chunk).unwrap().1,
                     mapping.get(chunk).unwrap().2.message_size,
+                    mapping.get(chunk).unwrap().2.field_index,
                 )
             })
             .collect::<Vec<_>>();
@@ -671,3 +692,14 @@ pub fn parse_grib_dataset<'py>(
     dataset.set_item("attrs", attrs).unwrap();
     Ok(dataset)
 }
+
+fn set_cf_attributes(metadata: &Bound<PyDict>, attributes: Attributes) {
+    for (key, value) in attributes {
+        match value {
+            AttributeValue::Text(text) => metadata.set_item(key, text),
+            AttributeValue::Number(number) => metadata.set_item(key, number),
+            AttributeValue::Numbers(numbers) => metadata.set_item(key, numbers),
+        }
+        .unwrap();
+    }
+}
@@ -1,8 +1,7 @@
 use std::collections::HashMap;
-use std::convert::TryFrom;
 
-use gribberish::message::Message;
-use gribberish::message_metadata::{scan_message_metadata, MessageMetadata};
+use gribberish::message::read_message_data;
+use gribberish::message_metadata::{read_message_metadata, scan_message_metadata, MessageMetadata};
 use numpy::{PyArray, PyArray1};
 use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
@@ -21,6 +20,41 @@ impl GribMessageMetadata {
         self.inner.message_size
     }
 
+    #[getter]
+    fn field_index(&self) -> usize {
+        self.inner.field_index
+    }
+
+    #[getter]
+    fn originating_centre(&self) -> String {
+        self.inner.originating_centre.to_string()
+    }
+
+    #[getter]
+    fn originating_centre_id(&self) -> u16 {
+        self.inner.originating_centre_id
+    }
+
+    #[getter]
+    fn originating_sub_centre(&self) -> u16 {
+        self.inner.originating_sub_centre
+    }
+
+    #[getter]
+    fn master_table_version(&self) -> u8 {
+        self.inner.master_table_version
+    }
+
+    #[getter]
+    fn local_table_version(&self) -> u8 {
+        self.inner.local_table_version
+    }
+
+    #[getter]
+    fn mars_keys(&self) -> Option<HashMap<&'static str, String>> {
+        self.inner.mars_keys()
+    }
+
     #[getter]
     fn var_name(&self) -> &str {
         self.inner.name.as_str()
@@ -36,6 +70,26 @@ impl GribMessageMetadata {
         self.inner.units.as_str()
     }
 
+    #[getter]
+    fn standard_name(&self) -> Option<&str> {
+        self.inner.standard_name.as_deref()
+    }
+
+    #[getter]
+    fn cell_methods(&self) -> Option<String> {
+        self.inner.cell_methods()
+    }
+
+    #[getter]
+    fn eccodes_short_name(&self) -> Option<&str> {
+        self.inner.eccodes_parameter.map(|p| p.short_name)
+    }
+
+    #[getter]
+    fn eccodes_param_id(&self) -> Option<u32> {
+        self.inner.eccodes_parameter.map(|p| p.param_id)
+    }
+
     #[getter]
     fn generating_process(&self) -> String {
         self.inner.generating_process.to_string()
@@ -49,6 +103,14 @@ impl GribMessageMetadata {
             .map(|p| p.to_string())
     }
 
+    #[getter]
+    fn statistical_processing(&self) -> Option<String> {
+        self.inner
+            .statistical_processing
+            .clone()
+            .map(|p| p.to_string())
+    }
+
     #[getter]
     fn level_type(&self) -> String {
         self.inner.first_fixed_surface_type.to_string()
@@ -164,35 +226,43 @@ pub struct GribMessage {
 #[pymethods]
 impl GribMessage {
     fn data<'py>(&self, py: Python<'py>, ) -> Bound<'py, PyArray1<f64>> {
-        parse_grib_array(py, &self.raw_data, self.offset)
+        parse_grib_array(py, &self.raw_data, self.offset, self.metadata.inner.field_index)
     }
 }
 
 #[pyfunction]
-pub fn parse_grib_array<'py>(py: Python<'py>, data: &[u8], offset: usize) -> Bound<'py, PyArray1<f64>> {
-    let message = Message::from_data(data, offset).unwrap();
-    let data = message.data().unwrap();
+#[pyo3(signature = (data, offset, field=0))]
+pub fn parse_grib_array<'py>(
+    py: Python<'py>,
+    data: &[u8],
+    offset: usize,
+    field: usize,
+) -> Bound<'py, PyArray1<f64>> {
+    let data = read_message_data(data, offset, field).unwrap();
     PyArray::from_vec_bound(py, data)
 }
 
 #[pyfunction]
-pub fn parse_grib_message_metadata(data: &[u8], offset: usize) -> PyResult<GribMessageMetadata> {
-    let message = Message::from_data(data, offset).unwrap();
-    let metadata = MessageMetadata::try_from(&message).unwrap();
+#[pyo3(signature = (data, offset, field=0))]
+pub fn parse_grib_message_metadata(
+    data: &[u8],
+    offset: usize,
+    field: usize,
+) -> PyResult<GribMessageMetadata> {
+    let metadata = read_message_metadata(data, offset, field).unwrap();
     Ok(GribMessageMetadata { inner: metadata })
 }
 
 #[pyfunction]
-pub fn parse_grib_message<'py>(data: &[u8], offset: usize) -> PyResult<GribMessage> {
-    match Message::from_data(&data.to_vec(), offset) {
-        Some(m) => Ok(GribMessage {
+#[pyo3(signature = (data, offset, field=0))]
+pub fn parse_grib_message<'py>(data: &[u8], offset: usize, field: usize) -> PyResult<GribMessage> {
+    match read_message_metadata(data, offset, field) {
+        Ok(metadata) => Ok(GribMessage {
             offset,
             raw_data: data.to_vec(),
-            metadata: GribMessageMetadata {
-                inner: MessageMetadata::try_from(&m).unwrap(),
-            },
+            metadata: GribMessageMetadata { inner: metadata },
         }),
-        None => Err(PyTypeError::new_err("Failed to read GribMessage")),
+        Err(_) => Err(PyTypeError::new_err("Failed to read GribMessage")),
     }
 }
 
@@ -1,6 +1,8 @@
 
+#[derive(Clone, Debug, PartialEq)]
 pub struct Parameter {
     pub name: String,
     pub unit: String,
     pub abbrev: String,
+    pub standard_name: Option<String>,
 }
\ No newline at end of file